@@ -14,5 +14,3 @@ mod storage;
 fn main() -> sc_cli::Result<()> {
     command::run()
 }
-
-mod account_mapping;
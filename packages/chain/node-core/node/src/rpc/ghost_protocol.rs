@@ -1,17 +1,54 @@
-use std::sync::Arc;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+    time::Duration,
+};
 
-use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::ErrorObjectOwned};
-use sp_api::ProvideRuntimeApi;
+use codec::Decode;
+use futures::{future, stream, stream::BoxStream, StreamExt};
+use jsonrpsee::{
+    core::{server::SendTimeoutError, RpcResult, SubscriptionResult},
+    proc_macros::rpc,
+    types::ErrorObjectOwned,
+    PendingSubscriptionSink, SubscriptionMessage,
+};
+use sc_client_api::{Backend, BlockchainEvents, StorageProvider};
+use serde::Serialize;
+use sp_api::{ApiExt, ProvideRuntimeApi, RuntimeApiInfo};
 use sp_blockchain::HeaderBackend;
+use sp_core::{crypto::Ss58Codec, storage::StorageKey};
 use sp_runtime::traits::Block as BlockT;
 
-use ghost_runtime::{opaque::Block, AccountId, BlockNumber};
+use ghost_runtime::{
+    configs::SS58Prefix, opaque::Block, AccountId, BlockNumber, ChainGhostEvent, G3MailEvent,
+    GhonityEvent, Hash, RuntimeEvent,
+};
 
-use super::types::{IntentResponse, IntentStatus, JourneyStepResponse, MessageResponse};
+use crate::account_mapping;
+
+use super::types::{
+    AccountAddress, AccountResponse, AccountStatsResponse, AchievementKind, AchievementResponse,
+    BridgeIntent, Bytes, BytesEncoding, CancelReason, ChainRefResponse, ContractCallIntent,
+    ExecutionErrorResponse, FeedItemKind, FeedItemResponse, FollowEdgeResponse,
+    FollowSuggestionResponse, FollowerNotification, FollowerStatsResponse, FollowerUpdate,
+    InboxNotification, InboxUpdate, IntentAction, IntentKind, IntentNotification, IntentResponse,
+    IntentStatus, IntentTreeResponse, IntentUpdate, JourneyHeadResponse, JourneyStepKind,
+    JourneyStepResponse, LinkedTx, MessageResponse, PersonaResponse, ReputationNotification,
+    SubIntentResponse, SwapIntent, TransferIntent,
+};
 
 pub use ghost_runtime::apis::ghost_protocol::{
-    ChainGhostRuntimeApi, G3MailRuntimeApi, GhonityRuntimeApi,
+    ChainGhostRuntimeApi, G3MailRuntimeApi, GhonityRuntimeApi, MAX_BATCH_ACCOUNTS,
+    MAX_BATCH_INTENTS,
 };
+use ghost_runtime::apis::ghost_protocol::JourneyStepData;
+
+type ChainGhostRuntime = dyn ChainGhostRuntimeApi<Block, AccountId, BlockNumber>;
+type G3MailRuntime = dyn G3MailRuntimeApi<Block, AccountId, BlockNumber>;
+type GhonityRuntime = dyn GhonityRuntimeApi<Block, AccountId, BlockNumber>;
+
+/// Time a subscriber has to take a notification before its subscription is closed
+const SUBSCRIPTION_SEND_TIMEOUT: Duration = Duration::from_secs(30);
 
 fn runtime_error_into_rpc_error(err: impl std::fmt::Display) -> ErrorObjectOwned {
     ErrorObjectOwned::owned(
@@ -21,6 +58,587 @@ fn runtime_error_into_rpc_error(err: impl std::fmt::Display) -> ErrorObjectOwned
     )
 }
 
+/// Reject blocks whose runtime implements another version of `Api` than the node was built
+/// against, rather than failing to decode the call results
+fn ensure_api_version<Api: RuntimeApiInfo + ?Sized>(
+    api: &impl ApiExt<Block>,
+    at: Hash,
+) -> RpcResult<()> {
+    match api.api_version::<Api>(at).map_err(runtime_error_into_rpc_error)? {
+        Some(version) if version == Api::VERSION => Ok(()),
+        version => Err(ErrorObjectOwned::owned(
+            jsonrpsee::types::error::ErrorCode::InternalError.code(),
+            "Unsupported runtime API version".to_string(),
+            Some(format!(
+                "Runtime implements version {}, node expects {}",
+                version.map_or_else(|| "none".to_string(), |version| version.to_string()),
+                Api::VERSION
+            )),
+        )),
+    }
+}
+
+/// Reject batches over `max` items rather than silently truncating them in the runtime
+fn ensure_batch_size(len: usize, max: u32) -> RpcResult<()> {
+    if len > max as usize {
+        return Err(ErrorObjectOwned::owned(
+            jsonrpsee::types::error::ErrorCode::InvalidParams.code(),
+            "Batch too large".to_string(),
+            Some(format!("{} items requested, at most {} allowed", len, max)),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Account of an address parameter: SS58 with the chain prefix, or H160 through the EVM
+/// address mapping
+fn resolve_account(address: AccountAddress) -> RpcResult<AccountId> {
+    match address {
+        AccountAddress::Ss58(account, prefix) if prefix == u16::from(SS58Prefix::get()) => {
+            Ok(account)
+        },
+        AccountAddress::Ss58(_, prefix) => Err(ErrorObjectOwned::owned(
+            jsonrpsee::types::error::ErrorCode::InvalidParams.code(),
+            "Invalid address".to_string(),
+            Some(format!("SS58 prefix {}, expected {}", prefix, SS58Prefix::get())),
+        )),
+        AccountAddress::H160(address) => Ok(account_mapping::h160_to_account_id(&address)),
+    }
+}
+
+fn resolve_accounts(addresses: Vec<AccountAddress>) -> RpcResult<Vec<AccountId>> {
+    addresses.into_iter().map(resolve_account).collect()
+}
+
+fn account_into_rpc(account: AccountId) -> AccountResponse {
+    AccountResponse {
+        ss58: account.to_ss58check_with_version(u16::from(SS58Prefix::get()).into()),
+        h160: account_mapping::account_id_to_h160(&account),
+    }
+}
+
+fn intent_action_into_rpc(
+    action: ghost_runtime::IntentAction<AccountId>,
+) -> IntentAction<AccountResponse> {
+    use ghost_runtime::IntentAction as Action;
+
+    match action {
+        Action::Transfer(t) => IntentAction::Transfer(TransferIntent {
+            dest: account_into_rpc(t.dest),
+            asset: t.asset,
+            amount: t.amount,
+        }),
+        Action::Swap(s) => IntentAction::Swap(SwapIntent {
+            asset_in: s.asset_in,
+            asset_out: s.asset_out,
+            amount_in: s.amount_in,
+            min_amount_out: s.min_amount_out,
+        }),
+        Action::Bridge(b) => IntentAction::Bridge(BridgeIntent {
+            asset: b.asset,
+            amount: b.amount,
+            dest_chain: b.dest_chain,
+            recipient: Bytes::hex(b.recipient.into_inner()),
+        }),
+        Action::ContractCall(c) => IntentAction::ContractCall(ContractCallIntent {
+            contract: c.contract.into(),
+            value: c.value,
+            input: Bytes::hex(c.input.into_inner()),
+        }),
+    }
+}
+
+fn intent_status_into_rpc(status: ghost_runtime::IntentStatus) -> IntentStatus {
+    match status {
+        ghost_runtime::IntentStatus::Pending => IntentStatus::Pending,
+        ghost_runtime::IntentStatus::Executed => IntentStatus::Executed,
+        ghost_runtime::IntentStatus::Failed => IntentStatus::Failed,
+        ghost_runtime::IntentStatus::Cancelled => IntentStatus::Cancelled,
+    }
+}
+
+fn cancel_reason_into_rpc(reason: ghost_runtime::CancelReason) -> CancelReason {
+    match reason {
+        ghost_runtime::CancelReason::UserRequested => CancelReason::UserRequested,
+        ghost_runtime::CancelReason::Expired => CancelReason::Expired,
+        ghost_runtime::CancelReason::MarketMoved => CancelReason::MarketMoved,
+        ghost_runtime::CancelReason::InsufficientFunds => CancelReason::InsufficientFunds,
+        ghost_runtime::CancelReason::Superseded(by) => CancelReason::Superseded(by),
+    }
+}
+
+fn journey_step_kind_into_rpc(kind: ghost_runtime::JourneyStepKind) -> JourneyStepKind {
+    use ghost_runtime::JourneyStepKind as Kind;
+
+    match kind {
+        Kind::TxSubmitted => JourneyStepKind::TxSubmitted,
+        Kind::TxConfirmed => JourneyStepKind::TxConfirmed,
+        Kind::BridgeHop => JourneyStepKind::BridgeHop,
+        Kind::Note => JourneyStepKind::Note,
+        Kind::Milestone => JourneyStepKind::Milestone,
+    }
+}
+
+fn achievement_kind_into_rpc(kind: ghost_runtime::AchievementKind) -> AchievementKind {
+    use ghost_runtime::AchievementKind as Kind;
+
+    match kind {
+        Kind::IntentsExecuted => AchievementKind::IntentsExecuted,
+        Kind::SuccessRatio => AchievementKind::SuccessRatio,
+        Kind::DistinctKinds => AchievementKind::DistinctKinds,
+        Kind::JourneySteps => AchievementKind::JourneySteps,
+    }
+}
+
+fn linked_tx_into_rpc(tx: ghost_runtime::LinkedTx) -> LinkedTx {
+    match tx {
+        ghost_runtime::LinkedTx::Substrate(hash) => LinkedTx::Substrate(hash.into()),
+        ghost_runtime::LinkedTx::Ethereum(hash) => LinkedTx::Ethereum(hash.into()),
+    }
+}
+
+fn intent_into_rpc(
+    data: ghost_runtime::apis::ghost_protocol::IntentData<AccountId, BlockNumber>,
+) -> IntentResponse<AccountResponse, BlockNumber> {
+    IntentResponse {
+        intent_id: data.intent_id,
+        account: account_into_rpc(data.account),
+        status: intent_status_into_rpc(data.status),
+        timestamp: data.timestamp,
+        metadata: Bytes::hex(data.metadata),
+        kind: data.kind.map(intent_kind_into_rpc),
+        error: data.error.map(|e| ExecutionErrorResponse {
+            call_index: e.call_index,
+            error: format!("{:?}", e.error),
+        }),
+        chain_refs: data
+            .chain_refs
+            .into_iter()
+            .map(|chain_ref| ChainRefResponse {
+                chain_id: chain_ref.chain_id,
+                tx_hash: Bytes::hex(chain_ref.tx_hash),
+                block_number: chain_ref.block_number,
+                attested: chain_ref.attested,
+            })
+            .collect(),
+        cancel_reason: data.cancel_reason.map(cancel_reason_into_rpc),
+    }
+}
+
+fn journey_step_into_rpc(
+    step: ghost_runtime::apis::ghost_protocol::JourneyStepData<AccountId, BlockNumber>,
+    encoding: BytesEncoding,
+) -> JourneyStepResponse<AccountResponse, BlockNumber> {
+    JourneyStepResponse {
+        step_id: step.step_id,
+        step_kind: journey_step_kind_into_rpc(step.step_kind),
+        description: encoding.text(step.description),
+        timestamp: step.timestamp,
+        author: account_into_rpc(step.author),
+        linked_tx: step.linked_tx.map(linked_tx_into_rpc),
+        attachment: step.attachment.map(|cid| encoding.cid(cid)),
+    }
+}
+
+fn message_into_rpc(
+    msg: ghost_runtime::apis::ghost_protocol::MessagePointerData<AccountId, BlockNumber>,
+    encoding: BytesEncoding,
+) -> MessageResponse<AccountResponse, BlockNumber> {
+    MessageResponse {
+        message_id: msg.message_id,
+        sender: account_into_rpc(msg.sender),
+        recipient: account_into_rpc(msg.recipient),
+        cid: encoding.cid(msg.cid),
+        timestamp: msg.timestamp,
+        read: msg.read,
+    }
+}
+
+fn intent_kind_into_rpc(
+    kind: ghost_runtime::IntentKind<AccountId>,
+) -> IntentKind<AccountResponse> {
+    use ghost_runtime::{IntentAction as Action, IntentKind as Kind};
+
+    // Single-action kinds share their payload with the matching batch action
+    let single = |action| match intent_action_into_rpc(action) {
+        IntentAction::Transfer(t) => IntentKind::Transfer(t),
+        IntentAction::Swap(s) => IntentKind::Swap(s),
+        IntentAction::Bridge(b) => IntentKind::Bridge(b),
+        IntentAction::ContractCall(c) => IntentKind::ContractCall(c),
+    };
+
+    match kind {
+        Kind::Transfer(t) => single(Action::Transfer(t)),
+        Kind::Swap(s) => single(Action::Swap(s)),
+        Kind::Bridge(b) => single(Action::Bridge(b)),
+        Kind::ContractCall(c) => single(Action::ContractCall(c)),
+        Kind::Batch(actions) => IntentKind::Batch {
+            actions: actions.into_iter().map(intent_action_into_rpc).collect(),
+        },
+    }
+}
+
+/// Hashes of the blocks scanned by subscriptions: each new best block, or each finalized block
+///
+/// Following the best chain is faster, but notifications of a block retracted by a reorg are
+/// not revoked and the blocks of the new fork are not replayed.
+fn block_stream<C>(client: &C, finalized: bool) -> BoxStream<'static, Hash>
+where
+    C: BlockchainEvents<Block>,
+{
+    if finalized {
+        // Blocks finalized together are all scanned, oldest first
+        client
+            .finality_notification_stream()
+            .flat_map(|notification| {
+                let mut hashes = notification.tree_route.to_vec();
+                hashes.push(notification.hash);
+                stream::iter(hashes)
+            })
+            .boxed()
+    } else {
+        client
+            .import_notification_stream()
+            .filter_map(|notification| {
+                future::ready(notification.is_new_best.then_some(notification.hash))
+            })
+            .boxed()
+    }
+}
+
+/// Events deposited by the block `hash`, decoded from its `System::Events` storage
+///
+/// Blocks whose events cannot be decoded with the node's runtime types, such as blocks
+/// produced by an older runtime, yield no events.
+fn block_events<C, B>(client: &C, hash: Hash) -> Vec<RuntimeEvent>
+where
+    C: StorageProvider<Block, B>,
+    B: Backend<Block>,
+{
+    let key = StorageKey([sp_core::twox_128(b"System"), sp_core::twox_128(b"Events")].concat());
+
+    client
+        .storage(hash, &key)
+        .ok()
+        .flatten()
+        .and_then(|data| {
+            Vec::<frame_system::EventRecord<RuntimeEvent, Hash>>::decode(&mut &data.0[..]).ok()
+        })
+        .map(|records| records.into_iter().map(|record| record.event).collect())
+        .unwrap_or_default()
+}
+
+/// Accept `pending` and push the items produced by `notifications` for each block of `blocks`
+///
+/// Sending waits for the client to keep up, so a slow client slows its own subscription down.
+/// A client that takes no notification for `SUBSCRIPTION_SEND_TIMEOUT` is too far behind: its
+/// subscription is closed with an error rather than buffering blocks for it indefinitely. The
+/// subscription also ends when the client unsubscribes or the block stream ends.
+async fn pipe_block_notifications<T, F>(
+    pending: PendingSubscriptionSink,
+    mut blocks: BoxStream<'static, Hash>,
+    mut notifications: F,
+) -> SubscriptionResult
+where
+    T: Serialize,
+    F: FnMut(Hash) -> Vec<T>,
+{
+    let sink = pending.accept().await?;
+
+    loop {
+        let hash = tokio::select! {
+            _ = sink.closed() => break,
+            hash = blocks.next() => match hash {
+                Some(hash) => hash,
+                None => break,
+            },
+        };
+
+        for item in notifications(hash) {
+            let message = SubscriptionMessage::from(serde_json::value::to_raw_value(&item)?);
+            match sink.send_timeout(message, SUBSCRIPTION_SEND_TIMEOUT).await {
+                Ok(()) => {},
+                Err(SendTimeoutError::Timeout(_)) => {
+                    return Err("Subscriber is too slow to take notifications".into())
+                },
+                Err(SendTimeoutError::Closed(_)) => return Ok(()),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Intent a ChainGhost event notifies about, for the events relayed by the intent subscriptions
+fn notified_intent(event: &RuntimeEvent) -> Option<u64> {
+    match event {
+        RuntimeEvent::ChainGhost(
+            ChainGhostEvent::IntentExecuted { intent_id, .. }
+            | ChainGhostEvent::IntentStatusUpdated { intent_id, .. }
+            | ChainGhostEvent::JourneyRecorded { intent_id, .. }
+            | ChainGhostEvent::IntentCancelled { intent_id, .. }
+            | ChainGhostEvent::IntentRemoved { intent_id, .. },
+        ) => Some(*intent_id),
+        _ => None,
+    }
+}
+
+/// Intent changes carried by `events`, the events of the block `hash`, kept when `wanted`
+///
+/// `journey_step` reads back the step announced by a `JourneyRecorded` event, only for the kept
+/// changes. Each `JourneyRecorded` event announces a single step.
+fn intent_notifications(
+    hash: Hash,
+    events: Vec<RuntimeEvent>,
+    encoding: BytesEncoding,
+    wanted: impl Fn(u64) -> bool,
+    mut journey_step: impl FnMut(u64, u32) -> Option<JourneyStepData<AccountId, BlockNumber>>,
+) -> Vec<IntentNotification<Hash, AccountResponse, BlockNumber>> {
+    let status = |status: u8| {
+        ghost_runtime::IntentStatus::from_u8(status).map(intent_status_into_rpc)
+    };
+
+    events
+        .into_iter()
+        .filter_map(|event| {
+            let intent_id = notified_intent(&event).filter(|intent_id| wanted(*intent_id))?;
+            let RuntimeEvent::ChainGhost(event) = event else {
+                return None;
+            };
+
+            let update = match event {
+                ChainGhostEvent::IntentExecuted { account, .. } => {
+                    IntentUpdate::Created {
+                        account: account_into_rpc(account),
+                    }
+                },
+                ChainGhostEvent::IntentStatusUpdated {
+                    old_status,
+                    new_status,
+                    ..
+                } => IntentUpdate::StatusChanged {
+                    old_status: status(old_status)?,
+                    new_status: status(new_status)?,
+                },
+                ChainGhostEvent::JourneyRecorded { step_count, .. } => {
+                    let step = journey_step(intent_id, step_count.checked_sub(1)?)?;
+                    IntentUpdate::JourneyStep {
+                        step: journey_step_into_rpc(step, encoding),
+                    }
+                },
+                ChainGhostEvent::IntentCancelled { reason, .. } => IntentUpdate::Cancelled {
+                    reason: cancel_reason_into_rpc(reason),
+                },
+                ChainGhostEvent::IntentRemoved { .. } => IntentUpdate::Removed,
+                _ => return None,
+            };
+
+            Some(IntentNotification {
+                block_hash: hash,
+                intent_id,
+                update,
+            })
+        })
+        .collect()
+}
+
+/// Journey step reader of the block `hash`, reading each journey through the runtime API once
+fn journey_steps_at<C>(
+    client: &C,
+    hash: Hash,
+) -> impl FnMut(u64, u32) -> Option<JourneyStepData<AccountId, BlockNumber>> + '_
+where
+    C: ProvideRuntimeApi<Block>,
+    C::Api: ChainGhostRuntimeApi<Block, AccountId, BlockNumber>,
+{
+    let mut journeys = HashMap::new();
+
+    move |intent_id, step_id| {
+        journeys
+            .entry(intent_id)
+            .or_insert_with(|| {
+                client.runtime_api().get_journey_steps(hash, intent_id).unwrap_or_default()
+            })
+            .iter()
+            .find(|step| step.step_id == step_id)
+            .cloned()
+    }
+}
+
+/// Intents notified by `events` that belong to `account`
+///
+/// `owned` keeps the live intents of `account` across blocks. Intents of other accounts are not
+/// cached, so that it stays bounded by the intents of `account`. Owners are read from the event
+/// payloads first, as an intent removed within the block is already gone from its state. The
+/// other intents are resolved together by `owners_of`, which returns their owners in order.
+fn account_intents(
+    account: &AccountId,
+    owned: &mut HashSet<u64>,
+    events: &[RuntimeEvent],
+    owners_of: impl FnOnce(Vec<u64>) -> Vec<Option<AccountId>>,
+) -> HashSet<u64> {
+    let mut owners = HashMap::new();
+    let mut removed = Vec::new();
+    for event in events {
+        match event {
+            RuntimeEvent::ChainGhost(ChainGhostEvent::IntentExecuted {
+                intent_id,
+                account: owner,
+                ..
+            }) => {
+                owners.insert(*intent_id, owner.clone());
+            },
+            RuntimeEvent::ChainGhost(ChainGhostEvent::IntentRemoved {
+                intent_id,
+                account: owner,
+            }) => {
+                owners.insert(*intent_id, owner.clone());
+                removed.push(*intent_id);
+            },
+            _ => {},
+        }
+    }
+
+    let mut unknown: Vec<u64> = events
+        .iter()
+        .filter_map(notified_intent)
+        .filter(|intent_id| !owned.contains(intent_id) && !owners.contains_key(intent_id))
+        .collect();
+    unknown.sort_unstable();
+    unknown.dedup();
+    if !unknown.is_empty() {
+        let resolved = owners_of(unknown.clone());
+        owners.extend(
+            unknown
+                .into_iter()
+                .zip(resolved)
+                .filter_map(|(intent_id, owner)| Some((intent_id, owner?))),
+        );
+    }
+
+    owned.extend(
+        owners
+            .into_iter()
+            .filter(|(_, owner)| owner == account)
+            .map(|(intent_id, _)| intent_id),
+    );
+    let intents = events
+        .iter()
+        .filter_map(notified_intent)
+        .filter(|intent_id| owned.contains(intent_id))
+        .collect();
+    for intent_id in removed {
+        owned.remove(&intent_id);
+    }
+
+    intents
+}
+
+/// Inbox changes of `account` carried by `events`, the events of the block `hash`
+fn inbox_notifications(
+    hash: Hash,
+    events: Vec<RuntimeEvent>,
+    account: &AccountId,
+    encoding: BytesEncoding,
+) -> Vec<InboxNotification<Hash, AccountResponse, BlockNumber>> {
+    events
+        .into_iter()
+        .filter_map(|event| {
+            let update = match event {
+                RuntimeEvent::G3Mail(G3MailEvent::MessageSent {
+                    message_id,
+                    sender,
+                    recipient,
+                    cid,
+                    timestamp,
+                }) if recipient == *account => InboxUpdate::MessageSent {
+                    message: MessageResponse {
+                        message_id,
+                        sender: account_into_rpc(sender),
+                        recipient: account_into_rpc(recipient),
+                        cid: encoding.cid(cid.into_inner()),
+                        timestamp,
+                        read: false,
+                    },
+                },
+                RuntimeEvent::G3Mail(G3MailEvent::MessageRead {
+                    message_id,
+                    recipient,
+                }) if recipient == *account => InboxUpdate::MessageRead { message_id },
+                _ => return None,
+            };
+
+            Some(InboxNotification {
+                block_hash: hash,
+                update,
+            })
+        })
+        .collect()
+}
+
+/// Accounts following and unfollowing `account` in `events`, the events of the block `hash`
+fn follower_notifications(
+    hash: Hash,
+    events: Vec<RuntimeEvent>,
+    account: &AccountId,
+) -> Vec<FollowerNotification<Hash, AccountResponse>> {
+    events
+        .into_iter()
+        .filter_map(|event| {
+            let update = match event {
+                RuntimeEvent::Ghonity(GhonityEvent::Followed { follower, followee })
+                    if followee == *account =>
+                {
+                    FollowerUpdate::Followed {
+                        follower: account_into_rpc(follower),
+                    }
+                },
+                RuntimeEvent::Ghonity(GhonityEvent::Unfollowed { follower, followee })
+                    if followee == *account =>
+                {
+                    FollowerUpdate::Unfollowed {
+                        follower: account_into_rpc(follower),
+                    }
+                },
+                _ => return None,
+            };
+
+            Some(FollowerNotification {
+                block_hash: hash,
+                update,
+            })
+        })
+        .collect()
+}
+
+/// Reputation change of `account` over `events`, the events of the block `hash`
+///
+/// Updates within a block are coalesced: the score before the first update and after the last
+/// one are all that matters.
+fn reputation_notification(
+    hash: Hash,
+    events: Vec<RuntimeEvent>,
+    account: &AccountId,
+) -> Option<ReputationNotification<Hash>> {
+    events
+        .into_iter()
+        .filter_map(|event| match event {
+            RuntimeEvent::Ghonity(GhonityEvent::ReputationUpdated {
+                account: updated,
+                old_score,
+                new_score,
+            }) if updated == *account => Some((old_score, new_score)),
+            _ => None,
+        })
+        .reduce(|(old_score, _), (_, new_score)| (old_score, new_score))
+        .map(|(old_score, new_score)| ReputationNotification {
+            block_hash: hash,
+            old_score,
+            new_score,
+        })
+}
+
 #[rpc(client, server)]
 pub trait ChainGhostApi<BlockHash, AccountId, BlockNumber> {
     #[method(name = "chainghost_getIntent")]
@@ -30,10 +648,14 @@ pub trait ChainGhostApi<BlockHash, AccountId, BlockNumber> {
         at: Option<BlockHash>,
     ) -> RpcResult<Option<IntentResponse<AccountId, BlockNumber>>>;
 
+    /// Intents of `account`, oldest first, in pages of at most `limit` (capped at 100), each
+    /// page starting after `cursor`, the last intent of the previous page
     #[method(name = "chainghost_getIntentsByAccount")]
     fn get_intents_by_account(
         &self,
-        account: AccountId,
+        account: AccountAddress,
+        cursor: Option<u64>,
+        limit: u32,
         at: Option<BlockHash>,
     ) -> RpcResult<Vec<u64>>;
 
@@ -42,7 +664,15 @@ pub trait ChainGhostApi<BlockHash, AccountId, BlockNumber> {
         &self,
         intent_id: u64,
         at: Option<BlockHash>,
-    ) -> RpcResult<Vec<JourneyStepResponse<BlockNumber>>>;
+        encoding: Option<BytesEncoding>,
+    ) -> RpcResult<Vec<JourneyStepResponse<AccountId, BlockNumber>>>;
+
+    #[method(name = "chainghost_getJourneyHead")]
+    fn get_journey_head(
+        &self,
+        intent_id: u64,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<JourneyHeadResponse>>;
 
     #[method(name = "chainghost_getIntentStatus")]
     fn get_intent_status(
@@ -50,6 +680,63 @@ pub trait ChainGhostApi<BlockHash, AccountId, BlockNumber> {
         intent_id: u64,
         at: Option<BlockHash>,
     ) -> RpcResult<Option<IntentStatus>>;
+
+    #[method(name = "chainghost_getIntentTree")]
+    fn get_intent_tree(
+        &self,
+        intent_id: u64,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<IntentTreeResponse<AccountId>>>;
+
+    #[method(name = "chainghost_getReadySubIntents")]
+    fn get_ready_sub_intents(
+        &self,
+        intent_id: u64,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u32>>;
+
+    #[method(name = "chainghost_getPersona")]
+    fn get_persona(
+        &self,
+        account: AccountAddress,
+        at: Option<BlockHash>,
+    ) -> RpcResult<PersonaResponse<BlockNumber>>;
+
+    /// `chainghost_getIntent` for up to `MAX_BATCH_INTENTS` intents, in order, read at one block
+    #[method(name = "chainghost_getIntentsBatch")]
+    fn get_intents_batch(
+        &self,
+        intent_ids: Vec<u64>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<Option<IntentResponse<AccountId, BlockNumber>>>>;
+
+    /// Status changes, new journey steps, cancellation and removal of `intent_id`, from each
+    /// new best block, or each finalized block when `finalized` is set
+    #[subscription(
+        name = "chainghost_subscribeIntent" => "chainghost_intent",
+        unsubscribe = "chainghost_unsubscribeIntent",
+        item = IntentNotification<BlockHash, AccountId, BlockNumber>
+    )]
+    async fn subscribe_intent(
+        &self,
+        intent_id: u64,
+        finalized: Option<bool>,
+        encoding: Option<BytesEncoding>,
+    ) -> SubscriptionResult;
+
+    /// Creation and changes of the intents of `account`, as pushed by
+    /// `chainghost_subscribeIntent`
+    #[subscription(
+        name = "chainghost_subscribeAccountIntents" => "chainghost_accountIntent",
+        unsubscribe = "chainghost_unsubscribeAccountIntents",
+        item = IntentNotification<BlockHash, AccountId, BlockNumber>
+    )]
+    async fn subscribe_account_intents(
+        &self,
+        account: AccountAddress,
+        finalized: Option<bool>,
+        encoding: Option<BytesEncoding>,
+    ) -> SubscriptionResult;
 }
 
 #[rpc(client, server)]
@@ -57,55 +744,168 @@ pub trait G3MailApi<BlockHash, AccountId, BlockNumber> {
     #[method(name = "g3mail_getPublicKey")]
     fn get_public_key(
         &self,
-        account: AccountId,
+        account: AccountAddress,
         at: Option<BlockHash>,
-    ) -> RpcResult<Option<Vec<u8>>>;
+    ) -> RpcResult<Option<Bytes>>;
 
     #[method(name = "g3mail_getMessagesByRecipient")]
     fn get_messages_by_recipient(
         &self,
-        recipient: AccountId,
+        recipient: AccountAddress,
         at: Option<BlockHash>,
+        encoding: Option<BytesEncoding>,
     ) -> RpcResult<Vec<(u64, MessageResponse<AccountId, BlockNumber>)>>;
 
     #[method(name = "g3mail_getMessage")]
     fn get_message(
         &self,
-        recipient: AccountId,
+        recipient: AccountAddress,
         message_id: u64,
         at: Option<BlockHash>,
+        encoding: Option<BytesEncoding>,
     ) -> RpcResult<Option<MessageResponse<AccountId, BlockNumber>>>;
 
     #[method(name = "g3mail_getInboxCount")]
-    fn get_inbox_count(&self, account: AccountId, at: Option<BlockHash>) -> RpcResult<u32>;
+    fn get_inbox_count(&self, account: AccountAddress, at: Option<BlockHash>) -> RpcResult<u32>;
+
+    /// `g3mail_getPublicKey` for up to `MAX_BATCH_ACCOUNTS` accounts, in order, read at one
+    /// block
+    #[method(name = "g3mail_getPublicKeysBatch")]
+    fn get_public_keys_batch(
+        &self,
+        accounts: Vec<AccountAddress>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<Option<Bytes>>>;
+
+    /// Messages sent to `account` and marked as read, from each new best block, or each
+    /// finalized block when `finalized` is set
+    #[subscription(
+        name = "g3mail_subscribeInbox" => "g3mail_inbox",
+        unsubscribe = "g3mail_unsubscribeInbox",
+        item = InboxNotification<BlockHash, AccountId, BlockNumber>
+    )]
+    async fn subscribe_inbox(
+        &self,
+        account: AccountAddress,
+        finalized: Option<bool>,
+        encoding: Option<BytesEncoding>,
+    ) -> SubscriptionResult;
 }
 
 #[rpc(client, server)]
-pub trait GhonityApi<BlockHash, AccountId> {
+pub trait GhonityApi<BlockHash, AccountId, BlockNumber> {
     #[method(name = "ghonity_isFollowing")]
     fn is_following(
         &self,
-        follower: AccountId,
-        followee: AccountId,
+        follower: AccountAddress,
+        followee: AccountAddress,
         at: Option<BlockHash>,
     ) -> RpcResult<bool>;
 
     #[method(name = "ghonity_getFollowerCount")]
-    fn get_follower_count(&self, account: AccountId, at: Option<BlockHash>) -> RpcResult<u32>;
+    fn get_follower_count(&self, account: AccountAddress, at: Option<BlockHash>) -> RpcResult<u32>;
 
     #[method(name = "ghonity_getFollowingCount")]
-    fn get_following_count(&self, account: AccountId, at: Option<BlockHash>) -> RpcResult<u32>;
+    fn get_following_count(&self, account: AccountAddress, at: Option<BlockHash>) -> RpcResult<u32>;
 
     #[method(name = "ghonity_getReputationScore")]
-    fn get_reputation_score(&self, account: AccountId, at: Option<BlockHash>) -> RpcResult<u32>;
+    fn get_reputation_score(
+        &self,
+        account: AccountAddress,
+        at: Option<BlockHash>,
+    ) -> RpcResult<u32>;
+
+    #[method(name = "ghonity_getFollowerStats")]
+    fn get_follower_stats(
+        &self,
+        account: AccountAddress,
+        at: Option<BlockHash>,
+    ) -> RpcResult<FollowerStatsResponse>;
+
+    #[method(name = "ghonity_getFollowEdge")]
+    fn get_follow_edge(
+        &self,
+        follower: AccountAddress,
+        followee: AccountAddress,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<FollowEdgeResponse<BlockNumber>>>;
+
+    #[method(name = "ghonity_getMutualFollows")]
+    fn get_mutual_follows(
+        &self,
+        a: AccountAddress,
+        b: AccountAddress,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<AccountId>>;
+
+    #[method(name = "ghonity_getCommonFollowers")]
+    fn get_common_followers(
+        &self,
+        a: AccountAddress,
+        b: AccountAddress,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<AccountId>>;
+
+    #[method(name = "ghonity_getFollowSuggestions")]
+    fn get_follow_suggestions(
+        &self,
+        account: AccountAddress,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<FollowSuggestionResponse<AccountId>>>;
+
+    #[method(name = "ghonity_getFeed")]
+    fn get_feed(
+        &self,
+        account: AccountAddress,
+        since_block: BlockNumber,
+        limit: u32,
+        at: Option<BlockHash>,
+        encoding: Option<BytesEncoding>,
+    ) -> RpcResult<Vec<FeedItemResponse<AccountId, BlockNumber>>>;
+
+    /// Follower, following and reputation counters of up to `MAX_BATCH_ACCOUNTS` accounts, in
+    /// order, read at one block
+    #[method(name = "ghonity_getStatsBatch")]
+    fn get_stats_batch(
+        &self,
+        accounts: Vec<AccountAddress>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<AccountStatsResponse>>;
+
+    /// Accounts following and unfollowing `account`, from each new best block, or each
+    /// finalized block when `finalized` is set
+    #[subscription(
+        name = "ghonity_subscribeFollowers" => "ghonity_follower",
+        unsubscribe = "ghonity_unsubscribeFollowers",
+        item = FollowerNotification<BlockHash, AccountId>
+    )]
+    async fn subscribe_followers(
+        &self,
+        account: AccountAddress,
+        finalized: Option<bool>,
+    ) -> SubscriptionResult;
+
+    /// Reputation changes of `account`, one notification per block, from each new best block,
+    /// or each finalized block when `finalized` is set
+    #[subscription(
+        name = "ghonity_subscribeReputation" => "ghonity_reputation",
+        unsubscribe = "ghonity_unsubscribeReputation",
+        item = ReputationNotification<BlockHash>
+    )]
+    async fn subscribe_reputation(
+        &self,
+        account: AccountAddress,
+        finalized: Option<bool>,
+    ) -> SubscriptionResult;
 }
 
-pub struct ChainGhost<C, Block> {
+pub struct ChainGhost<C, Block, B> {
     client: Arc<C>,
-    _marker: std::marker::PhantomData<Block>,
+    _marker: std::marker::PhantomData<(Block, B)>,
 }
 
-impl<C, Block> ChainGhost<C, Block> {
+impl<C, Block, B> ChainGhost<C, Block, B> {
     pub fn new(client: Arc<C>) -> Self {
         Self {
             client,
@@ -114,46 +914,44 @@ impl<C, Block> ChainGhost<C, Block> {
     }
 }
 
-impl<C> ChainGhostApiServer<<Block as BlockT>::Hash, AccountId, BlockNumber>
-    for ChainGhost<C, Block>
+#[jsonrpsee::core::async_trait]
+impl<C, B> ChainGhostApiServer<<Block as BlockT>::Hash, AccountResponse, BlockNumber>
+    for ChainGhost<C, Block, B>
 where
     C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C: BlockchainEvents<Block> + StorageProvider<Block, B>,
     C::Api: ChainGhostRuntimeApi<Block, AccountId, BlockNumber>,
+    B: Backend<Block> + Send + Sync + 'static,
 {
     fn get_intent(
         &self,
         intent_id: u64,
         at: Option<<Block as BlockT>::Hash>,
-    ) -> RpcResult<Option<IntentResponse<AccountId, BlockNumber>>> {
+    ) -> RpcResult<Option<IntentResponse<AccountResponse, BlockNumber>>> {
         let api = self.client.runtime_api();
         let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version::<ChainGhostRuntime>(&*api, at_hash)?;
 
         let intent_data = api
             .get_intent(at_hash, intent_id)
             .map_err(runtime_error_into_rpc_error)?;
 
-        Ok(intent_data.map(|data| IntentResponse {
-            intent_id: data.intent_id,
-            account: data.account,
-            status: match data.status {
-                ghost_runtime::IntentStatus::Pending => IntentStatus::Pending,
-                ghost_runtime::IntentStatus::Executed => IntentStatus::Executed,
-                ghost_runtime::IntentStatus::Failed => IntentStatus::Failed,
-            },
-            timestamp: data.timestamp,
-            metadata: data.metadata,
-        }))
+        Ok(intent_data.map(intent_into_rpc))
     }
 
     fn get_intents_by_account(
         &self,
-        account: AccountId,
+        account: AccountAddress,
+        cursor: Option<u64>,
+        limit: u32,
         at: Option<<Block as BlockT>::Hash>,
     ) -> RpcResult<Vec<u64>> {
+        let account = resolve_account(account)?;
         let api = self.client.runtime_api();
         let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version::<ChainGhostRuntime>(&*api, at_hash)?;
 
-        api.get_intents_by_account(at_hash, account)
+        api.get_intents_by_account(at_hash, account, cursor, limit)
             .map_err(runtime_error_into_rpc_error)
     }
 
@@ -161,22 +959,37 @@ where
         &self,
         intent_id: u64,
         at: Option<<Block as BlockT>::Hash>,
-    ) -> RpcResult<Vec<JourneyStepResponse<BlockNumber>>> {
+        encoding: Option<BytesEncoding>,
+    ) -> RpcResult<Vec<JourneyStepResponse<AccountResponse, BlockNumber>>> {
         let api = self.client.runtime_api();
         let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version::<ChainGhostRuntime>(&*api, at_hash)?;
 
         let steps = api
             .get_journey_steps(at_hash, intent_id)
             .map_err(runtime_error_into_rpc_error)?;
 
-        Ok(steps
-            .into_iter()
-            .map(|step| JourneyStepResponse {
-                step_id: step.step_id,
-                description: step.description,
-                timestamp: step.timestamp,
-            })
-            .collect())
+        let encoding = encoding.unwrap_or_default();
+        Ok(steps.into_iter().map(|step| journey_step_into_rpc(step, encoding)).collect())
+    }
+
+    fn get_journey_head(
+        &self,
+        intent_id: u64,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<JourneyHeadResponse>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version::<ChainGhostRuntime>(&*api, at_hash)?;
+
+        let head = api
+            .get_journey_head(at_hash, intent_id)
+            .map_err(runtime_error_into_rpc_error)?;
+
+        Ok(head.map(|head| JourneyHeadResponse {
+            step_count: head.step_count,
+            hash: head.hash,
+        }))
     }
 
     fn get_intent_status(
@@ -186,25 +999,204 @@ where
     ) -> RpcResult<Option<IntentStatus>> {
         let api = self.client.runtime_api();
         let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version::<ChainGhostRuntime>(&*api, at_hash)?;
 
         let status = api
             .get_intent_status(at_hash, intent_id)
             .map_err(runtime_error_into_rpc_error)?;
 
-        Ok(status.map(|s| match s {
-            ghost_runtime::IntentStatus::Pending => IntentStatus::Pending,
-            ghost_runtime::IntentStatus::Executed => IntentStatus::Executed,
-            ghost_runtime::IntentStatus::Failed => IntentStatus::Failed,
+        Ok(status.map(intent_status_into_rpc))
+    }
+
+    fn get_intent_tree(
+        &self,
+        intent_id: u64,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<IntentTreeResponse<AccountResponse>>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version::<ChainGhostRuntime>(&*api, at_hash)?;
+
+        let tree = api
+            .get_intent_tree(at_hash, intent_id)
+            .map_err(runtime_error_into_rpc_error)?;
+
+        Ok(tree.map(|tree| IntentTreeResponse {
+            intent_id: tree.intent_id,
+            status: intent_status_into_rpc(tree.status),
+            sub_intents: tree
+                .sub_intents
+                .into_iter()
+                .map(|sub| SubIntentResponse {
+                    index: sub.index,
+                    action: intent_action_into_rpc(sub.action),
+                    depends_on: sub.depends_on,
+                    status: intent_status_into_rpc(sub.status),
+                })
+                .collect(),
+            ready: tree.ready,
         }))
     }
+
+    fn get_ready_sub_intents(
+        &self,
+        intent_id: u64,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u32>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version::<ChainGhostRuntime>(&*api, at_hash)?;
+
+        api.get_ready_sub_intents(at_hash, intent_id)
+            .map_err(runtime_error_into_rpc_error)
+    }
+
+    fn get_persona(
+        &self,
+        account: AccountAddress,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<PersonaResponse<BlockNumber>> {
+        let account = resolve_account(account)?;
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version::<ChainGhostRuntime>(&*api, at_hash)?;
+
+        let persona = api
+            .get_persona(at_hash, account)
+            .map_err(runtime_error_into_rpc_error)?;
+
+        Ok(PersonaResponse {
+            intents_created: persona.intents_created,
+            intents_executed: persona.intents_executed,
+            intents_failed: persona.intents_failed,
+            success_ratio: persona.success_ratio,
+            distinct_kinds: persona.distinct_kinds,
+            journey_steps: persona.journey_steps,
+            achievements: persona
+                .achievements
+                .into_iter()
+                .map(|(achievement, unlocked_at)| AchievementResponse {
+                    kind: achievement_kind_into_rpc(achievement.kind),
+                    threshold: achievement.threshold,
+                    unlocked_at,
+                })
+                .collect(),
+        })
+    }
+
+    fn get_intents_batch(
+        &self,
+        intent_ids: Vec<u64>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<Option<IntentResponse<AccountResponse, BlockNumber>>>> {
+        ensure_batch_size(intent_ids.len(), MAX_BATCH_INTENTS)?;
+
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version::<ChainGhostRuntime>(&*api, at_hash)?;
+
+        let intents = api
+            .get_intents_batch(at_hash, intent_ids)
+            .map_err(runtime_error_into_rpc_error)?;
+
+        Ok(intents.into_iter().map(|data| data.map(intent_into_rpc)).collect())
+    }
+
+    async fn subscribe_intent(
+        &self,
+        pending: PendingSubscriptionSink,
+        intent_id: u64,
+        finalized: Option<bool>,
+        encoding: Option<BytesEncoding>,
+    ) -> SubscriptionResult {
+        let supported = ensure_api_version::<ChainGhostRuntime>(
+            &*self.client.runtime_api(),
+            self.client.info().best_hash,
+        );
+        if let Err(err) = supported {
+            pending.reject(err).await;
+            return Ok(());
+        }
+        let client = self.client.clone();
+        let blocks = block_stream(&*client, finalized.unwrap_or(false));
+        let encoding = encoding.unwrap_or_default();
+
+        pipe_block_notifications(pending, blocks, |hash| {
+            intent_notifications(
+                hash,
+                block_events(&*client, hash),
+                encoding,
+                |id| id == intent_id,
+                journey_steps_at(&*client, hash),
+            )
+        })
+        .await
+    }
+
+    async fn subscribe_account_intents(
+        &self,
+        pending: PendingSubscriptionSink,
+        account: AccountAddress,
+        finalized: Option<bool>,
+        encoding: Option<BytesEncoding>,
+    ) -> SubscriptionResult {
+        let supported = ensure_api_version::<ChainGhostRuntime>(
+            &*self.client.runtime_api(),
+            self.client.info().best_hash,
+        );
+        if let Err(err) = supported {
+            pending.reject(err).await;
+            return Ok(());
+        }
+        let account = match resolve_account(account) {
+            Ok(account) => account,
+            Err(err) => {
+                pending.reject(err).await;
+                return Ok(());
+            },
+        };
+        let client = self.client.clone();
+        let blocks = block_stream(&*client, finalized.unwrap_or(false));
+        let encoding = encoding.unwrap_or_default();
+        // Live intents known to belong to `account`
+        let mut owned = HashSet::new();
+
+        pipe_block_notifications(pending, blocks, |hash| {
+            let events = block_events(&*client, hash);
+            // The intents of unknown owners are looked up together, once per block
+            let intents = account_intents(&account, &mut owned, &events, |intent_ids| {
+                intent_ids
+                    .chunks(MAX_BATCH_INTENTS as usize)
+                    .flat_map(|chunk| {
+                        let mut intents = client
+                            .runtime_api()
+                            .get_intents_batch(hash, chunk.to_vec())
+                            .unwrap_or_default();
+                        intents.resize_with(chunk.len(), || None);
+                        intents
+                    })
+                    .map(|intent| intent.map(|intent| intent.account))
+                    .collect()
+            });
+
+            intent_notifications(
+                hash,
+                events,
+                encoding,
+                |intent_id| intents.contains(&intent_id),
+                journey_steps_at(&*client, hash),
+            )
+        })
+        .await
+    }
 }
 
-pub struct G3Mail<C, Block> {
+pub struct G3Mail<C, Block, B> {
     client: Arc<C>,
-    _marker: std::marker::PhantomData<Block>,
+    _marker: std::marker::PhantomData<(Block, B)>,
 }
 
-impl<C, Block> G3Mail<C, Block> {
+impl<C, Block, B> G3Mail<C, Block, B> {
     pub fn new(client: Arc<C>) -> Self {
         Self {
             client,
@@ -213,95 +1205,138 @@ impl<C, Block> G3Mail<C, Block> {
     }
 }
 
-impl<C> G3MailApiServer<<Block as BlockT>::Hash, AccountId, BlockNumber> for G3Mail<C, Block>
+#[jsonrpsee::core::async_trait]
+impl<C, B> G3MailApiServer<<Block as BlockT>::Hash, AccountResponse, BlockNumber>
+    for G3Mail<C, Block, B>
 where
     C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C: BlockchainEvents<Block> + StorageProvider<Block, B>,
     C::Api: G3MailRuntimeApi<Block, AccountId, BlockNumber>,
+    B: Backend<Block> + Send + Sync + 'static,
 {
     fn get_public_key(
         &self,
-        account: AccountId,
+        account: AccountAddress,
         at: Option<<Block as BlockT>::Hash>,
-    ) -> RpcResult<Option<Vec<u8>>> {
+    ) -> RpcResult<Option<Bytes>> {
+        let account = resolve_account(account)?;
         let api = self.client.runtime_api();
         let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version::<G3MailRuntime>(&*api, at_hash)?;
 
-        api.get_public_key(at_hash, account)
-            .map_err(runtime_error_into_rpc_error)
+        let key = api
+            .get_public_key(at_hash, account)
+            .map_err(runtime_error_into_rpc_error)?;
+
+        Ok(key.map(Bytes::hex))
     }
 
     fn get_messages_by_recipient(
         &self,
-        recipient: AccountId,
+        recipient: AccountAddress,
         at: Option<<Block as BlockT>::Hash>,
-    ) -> RpcResult<Vec<(u64, MessageResponse<AccountId, BlockNumber>)>> {
+        encoding: Option<BytesEncoding>,
+    ) -> RpcResult<Vec<(u64, MessageResponse<AccountResponse, BlockNumber>)>> {
+        let recipient = resolve_account(recipient)?;
         let api = self.client.runtime_api();
         let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version::<G3MailRuntime>(&*api, at_hash)?;
 
         let messages = api
             .get_messages_by_recipient(at_hash, recipient)
             .map_err(runtime_error_into_rpc_error)?;
 
+        let encoding = encoding.unwrap_or_default();
         Ok(messages
             .into_iter()
-            .map(|(id, msg)| {
-                (
-                    id,
-                    MessageResponse {
-                        message_id: msg.message_id,
-                        sender: msg.sender,
-                        recipient: msg.recipient,
-                        cid: msg.cid,
-                        timestamp: msg.timestamp,
-                        read: msg.read,
-                    },
-                )
-            })
+            .map(|(id, msg)| (id, message_into_rpc(msg, encoding)))
             .collect())
     }
 
     fn get_message(
         &self,
-        recipient: AccountId,
+        recipient: AccountAddress,
         message_id: u64,
         at: Option<<Block as BlockT>::Hash>,
-    ) -> RpcResult<Option<MessageResponse<AccountId, BlockNumber>>> {
+        encoding: Option<BytesEncoding>,
+    ) -> RpcResult<Option<MessageResponse<AccountResponse, BlockNumber>>> {
+        let recipient = resolve_account(recipient)?;
         let api = self.client.runtime_api();
         let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version::<G3MailRuntime>(&*api, at_hash)?;
 
         let message = api
             .get_message(at_hash, recipient, message_id)
             .map_err(runtime_error_into_rpc_error)?;
 
-        Ok(message.map(|msg| MessageResponse {
-            message_id: msg.message_id,
-            sender: msg.sender,
-            recipient: msg.recipient,
-            cid: msg.cid,
-            timestamp: msg.timestamp,
-            read: msg.read,
-        }))
+        let encoding = encoding.unwrap_or_default();
+        Ok(message.map(|msg| message_into_rpc(msg, encoding)))
     }
 
     fn get_inbox_count(
         &self,
-        account: AccountId,
+        account: AccountAddress,
         at: Option<<Block as BlockT>::Hash>,
     ) -> RpcResult<u32> {
+        let account = resolve_account(account)?;
         let api = self.client.runtime_api();
         let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version::<G3MailRuntime>(&*api, at_hash)?;
 
         api.get_inbox_count(at_hash, account)
             .map_err(runtime_error_into_rpc_error)
     }
+
+    fn get_public_keys_batch(
+        &self,
+        accounts: Vec<AccountAddress>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<Option<Bytes>>> {
+        ensure_batch_size(accounts.len(), MAX_BATCH_ACCOUNTS)?;
+        let accounts = resolve_accounts(accounts)?;
+
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version::<G3MailRuntime>(&*api, at_hash)?;
+
+        let keys = api
+            .get_public_keys_batch(at_hash, accounts)
+            .map_err(runtime_error_into_rpc_error)?;
+
+        Ok(keys.into_iter().map(|key| key.map(Bytes::hex)).collect())
+    }
+
+    async fn subscribe_inbox(
+        &self,
+        pending: PendingSubscriptionSink,
+        account: AccountAddress,
+        finalized: Option<bool>,
+        encoding: Option<BytesEncoding>,
+    ) -> SubscriptionResult {
+        let account = match resolve_account(account) {
+            Ok(account) => account,
+            Err(err) => {
+                pending.reject(err).await;
+                return Ok(());
+            },
+        };
+        let client = self.client.clone();
+        let blocks = block_stream(&*client, finalized.unwrap_or(false));
+        let encoding = encoding.unwrap_or_default();
+
+        pipe_block_notifications(pending, blocks, |hash| {
+            inbox_notifications(hash, block_events(&*client, hash), &account, encoding)
+        })
+        .await
+    }
 }
 
-pub struct Ghonity<C, Block> {
+pub struct Ghonity<C, Block, B> {
     client: Arc<C>,
-    _marker: std::marker::PhantomData<Block>,
+    _marker: std::marker::PhantomData<(Block, B)>,
 }
 
-impl<C, Block> Ghonity<C, Block> {
+impl<C, Block, B> Ghonity<C, Block, B> {
     pub fn new(client: Arc<C>) -> Self {
         Self {
             client,
@@ -310,19 +1345,25 @@ impl<C, Block> Ghonity<C, Block> {
     }
 }
 
-impl<C> GhonityApiServer<<Block as BlockT>::Hash, AccountId> for Ghonity<C, Block>
+#[jsonrpsee::core::async_trait]
+impl<C, B> GhonityApiServer<<Block as BlockT>::Hash, AccountResponse, BlockNumber>
+    for Ghonity<C, Block, B>
 where
     C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
-    C::Api: GhonityRuntimeApi<Block, AccountId>,
+    C: BlockchainEvents<Block> + StorageProvider<Block, B>,
+    C::Api: GhonityRuntimeApi<Block, AccountId, BlockNumber>,
+    B: Backend<Block> + Send + Sync + 'static,
 {
     fn is_following(
         &self,
-        follower: AccountId,
-        followee: AccountId,
+        follower: AccountAddress,
+        followee: AccountAddress,
         at: Option<<Block as BlockT>::Hash>,
     ) -> RpcResult<bool> {
+        let (follower, followee) = (resolve_account(follower)?, resolve_account(followee)?);
         let api = self.client.runtime_api();
         let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version::<GhonityRuntime>(&*api, at_hash)?;
 
         api.is_following(at_hash, follower, followee)
             .map_err(runtime_error_into_rpc_error)
@@ -330,11 +1371,13 @@ where
 
     fn get_follower_count(
         &self,
-        account: AccountId,
+        account: AccountAddress,
         at: Option<<Block as BlockT>::Hash>,
     ) -> RpcResult<u32> {
+        let account = resolve_account(account)?;
         let api = self.client.runtime_api();
         let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version::<GhonityRuntime>(&*api, at_hash)?;
 
         api.get_follower_count(at_hash, account)
             .map_err(runtime_error_into_rpc_error)
@@ -342,11 +1385,13 @@ where
 
     fn get_following_count(
         &self,
-        account: AccountId,
+        account: AccountAddress,
         at: Option<<Block as BlockT>::Hash>,
     ) -> RpcResult<u32> {
+        let account = resolve_account(account)?;
         let api = self.client.runtime_api();
         let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version::<GhonityRuntime>(&*api, at_hash)?;
 
         api.get_following_count(at_hash, account)
             .map_err(runtime_error_into_rpc_error)
@@ -354,13 +1399,579 @@ where
 
     fn get_reputation_score(
         &self,
-        account: AccountId,
+        account: AccountAddress,
         at: Option<<Block as BlockT>::Hash>,
     ) -> RpcResult<u32> {
+        let account = resolve_account(account)?;
         let api = self.client.runtime_api();
         let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version::<GhonityRuntime>(&*api, at_hash)?;
 
         api.get_reputation_score(at_hash, account)
             .map_err(runtime_error_into_rpc_error)
     }
+
+    fn get_follower_stats(
+        &self,
+        account: AccountAddress,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<FollowerStatsResponse> {
+        let account = resolve_account(account)?;
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version::<GhonityRuntime>(&*api, at_hash)?;
+
+        let stats = api
+            .get_follower_stats(at_hash, account)
+            .map_err(runtime_error_into_rpc_error)?;
+
+        Ok(FollowerStatsResponse {
+            follower_count: stats.follower_count,
+            weighted_score: stats.weighted_score,
+        })
+    }
+
+    fn get_follow_edge(
+        &self,
+        follower: AccountAddress,
+        followee: AccountAddress,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<FollowEdgeResponse<BlockNumber>>> {
+        let (follower, followee) = (resolve_account(follower)?, resolve_account(followee)?);
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version::<GhonityRuntime>(&*api, at_hash)?;
+
+        let edge = api
+            .get_follow_edge(at_hash, follower, followee)
+            .map_err(runtime_error_into_rpc_error)?;
+
+        Ok(edge.map(|e| FollowEdgeResponse {
+            since: e.since,
+            age: e.age,
+            tags: e.tags,
+        }))
+    }
+
+    fn get_mutual_follows(
+        &self,
+        a: AccountAddress,
+        b: AccountAddress,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<AccountResponse>> {
+        let (a, b) = (resolve_account(a)?, resolve_account(b)?);
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version::<GhonityRuntime>(&*api, at_hash)?;
+
+        let accounts = api
+            .get_mutual_follows(at_hash, a, b)
+            .map_err(runtime_error_into_rpc_error)?;
+
+        Ok(accounts.into_iter().map(account_into_rpc).collect())
+    }
+
+    fn get_common_followers(
+        &self,
+        a: AccountAddress,
+        b: AccountAddress,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<AccountResponse>> {
+        let (a, b) = (resolve_account(a)?, resolve_account(b)?);
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version::<GhonityRuntime>(&*api, at_hash)?;
+
+        let accounts = api
+            .get_common_followers(at_hash, a, b)
+            .map_err(runtime_error_into_rpc_error)?;
+
+        Ok(accounts.into_iter().map(account_into_rpc).collect())
+    }
+
+    fn get_follow_suggestions(
+        &self,
+        account: AccountAddress,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<FollowSuggestionResponse<AccountResponse>>> {
+        let account = resolve_account(account)?;
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version::<GhonityRuntime>(&*api, at_hash)?;
+
+        let suggestions = api
+            .get_follow_suggestions(at_hash, account, limit)
+            .map_err(runtime_error_into_rpc_error)?;
+
+        Ok(suggestions
+            .into_iter()
+            .map(|s| FollowSuggestionResponse {
+                account: account_into_rpc(s.account),
+                reputation: s.reputation,
+                mutual_count: s.mutual_count,
+            })
+            .collect())
+    }
+
+    fn get_feed(
+        &self,
+        account: AccountAddress,
+        since_block: BlockNumber,
+        limit: u32,
+        at: Option<<Block as BlockT>::Hash>,
+        encoding: Option<BytesEncoding>,
+    ) -> RpcResult<Vec<FeedItemResponse<AccountResponse, BlockNumber>>> {
+        let account = resolve_account(account)?;
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version::<GhonityRuntime>(&*api, at_hash)?;
+
+        let items = api
+            .get_feed(at_hash, account, since_block, limit)
+            .map_err(runtime_error_into_rpc_error)?;
+
+        let encoding = encoding.unwrap_or_default();
+        Ok(items
+            .into_iter()
+            .map(|item| {
+                // Intent items carry the intent metadata, journey step items its description
+                let (kind, content) = match item.kind {
+                    ghost_runtime::apis::ghost_protocol::FeedItemKind::Intent => {
+                        (FeedItemKind::Intent, Bytes::hex(item.content))
+                    },
+                    ghost_runtime::apis::ghost_protocol::FeedItemKind::JourneyStep { step_id } => {
+                        (FeedItemKind::JourneyStep { step_id }, encoding.text(item.content))
+                    },
+                };
+                FeedItemResponse {
+                    account: account_into_rpc(item.account),
+                    intent_id: item.intent_id,
+                    kind,
+                    status: intent_status_into_rpc(item.status),
+                    block_number: item.block_number,
+                    content,
+                }
+            })
+            .collect())
+    }
+
+    fn get_stats_batch(
+        &self,
+        accounts: Vec<AccountAddress>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<AccountStatsResponse>> {
+        ensure_batch_size(accounts.len(), MAX_BATCH_ACCOUNTS)?;
+        let accounts = resolve_accounts(accounts)?;
+
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version::<GhonityRuntime>(&*api, at_hash)?;
+
+        let stats = api
+            .get_stats_batch(at_hash, accounts)
+            .map_err(runtime_error_into_rpc_error)?;
+
+        Ok(stats
+            .into_iter()
+            .map(|stats| AccountStatsResponse {
+                follower_count: stats.follower_count,
+                following_count: stats.following_count,
+                reputation_score: stats.reputation_score,
+                weighted_score: stats.weighted_score,
+            })
+            .collect())
+    }
+
+    async fn subscribe_followers(
+        &self,
+        pending: PendingSubscriptionSink,
+        account: AccountAddress,
+        finalized: Option<bool>,
+    ) -> SubscriptionResult {
+        let account = match resolve_account(account) {
+            Ok(account) => account,
+            Err(err) => {
+                pending.reject(err).await;
+                return Ok(());
+            },
+        };
+        let client = self.client.clone();
+        let blocks = block_stream(&*client, finalized.unwrap_or(false));
+
+        pipe_block_notifications(pending, blocks, |hash| {
+            follower_notifications(hash, block_events(&*client, hash), &account)
+        })
+        .await
+    }
+
+    async fn subscribe_reputation(
+        &self,
+        pending: PendingSubscriptionSink,
+        account: AccountAddress,
+        finalized: Option<bool>,
+    ) -> SubscriptionResult {
+        let account = match resolve_account(account) {
+            Ok(account) => account,
+            Err(err) => {
+                pending.reject(err).await;
+                return Ok(());
+            },
+        };
+        let client = self.client.clone();
+        let blocks = block_stream(&*client, finalized.unwrap_or(false));
+
+        pipe_block_notifications(pending, blocks, |hash| {
+            reputation_notification(hash, block_events(&*client, hash), &account)
+                .into_iter()
+                .collect()
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    const ALICE: AccountId = AccountId::new([1; 32]);
+    const BOB: AccountId = AccountId::new([2; 32]);
+
+    fn block() -> Hash {
+        Hash::repeat_byte(0xb1)
+    }
+
+    fn executed(intent_id: u64, account: AccountId) -> RuntimeEvent {
+        RuntimeEvent::ChainGhost(ChainGhostEvent::IntentExecuted {
+            intent_id,
+            account,
+            timestamp: 1,
+        })
+    }
+
+    fn journey_recorded(intent_id: u64, step_count: u32) -> RuntimeEvent {
+        RuntimeEvent::ChainGhost(ChainGhostEvent::JourneyRecorded {
+            intent_id,
+            step_count,
+        })
+    }
+
+    fn cancelled(intent_id: u64) -> RuntimeEvent {
+        RuntimeEvent::ChainGhost(ChainGhostEvent::IntentCancelled {
+            intent_id,
+            reason: ghost_runtime::CancelReason::UserRequested,
+        })
+    }
+
+    fn removed(intent_id: u64, account: AccountId) -> RuntimeEvent {
+        RuntimeEvent::ChainGhost(ChainGhostEvent::IntentRemoved { intent_id, account })
+    }
+
+    fn step(step_id: u32) -> JourneyStepData<AccountId, BlockNumber> {
+        JourneyStepData {
+            step_id,
+            step_kind: ghost_runtime::JourneyStepKind::Note,
+            description: b"step".to_vec(),
+            timestamp: 1,
+            author: ALICE,
+            linked_tx: None,
+            attachment: None,
+        }
+    }
+
+    fn updates(
+        notifications: Vec<IntentNotification<Hash, AccountResponse, BlockNumber>>,
+    ) -> Vec<(u64, IntentUpdate<AccountResponse, BlockNumber>)> {
+        notifications
+            .into_iter()
+            .map(|notification| {
+                assert_eq!(notification.block_hash, block());
+                (notification.intent_id, notification.update)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn intent_events_map_to_notifications() {
+        let events = vec![
+            executed(1, ALICE),
+            RuntimeEvent::ChainGhost(ChainGhostEvent::IntentStatusUpdated {
+                intent_id: 1,
+                old_status: 0,
+                new_status: 1,
+            }),
+            journey_recorded(1, 1),
+            journey_recorded(1, 2),
+            cancelled(1),
+            removed(1, ALICE),
+            // Not relayed, or not wanted
+            RuntimeEvent::ChainGhost(ChainGhostEvent::TemplateCreated {
+                account: ALICE,
+                template_id: 0,
+            }),
+            executed(2, BOB),
+            journey_recorded(2, 1),
+        ];
+
+        let read = RefCell::new(Vec::new());
+        let notifications = intent_notifications(
+            block(),
+            events,
+            BytesEncoding::default(),
+            |intent_id| intent_id == 1,
+            |intent_id, step_id| {
+                read.borrow_mut().push((intent_id, step_id));
+                Some(step(step_id))
+            },
+        );
+
+        let journey_step = |step_id| IntentUpdate::JourneyStep {
+            step: journey_step_into_rpc(step(step_id), BytesEncoding::default()),
+        };
+        assert_eq!(
+            updates(notifications),
+            vec![
+                (1, IntentUpdate::Created { account: account_into_rpc(ALICE) }),
+                (
+                    1,
+                    IntentUpdate::StatusChanged {
+                        old_status: IntentStatus::Pending,
+                        new_status: IntentStatus::Executed,
+                    }
+                ),
+                (1, journey_step(0)),
+                (1, journey_step(1)),
+                (
+                    1,
+                    IntentUpdate::Cancelled {
+                        reason: CancelReason::UserRequested,
+                    }
+                ),
+                (1, IntentUpdate::Removed),
+            ]
+        );
+        // Journeys are only read for the kept changes
+        assert_eq!(read.into_inner(), vec![(1, 0), (1, 1)]);
+    }
+
+    #[test]
+    fn steps_that_cannot_be_read_back_are_skipped() {
+        let notifications = intent_notifications(
+            block(),
+            vec![journey_recorded(1, 0), journey_recorded(1, 1)],
+            BytesEncoding::default(),
+            |_| true,
+            |_, _| None,
+        );
+
+        assert!(notifications.is_empty());
+    }
+
+    #[test]
+    fn account_intents_are_resolved_once_per_block() {
+        let mut owned = HashSet::new();
+
+        // Owners of new intents come from their events, the others are looked up together
+        let lookups = RefCell::new(Vec::new());
+        let lookup = |intent_ids: Vec<u64>| {
+            lookups.borrow_mut().push(intent_ids.clone());
+            intent_ids
+                .into_iter()
+                .map(|intent_id| match intent_id {
+                    3 => Some(ALICE),
+                    4 => Some(BOB),
+                    _ => None,
+                })
+                .collect()
+        };
+        let events = vec![
+            executed(1, ALICE),
+            executed(2, BOB),
+            journey_recorded(4, 1),
+            journey_recorded(3, 1),
+            cancelled(3),
+            cancelled(5),
+        ];
+        let intents = account_intents(&ALICE, &mut owned, &events, lookup);
+        assert_eq!(intents, HashSet::from([1, 3]));
+        assert_eq!(lookups.take(), vec![vec![3, 4, 5]]);
+        assert_eq!(owned, HashSet::from([1, 3]));
+
+        // Known intents are not looked up again, unlike those of other accounts
+        let intents = account_intents(&ALICE, &mut owned, &[cancelled(1), cancelled(4)], lookup);
+        assert_eq!(intents, HashSet::from([1]));
+        assert_eq!(lookups.take(), vec![vec![4]]);
+
+        // Nothing to look up, nothing looked up
+        let intents = account_intents(&ALICE, &mut owned, &[cancelled(3)], lookup);
+        assert_eq!(intents, HashSet::from([3]));
+        assert!(lookups.take().is_empty());
+    }
+
+    #[test]
+    fn intents_removed_within_the_block_keep_their_notifications() {
+        let mut owned = HashSet::new();
+
+        // Gone from the state after the block, the owner is read from the removal event
+        let events = vec![cancelled(7), removed(7, ALICE), cancelled(8), removed(8, BOB)];
+        let intents = account_intents(&ALICE, &mut owned, &events, |_| {
+            panic!("owners are known from the events")
+        });
+        assert_eq!(intents, HashSet::from([7]));
+        assert!(owned.is_empty());
+
+        let notifications = intent_notifications(
+            block(),
+            events,
+            BytesEncoding::default(),
+            |intent_id| intents.contains(&intent_id),
+            |_, _| None,
+        );
+        assert_eq!(
+            updates(notifications),
+            vec![
+                (
+                    7,
+                    IntentUpdate::Cancelled {
+                        reason: CancelReason::UserRequested,
+                    }
+                ),
+                (7, IntentUpdate::Removed),
+            ]
+        );
+    }
+
+    #[test]
+    fn inbox_notifications_keep_the_recipient_messages() {
+        let cid = b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec();
+        let sent = |message_id, recipient| {
+            RuntimeEvent::G3Mail(G3MailEvent::MessageSent {
+                message_id,
+                sender: BOB,
+                recipient,
+                cid: cid.clone().try_into().unwrap(),
+                timestamp: 3,
+            })
+        };
+        let read = |message_id, recipient| {
+            RuntimeEvent::G3Mail(G3MailEvent::MessageRead {
+                message_id,
+                recipient,
+            })
+        };
+        let events = vec![
+            sent(1, ALICE),
+            sent(2, BOB),
+            read(1, ALICE),
+            read(2, BOB),
+            executed(1, ALICE),
+        ];
+
+        let notifications = inbox_notifications(block(), events, &ALICE, BytesEncoding::default());
+
+        let updates: Vec<_> = notifications
+            .into_iter()
+            .map(|notification| {
+                assert_eq!(notification.block_hash, block());
+                notification.update
+            })
+            .collect();
+        assert_eq!(
+            updates,
+            vec![
+                InboxUpdate::MessageSent {
+                    message: MessageResponse {
+                        message_id: 1,
+                        sender: account_into_rpc(BOB),
+                        recipient: account_into_rpc(ALICE),
+                        cid: BytesEncoding::default().cid(cid.clone()),
+                        timestamp: 3,
+                        read: false,
+                    },
+                },
+                InboxUpdate::MessageRead { message_id: 1 },
+            ]
+        );
+    }
+
+    fn followed(follower: AccountId, followee: AccountId) -> RuntimeEvent {
+        RuntimeEvent::Ghonity(GhonityEvent::Followed { follower, followee })
+    }
+
+    fn reputation_updated(account: AccountId, old_score: u32, new_score: u32) -> RuntimeEvent {
+        RuntimeEvent::Ghonity(GhonityEvent::ReputationUpdated {
+            account,
+            old_score,
+            new_score,
+        })
+    }
+
+    #[test]
+    fn follower_notifications_keep_the_followee_events() {
+        const CAROL: AccountId = AccountId::new([3; 32]);
+        let events = vec![
+            followed(BOB, ALICE),
+            followed(ALICE, BOB),
+            RuntimeEvent::Ghonity(GhonityEvent::Unfollowed {
+                follower: CAROL,
+                followee: ALICE,
+            }),
+            RuntimeEvent::Ghonity(GhonityEvent::Unfollowed {
+                follower: ALICE,
+                followee: CAROL,
+            }),
+            executed(1, ALICE),
+        ];
+
+        let notifications = follower_notifications(block(), events, &ALICE);
+
+        assert_eq!(
+            notifications,
+            vec![
+                FollowerNotification {
+                    block_hash: block(),
+                    update: FollowerUpdate::Followed {
+                        follower: account_into_rpc(BOB),
+                    },
+                },
+                FollowerNotification {
+                    block_hash: block(),
+                    update: FollowerUpdate::Unfollowed {
+                        follower: account_into_rpc(CAROL),
+                    },
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn reputation_updates_are_coalesced_per_block() {
+        let events = vec![
+            reputation_updated(ALICE, 10, 12),
+            reputation_updated(BOB, 50, 40),
+            followed(BOB, ALICE),
+            reputation_updated(ALICE, 12, 15),
+            reputation_updated(ALICE, 15, 11),
+        ];
+
+        // The first old score and the last new score of the account
+        assert_eq!(
+            reputation_notification(block(), events.clone(), &ALICE),
+            Some(ReputationNotification {
+                block_hash: block(),
+                old_score: 10,
+                new_score: 11,
+            })
+        );
+        assert_eq!(
+            reputation_notification(block(), events[1..2].to_vec(), &BOB),
+            Some(ReputationNotification {
+                block_hash: block(),
+                old_score: 50,
+                new_score: 40,
+            })
+        );
+        assert_eq!(reputation_notification(block(), events[2..3].to_vec(), &ALICE), None);
+    }
 }
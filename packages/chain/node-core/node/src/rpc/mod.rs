@@ -56,17 +56,18 @@ where
     C: ProvideRuntimeApi<Block>,
     C: HeaderBackend<Block> + HeaderMetadata<Block, Error = BlockChainError> + 'static,
     C: sc_client_api::BlockBackend<Block> + sc_client_api::StorageProvider<Block, B> + 'static,
+    C: sc_client_api::BlockchainEvents<Block>,
     C: Send + Sync + 'static,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
     C::Api: BlockBuilder<Block>,
     C::Api: ChainGhostRuntimeApi<Block, AccountId, BlockNumber>,
     C::Api: G3MailRuntimeApi<Block, AccountId, BlockNumber>,
-    C::Api: GhonityRuntimeApi<Block, AccountId>,
+    C::Api: GhonityRuntimeApi<Block, AccountId, BlockNumber>,
     C::Api: fp_rpc::EthereumRuntimeRPCApi<Block>,
     C::Api: fp_rpc::ConvertTransactionRuntimeApi<Block>,
     P: TransactionPool + 'static,
-    B: sc_client_api::Backend<Block> + 'static,
+    B: sc_client_api::Backend<Block> + Send + Sync + 'static,
     B::State: sc_client_api::StateBackend<sp_runtime::traits::HashingFor<Block>>,
 {
     use ghost_protocol::{
@@ -92,9 +93,9 @@ where
     module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
 
     // Ghost Protocol custom RPC methods
-    module.merge(ChainGhost::new(client.clone()).into_rpc())?;
-    module.merge(G3Mail::new(client.clone()).into_rpc())?;
-    module.merge(Ghonity::new(client.clone()).into_rpc())?;
+    module.merge(ChainGhost::<_, _, B>::new(client.clone()).into_rpc())?;
+    module.merge(G3Mail::<_, _, B>::new(client.clone()).into_rpc())?;
+    module.merge(Ghonity::<_, _, B>::new(client.clone()).into_rpc())?;
 
     // Frontier Ethereum RPC methods
     let frontier_deps = frontier::FrontierRpcDeps {
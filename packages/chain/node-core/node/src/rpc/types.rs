@@ -1,5 +1,203 @@
 use codec::{Decode, Encode};
-use serde::{Deserialize, Serialize};
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+use sp_core::{
+    crypto::{AccountId32, Ss58AddressFormat, Ss58Codec},
+    H160,
+};
+
+/// Account parameter of an RPC method: an SS58 address or a `0x`-prefixed H160 address
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AccountAddress {
+    /// Account and the SS58 prefix its address was encoded with
+    Ss58(AccountId32, u16),
+    /// Ethereum address, resolved to an account through the EVM address mapping
+    H160(H160),
+}
+
+impl Serialize for AccountAddress {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            AccountAddress::Ss58(account, prefix) => serializer.serialize_str(
+                &account.to_ss58check_with_version(Ss58AddressFormat::custom(*prefix)),
+            ),
+            AccountAddress::H160(address) => serializer.serialize_str(&format!("{:?}", address)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for AccountAddress {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+
+        if string.starts_with("0x") {
+            let bytes = sp_core::bytes::from_hex(&string).map_err(D::Error::custom)?;
+            if bytes.len() != H160::len_bytes() {
+                return Err(D::Error::custom(format!(
+                    "H160 address of {} bytes, expected {}",
+                    bytes.len(),
+                    H160::len_bytes()
+                )));
+            }
+            return Ok(AccountAddress::H160(H160::from_slice(&bytes)));
+        }
+
+        let (account, format) = AccountId32::from_ss58check_with_version(&string)
+            .map_err(|err| D::Error::custom(format!("Invalid SS58 address: {}", err)))?;
+        Ok(AccountAddress::Ss58(account, format.prefix()))
+    }
+}
+
+/// Account of an RPC response, in both address forms
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountResponse {
+    /// SS58 address with the chain prefix
+    pub ss58: String,
+    /// Ethereum address the account is mapped from, null for accounts no address maps to
+    pub h160: Option<H160>,
+}
+
+/// Byte field of an RPC response, rendered as a JSON string
+///
+/// Text never starts with `0x`, so both forms read back unambiguously.
+#[derive(Clone, Debug, Eq, PartialEq, Encode, Decode)]
+pub enum Bytes {
+    /// Rendered as `0x`-prefixed hex
+    Hex(Vec<u8>),
+    /// Rendered as is
+    Text(String),
+}
+
+impl Bytes {
+    pub fn hex(bytes: Vec<u8>) -> Self {
+        Bytes::Hex(bytes)
+    }
+
+    /// The UTF-8 text of `bytes` when valid, hex otherwise
+    pub fn text_or_hex(bytes: Vec<u8>) -> Self {
+        match String::from_utf8(bytes) {
+            Ok(text) if !text.starts_with("0x") => Bytes::Text(text),
+            Ok(text) => Bytes::Hex(text.into_bytes()),
+            Err(err) => Bytes::Hex(err.into_bytes()),
+        }
+    }
+
+    /// A multibase string for the CID `bytes`
+    ///
+    /// CIDs stored in their string form (multibase CIDv1, or base58btc CIDv0) are kept as is.
+    /// Binary CIDs are rendered in base32, the default multibase of CIDv1 strings.
+    pub fn multibase(bytes: Vec<u8>) -> Self {
+        let textual = !bytes.is_empty()
+            && !bytes.starts_with(b"0x")
+            && bytes.iter().all(u8::is_ascii_alphanumeric);
+
+        if textual {
+            Bytes::Text(bytes.into_iter().map(char::from).collect())
+        } else {
+            Bytes::Text(format!("b{}", base32_lower(&bytes)))
+        }
+    }
+}
+
+impl Serialize for Bytes {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Bytes::Hex(bytes) => serializer.serialize_str(&sp_core::bytes::to_hex(bytes, false)),
+            Bytes::Text(text) => serializer.serialize_str(text),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Bytes {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        let hex = string.starts_with("0x").then(|| sp_core::bytes::from_hex(&string).ok());
+
+        Ok(match hex.flatten() {
+            Some(bytes) => Bytes::Hex(bytes),
+            None => Bytes::Text(string),
+        })
+    }
+}
+
+/// Serde for balances as decimal strings
+///
+/// JSON numbers lose precision above 2^53 in most clients, and internally tagged enums cannot
+/// buffer `u128` numbers. Numbers are still accepted on input.
+mod balance {
+    use serde::{de::Error as _, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &u128, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&value.to_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u128, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum NumberOrString {
+            Number(u64),
+            String(String),
+        }
+
+        match NumberOrString::deserialize(deserializer)? {
+            NumberOrString::Number(number) => Ok(number.into()),
+            NumberOrString::String(string) => string
+                .parse()
+                .map_err(|err| D::Error::custom(format!("Invalid balance {:?}: {}", string, err))),
+        }
+    }
+}
+
+/// RFC 4648 base32, lowercase and unpadded, as in multibase `b` strings
+fn base32_lower(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 32] = b"abcdefghijklmnopqrstuvwxyz234567";
+
+    let mut encoded = String::with_capacity((bytes.len() * 8).div_ceil(5));
+    let (mut buffer, mut bits) = (0u32, 0u32);
+    for byte in bytes {
+        buffer = (buffer << 8) | u32::from(*byte);
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            encoded.push(char::from(ALPHABET[((buffer >> bits) & 0x1f) as usize]));
+        }
+        buffer &= (1 << bits) - 1;
+    }
+    if bits > 0 {
+        encoded.push(char::from(ALPHABET[((buffer << (5 - bits)) & 0x1f) as usize]));
+    }
+
+    encoded
+}
+
+/// How byte fields are rendered, chosen by the `encoding` parameter of the RPC methods
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BytesEncoding {
+    /// Hex, except text fields in UTF-8 when valid and CIDs as multibase strings
+    #[default]
+    Readable,
+    /// Hex for every field
+    Hex,
+}
+
+impl BytesEncoding {
+    /// Render a text field: descriptions, messages and free-form contents
+    pub fn text(self, bytes: Vec<u8>) -> Bytes {
+        match self {
+            BytesEncoding::Readable => Bytes::text_or_hex(bytes),
+            BytesEncoding::Hex => Bytes::hex(bytes),
+        }
+    }
+
+    /// Render a content identifier field
+    pub fn cid(self, bytes: Vec<u8>) -> Bytes {
+        match self {
+            BytesEncoding::Readable => Bytes::multibase(bytes),
+            BytesEncoding::Hex => Bytes::hex(bytes),
+        }
+    }
+}
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Encode, Decode)]
 #[serde(rename_all = "camelCase")]
@@ -7,6 +205,76 @@ pub enum IntentStatus {
     Pending,
     Executed,
     Failed,
+    Cancelled,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "camelCase")]
+pub enum CancelReason {
+    UserRequested,
+    Expired,
+    MarketMoved,
+    InsufficientFunds,
+    /// ID of the intent replacing the cancelled one
+    Superseded(u64),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferIntent<AccountId> {
+    pub dest: AccountId,
+    pub asset: u32,
+    #[serde(with = "balance")]
+    pub amount: u128,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "camelCase")]
+pub struct SwapIntent {
+    pub asset_in: u32,
+    pub asset_out: u32,
+    #[serde(with = "balance")]
+    pub amount_in: u128,
+    #[serde(with = "balance")]
+    pub min_amount_out: u128,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "camelCase")]
+pub struct BridgeIntent {
+    pub asset: u32,
+    #[serde(with = "balance")]
+    pub amount: u128,
+    pub dest_chain: u32,
+    pub recipient: Bytes,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractCallIntent {
+    pub contract: sp_core::H160,
+    #[serde(with = "balance")]
+    pub value: u128,
+    pub input: Bytes,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "camelCase", tag = "type")]
+pub enum IntentAction<AccountId> {
+    Transfer(TransferIntent<AccountId>),
+    Swap(SwapIntent),
+    Bridge(BridgeIntent),
+    ContractCall(ContractCallIntent),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "camelCase", tag = "type")]
+pub enum IntentKind<AccountId> {
+    Transfer(TransferIntent<AccountId>),
+    Swap(SwapIntent),
+    Bridge(BridgeIntent),
+    ContractCall(ContractCallIntent),
+    Batch { actions: Vec<IntentAction<AccountId>> },
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Encode, Decode)]
@@ -16,17 +284,143 @@ pub struct IntentResponse<AccountId, BlockNumber> {
     pub account: AccountId,
     pub status: IntentStatus,
     pub timestamp: BlockNumber,
-    #[serde(with = "serde_bytes")]
-    pub metadata: Vec<u8>,
+    pub metadata: Bytes,
+    pub kind: Option<IntentKind<AccountId>>,
+    pub error: Option<ExecutionErrorResponse>,
+    pub chain_refs: Vec<ChainRefResponse>,
+    pub cancel_reason: Option<CancelReason>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Encode, Decode)]
 #[serde(rename_all = "camelCase")]
-pub struct JourneyStepResponse<BlockNumber> {
+pub struct ChainRefResponse {
+    pub chain_id: u32,
+    pub tx_hash: Bytes,
+    pub block_number: u64,
+    /// Whether a relayer has attested the transaction
+    pub attested: bool,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecutionErrorResponse {
+    /// Index of the dispatched call that failed
+    pub call_index: u32,
+    /// Debug rendering of the `DispatchError`
+    pub error: String,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "camelCase")]
+pub struct JourneyStepResponse<AccountId, BlockNumber> {
     pub step_id: u32,
-    #[serde(with = "serde_bytes")]
-    pub description: Vec<u8>,
+    pub step_kind: JourneyStepKind,
+    pub description: Bytes,
     pub timestamp: BlockNumber,
+    pub author: AccountId,
+    pub linked_tx: Option<LinkedTx>,
+    pub attachment: Option<Bytes>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "camelCase")]
+pub struct JourneyHeadResponse {
+    /// Number of steps committed to by `hash`
+    pub step_count: u32,
+    /// Hash chain head after the last step
+    pub hash: sp_core::H256,
+}
+
+/// Change to an intent pushed by the intent subscriptions
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "camelCase")]
+pub struct IntentNotification<BlockHash, AccountId, BlockNumber> {
+    /// Block whose events carried the change
+    pub block_hash: BlockHash,
+    pub intent_id: u64,
+    pub update: IntentUpdate<AccountId, BlockNumber>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "camelCase", tag = "type")]
+pub enum IntentUpdate<AccountId, BlockNumber> {
+    Created { account: AccountId },
+    #[serde(rename_all = "camelCase")]
+    StatusChanged {
+        old_status: IntentStatus,
+        new_status: IntentStatus,
+    },
+    JourneyStep { step: JourneyStepResponse<AccountId, BlockNumber> },
+    Cancelled { reason: CancelReason },
+    Removed,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "camelCase")]
+pub enum JourneyStepKind {
+    TxSubmitted,
+    TxConfirmed,
+    BridgeHop,
+    Note,
+    Milestone,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "camelCase", tag = "chain", content = "hash")]
+pub enum LinkedTx {
+    /// Hash of a Substrate extrinsic
+    Substrate(sp_core::H256),
+    /// Hash of an Ethereum transaction
+    Ethereum(sp_core::H256),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "camelCase")]
+pub struct SubIntentResponse<AccountId> {
+    pub index: u32,
+    pub action: IntentAction<AccountId>,
+    pub depends_on: Vec<u32>,
+    pub status: IntentStatus,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "camelCase")]
+pub struct IntentTreeResponse<AccountId> {
+    pub intent_id: u64,
+    pub status: IntentStatus,
+    pub sub_intents: Vec<SubIntentResponse<AccountId>>,
+    /// Pending sub-intents whose dependencies are all executed
+    pub ready: Vec<u32>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "camelCase")]
+pub enum AchievementKind {
+    IntentsExecuted,
+    SuccessRatio,
+    DistinctKinds,
+    JourneySteps,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "camelCase")]
+pub struct AchievementResponse<BlockNumber> {
+    pub kind: AchievementKind,
+    pub threshold: u32,
+    pub unlocked_at: BlockNumber,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonaResponse<BlockNumber> {
+    pub intents_created: u32,
+    pub intents_executed: u32,
+    pub intents_failed: u32,
+    /// Percentage of resolved intents that were executed
+    pub success_ratio: Option<u32>,
+    pub distinct_kinds: u32,
+    pub journey_steps: u32,
+    pub achievements: Vec<AchievementResponse<BlockNumber>>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Encode, Decode)]
@@ -35,8 +429,313 @@ pub struct MessageResponse<AccountId, BlockNumber> {
     pub message_id: u64,
     pub sender: AccountId,
     pub recipient: AccountId,
-    #[serde(with = "serde_bytes")]
-    pub cid: Vec<u8>,
+    pub cid: Bytes,
     pub timestamp: BlockNumber,
     pub read: bool,
 }
+
+/// Inbox change pushed by `g3mail_subscribeInbox`
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "camelCase")]
+pub struct InboxNotification<BlockHash, AccountId, BlockNumber> {
+    /// Block whose events carried the change
+    pub block_hash: BlockHash,
+    pub update: InboxUpdate<AccountId, BlockNumber>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "camelCase", tag = "type")]
+pub enum InboxUpdate<AccountId, BlockNumber> {
+    MessageSent { message: MessageResponse<AccountId, BlockNumber> },
+    #[serde(rename_all = "camelCase")]
+    MessageRead { message_id: u64 },
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "camelCase")]
+pub struct FollowerStatsResponse {
+    pub follower_count: u32,
+    pub weighted_score: u64,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountStatsResponse {
+    pub follower_count: u32,
+    pub following_count: u32,
+    pub reputation_score: u32,
+    pub weighted_score: u64,
+}
+
+/// Follower change pushed by `ghonity_subscribeFollowers`
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "camelCase")]
+pub struct FollowerNotification<BlockHash, AccountId> {
+    /// Block whose events carried the change
+    pub block_hash: BlockHash,
+    pub update: FollowerUpdate<AccountId>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "camelCase", tag = "type")]
+pub enum FollowerUpdate<AccountId> {
+    Followed { follower: AccountId },
+    Unfollowed { follower: AccountId },
+}
+
+/// Reputation change pushed by `ghonity_subscribeReputation`
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "camelCase")]
+pub struct ReputationNotification<BlockHash> {
+    /// Block whose events carried the change
+    pub block_hash: BlockHash,
+    /// Score before the block
+    pub old_score: u32,
+    /// Score after the block
+    pub new_score: u32,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "camelCase")]
+pub struct FollowEdgeResponse<BlockNumber> {
+    pub since: BlockNumber,
+    pub age: BlockNumber,
+    pub tags: u8,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "camelCase")]
+pub struct FollowSuggestionResponse<AccountId> {
+    pub account: AccountId,
+    pub reputation: u32,
+    pub mutual_count: u32,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "camelCase", tag = "type")]
+pub enum FeedItemKind {
+    Intent,
+    #[serde(rename_all = "camelCase")]
+    JourneyStep { step_id: u32 },
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "camelCase")]
+pub struct FeedItemResponse<AccountId, BlockNumber> {
+    pub account: AccountId,
+    pub intent_id: u64,
+    pub kind: FeedItemKind,
+    pub status: IntentStatus,
+    pub block_number: BlockNumber,
+    pub content: Bytes,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::de::DeserializeOwned;
+
+    fn round_trip<T: Serialize + DeserializeOwned + PartialEq + std::fmt::Debug>(
+        value: T,
+        json: serde_json::Value,
+    ) {
+        assert_eq!(serde_json::to_value(&value).unwrap(), json);
+        assert_eq!(serde_json::from_value::<T>(json).unwrap(), value);
+    }
+
+    #[test]
+    fn addresses_parse_from_ss58_and_h160() {
+        let alice = AccountId32::new([1; 32]);
+        let ss58 = alice.to_ss58check_with_version(Ss58AddressFormat::custom(42));
+        round_trip(AccountAddress::Ss58(alice.clone(), 42), ss58.into());
+
+        // The prefix is kept for the caller to check against the chain's
+        let polkadot = alice.to_ss58check_with_version(Ss58AddressFormat::custom(0));
+        round_trip(AccountAddress::Ss58(alice, 0), polkadot.into());
+
+        let h160 = "0x00000000000000000000000000000000000000ff";
+        round_trip(AccountAddress::H160(H160::from_low_u64_be(0xff)), h160.into());
+
+        assert!(serde_json::from_value::<AccountAddress>("0xdeadbeef".into()).is_err());
+        assert!(serde_json::from_value::<AccountAddress>("not an address".into()).is_err());
+    }
+
+    #[test]
+    fn accounts_render_in_both_forms() {
+        round_trip(
+            AccountResponse {
+                ss58: "5C4hrfjw9DjXZTzV3MwzrrAr9P1MJhSrvWGWqi1eSuyUpnhM".into(),
+                h160: Some(H160::from_low_u64_be(1)),
+            },
+            serde_json::json!({
+                "ss58": "5C4hrfjw9DjXZTzV3MwzrrAr9P1MJhSrvWGWqi1eSuyUpnhM",
+                "h160": "0x0000000000000000000000000000000000000001",
+            }),
+        );
+        round_trip(
+            AccountResponse {
+                ss58: "5C4hrfjw9DjXZTzV3MwzrrAr9P1MJhSrvWGWqi1eSuyUpnhM".into(),
+                h160: None,
+            },
+            serde_json::json!({
+                "ss58": "5C4hrfjw9DjXZTzV3MwzrrAr9P1MJhSrvWGWqi1eSuyUpnhM",
+                "h160": null,
+            }),
+        );
+    }
+
+    #[test]
+    fn bytes_render_as_strings() {
+        round_trip(Bytes::hex(vec![0xde, 0xad, 0xbe, 0xef]), "0xdeadbeef".into());
+        round_trip(Bytes::hex(Vec::new()), "0x".into());
+        round_trip(Bytes::text_or_hex(b"Swap 100 USDC".to_vec()), "Swap 100 USDC".into());
+
+        // Invalid UTF-8, and text that would read back as hex, fall back to hex
+        round_trip(Bytes::text_or_hex(vec![0xff, 0x00]), "0xff00".into());
+        round_trip(Bytes::text_or_hex(b"0x12".to_vec()), "0x30783132".into());
+    }
+
+    #[test]
+    fn cids_render_as_multibase() {
+        let cid_v0 = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+        round_trip(Bytes::multibase(cid_v0.as_bytes().to_vec()), cid_v0.into());
+
+        let cid_v1 = "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi";
+        round_trip(Bytes::multibase(cid_v1.as_bytes().to_vec()), cid_v1.into());
+
+        // Binary CIDs are base32 encoded
+        round_trip(Bytes::multibase(b"hello!".to_vec()), "bnbswy3dpee".into());
+        round_trip(Bytes::multibase(vec![0x01, 0x70]), "bafya".into());
+    }
+
+    #[test]
+    fn encoding_selects_the_rendering() {
+        assert_eq!(
+            serde_json::from_value::<BytesEncoding>("hex".into()).unwrap(),
+            BytesEncoding::Hex
+        );
+        assert_eq!(BytesEncoding::default(), BytesEncoding::Readable);
+
+        let description = b"Bridged to Arbitrum".to_vec();
+        assert_eq!(
+            BytesEncoding::Readable.text(description.clone()),
+            Bytes::Text("Bridged to Arbitrum".into())
+        );
+        assert_eq!(BytesEncoding::Hex.text(description.clone()), Bytes::Hex(description));
+        assert_eq!(BytesEncoding::Hex.cid(b"Qm".to_vec()), Bytes::Hex(b"Qm".to_vec()));
+    }
+
+    #[test]
+    fn responses_round_trip() {
+        round_trip(
+            JourneyStepResponse {
+                step_id: 1,
+                step_kind: JourneyStepKind::Note,
+                description: Bytes::text_or_hex(b"Swapped".to_vec()),
+                timestamp: 7u32,
+                author: 3u64,
+                linked_tx: None,
+                attachment: Some(Bytes::multibase(b"bafyattachment".to_vec())),
+            },
+            serde_json::json!({
+                "stepId": 1,
+                "stepKind": "note",
+                "description": "Swapped",
+                "timestamp": 7,
+                "author": 3,
+                "linkedTx": null,
+                "attachment": "bafyattachment",
+            }),
+        );
+
+        round_trip(
+            MessageResponse {
+                message_id: 4,
+                sender: 1u64,
+                recipient: 2u64,
+                cid: Bytes::multibase(vec![0x01, 0x70]),
+                timestamp: 9u32,
+                read: false,
+            },
+            serde_json::json!({
+                "messageId": 4,
+                "sender": 1,
+                "recipient": 2,
+                "cid": "bafya",
+                "timestamp": 9,
+                "read": false,
+            }),
+        );
+
+        round_trip(
+            IntentResponse {
+                intent_id: 42,
+                account: 1u64,
+                status: IntentStatus::Pending,
+                timestamp: 5u32,
+                metadata: Bytes::hex(b"{}".to_vec()),
+                kind: Some(IntentKind::Bridge(BridgeIntent {
+                    asset: 0,
+                    amount: 10,
+                    dest_chain: 1,
+                    recipient: Bytes::hex(vec![0xab; 2]),
+                })),
+                error: None,
+                chain_refs: Vec::new(),
+                cancel_reason: None,
+            },
+            serde_json::json!({
+                "intentId": 42,
+                "account": 1,
+                "status": "pending",
+                "timestamp": 5,
+                "metadata": "0x7b7d",
+                "kind": {
+                    "type": "bridge",
+                    "asset": 0,
+                    "amount": "10",
+                    "destChain": 1,
+                    "recipient": "0xabab",
+                },
+                "error": null,
+                "chainRefs": [],
+                "cancelReason": null,
+            }),
+        );
+    }
+
+    #[test]
+    fn balances_render_as_decimal_strings() {
+        let max = IntentKind::<u64>::ContractCall(ContractCallIntent {
+            contract: H160::zero(),
+            value: u128::MAX,
+            input: Bytes::hex(Vec::new()),
+        });
+        round_trip(
+            max,
+            serde_json::json!({
+                "type": "contractCall",
+                "contract": "0x0000000000000000000000000000000000000000",
+                "value": "340282366920938463463374607431768211455",
+                "input": "0x",
+            }),
+        );
+
+        let swap = serde_json::json!({
+            "type": "swap",
+            "assetIn": 0,
+            "assetOut": 1,
+            "amountIn": 100,
+            "minAmountOut": "95",
+        });
+        assert_eq!(
+            serde_json::from_value::<IntentKind<u64>>(swap).unwrap(),
+            IntentKind::Swap(SwapIntent {
+                asset_in: 0,
+                asset_out: 1,
+                amount_in: 100,
+                min_amount_out: 95,
+            })
+        );
+    }
+}
@@ -5,7 +5,93 @@ use super::*;
 #[allow(unused)]
 use crate::Pallet as ChainGhost;
 use frame_benchmarking::v2::*;
-use frame_system::RawOrigin;
+use frame_support::{
+    sp_runtime::traits::{One, Saturating, Zero},
+    traits::{
+        fungible::{Inspect, Mutate},
+        Get,
+    },
+    BoundedVec,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_std::{vec, vec::Vec};
+
+/// Largest valid payload: a full batch of maximum-size contract calls
+fn worst_case_kind<T: Config>() -> IntentKind<T::AccountId> {
+    let action = IntentAction::ContractCall(ContractCallIntent {
+        contract: [0x11; 20],
+        value: 1,
+        input: BoundedVec::truncate_from(vec![0xff; 256]),
+    });
+    IntentKind::Batch(BoundedVec::truncate_from(vec![
+        action;
+        MaxBatchActions::get() as usize
+    ]))
+}
+
+/// `count` maximum-size contract calls, each depending on all earlier ones
+fn worst_case_sub_intents<T: Config>(count: u32) -> Vec<(IntentAction<T::AccountId>, Vec<u32>)> {
+    let action = IntentAction::ContractCall(ContractCallIntent {
+        contract: [0x11; 20],
+        value: 1,
+        input: BoundedVec::truncate_from(vec![0xff; 256]),
+    });
+    (0..count).map(|index| (action.clone(), (0..index).collect())).collect()
+}
+
+/// Give `who` enough balance for a solver bond, a reward and every deposit
+fn fund<T: Config>(who: &T::AccountId) {
+    let amount = T::SolverBond::get()
+        .saturating_mul(10u32.into())
+        .saturating_add(T::Currency::minimum_balance())
+        .saturating_add(T::IntentDeposit::get())
+        .saturating_add(T::TemplateDeposit::get())
+        .saturating_add(T::RecurringDeposit::get())
+        .saturating_add(reward::<T>());
+    T::Currency::set_balance(who, amount);
+}
+
+fn reward<T: Config>() -> BalanceOf<T> {
+    T::Currency::minimum_balance().saturating_mul(100u32.into())
+}
+
+/// Create a worst-case intent owned by `owner`, with a reward escrowed
+fn rewarded_intent<T: Config>(owner: &T::AccountId) -> IntentId {
+    fund::<T>(owner);
+    let intent_id = ChainGhost::<T>::next_intent_id();
+    ChainGhost::<T>::execute_intent(
+        RawOrigin::Signed(owner.clone()).into(),
+        worst_case_kind::<T>(),
+        b"Test intent".to_vec(),
+        vec![],
+    )
+    .expect("Intent creation should succeed");
+    ChainGhost::<T>::offer_reward(RawOrigin::Signed(owner.clone()).into(), intent_id, reward::<T>())
+        .expect("Reward offer should succeed");
+    intent_id
+}
+
+/// Store a maximum-size template owned by a funded `owner`
+fn max_template<T: Config>(owner: &T::AccountId) -> TemplateId {
+    fund::<T>(owner);
+    let template_id = ChainGhost::<T>::next_template_id();
+    ChainGhost::<T>::create_template(
+        RawOrigin::Signed(owner.clone()).into(),
+        worst_case_kind::<T>(),
+        vec![b'a'; T::MaxMetadataLen::get() as usize],
+    )
+    .expect("Template creation should succeed");
+    template_id
+}
+
+/// Register a funded solver
+fn registered_solver<T: Config>() -> T::AccountId {
+    let solver: T::AccountId = account("solver", 0, 0);
+    fund::<T>(&solver);
+    ChainGhost::<T>::register_solver(RawOrigin::Signed(solver.clone()).into())
+        .expect("Solver registration should succeed");
+    solver
+}
 
 #[benchmarks]
 mod benchmarks {
@@ -13,11 +99,17 @@ mod benchmarks {
 
     #[benchmark]
     fn execute_intent() {
-        let metadata = b"Swap 100 USDC to ETH on Arbitrum".to_vec();
+        let metadata = vec![b'a'; T::MaxMetadataLen::get() as usize];
         let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
 
         #[extrinsic_call]
-        execute_intent(RawOrigin::Signed(caller.clone()), metadata.clone());
+        execute_intent(
+            RawOrigin::Signed(caller.clone()),
+            worst_case_kind::<T>(),
+            metadata.clone(),
+            vec![],
+        );
 
         assert_eq!(ChainGhost::<T>::next_intent_id(), 1);
         let intent = ChainGhost::<T>::intent_by_id(0).expect("Intent should exist");
@@ -26,34 +118,471 @@ mod benchmarks {
 
     #[benchmark]
     fn record_journey() {
-        let metadata = b"Test intent".to_vec();
+        // Worst case: the step is recorded by the claiming solver rather than the owner
+        let owner: T::AccountId = account("owner", 0, 0);
+        let intent_id = rewarded_intent::<T>(&owner);
+        let solver = registered_solver::<T>();
+        ChainGhost::<T>::claim_intent(RawOrigin::Signed(solver.clone()).into(), intent_id)
+            .expect("Claim should succeed");
+
+        #[extrinsic_call]
+        record_journey(
+            RawOrigin::Signed(solver),
+            intent_id,
+            JourneyStepKind::BridgeHop,
+            vec![b'a'; T::MaxDescriptionLen::get() as usize],
+            Some(LinkedTx::Ethereum([0xff; 32])),
+            Some(vec![b'b'; 128]),
+        );
+
+        let journey_steps = ChainGhost::<T>::journey_by_intent(intent_id);
+        assert_eq!(journey_steps.len(), 1);
+    }
+
+    #[benchmark]
+    fn update_intent_status() {
         let caller: T::AccountId = whitelisted_caller();
 
-        // Setup: Create an intent first
-        let _ = ChainGhost::<T>::execute_intent(RawOrigin::Signed(caller.clone()).into(), metadata);
+        // Setup: Create an intent with an escrowed reward, released on resolution
+        let intent_id = rewarded_intent::<T>(&caller);
 
-        let description = b"Step 1: Transaction initiated on Arbitrum".to_vec();
+        #[extrinsic_call]
+        update_intent_status(RawOrigin::Signed(caller), intent_id, 1); // 1 = Executed
+
+        let intent = ChainGhost::<T>::intent_by_id(intent_id).expect("Intent should exist");
+        assert_eq!(intent.status, IntentStatus::Executed);
+        assert!(ChainGhost::<T>::intent_reward(intent_id).is_zero());
+    }
+
+    #[benchmark]
+    fn register_solver() {
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
 
         #[extrinsic_call]
-        record_journey(RawOrigin::Signed(caller), 0, description.clone());
+        register_solver(RawOrigin::Signed(caller.clone()));
 
-        let journey_steps = ChainGhost::<T>::journey_by_intent(0);
-        assert_eq!(journey_steps.len(), 1);
+        assert!(ChainGhost::<T>::solver(&caller).is_some());
     }
 
     #[benchmark]
-    fn update_intent_status() {
-        let metadata = b"Test intent".to_vec();
+    fn deregister_solver() {
+        let solver = registered_solver::<T>();
+
+        #[extrinsic_call]
+        deregister_solver(RawOrigin::Signed(solver.clone()));
+
+        assert!(ChainGhost::<T>::solver(&solver).is_none());
+    }
+
+    #[benchmark]
+    fn offer_reward() {
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+        ChainGhost::<T>::execute_intent(
+            RawOrigin::Signed(caller.clone()).into(),
+            worst_case_kind::<T>(),
+            b"Test intent".to_vec(),
+            vec![],
+        )
+        .expect("Intent creation should succeed");
+
+        #[extrinsic_call]
+        offer_reward(RawOrigin::Signed(caller), 0, reward::<T>());
+
+        assert_eq!(ChainGhost::<T>::intent_reward(0), reward::<T>());
+    }
+
+    #[benchmark]
+    fn claim_intent() {
+        let owner: T::AccountId = account("owner", 0, 0);
+        let intent_id = rewarded_intent::<T>(&owner);
+        let solver = registered_solver::<T>();
+
+        #[extrinsic_call]
+        claim_intent(RawOrigin::Signed(solver), intent_id);
+
+        assert!(ChainGhost::<T>::intent_claim(intent_id).is_some());
+    }
+
+    #[benchmark]
+    fn fulfil_intent() {
+        let owner: T::AccountId = account("owner", 0, 0);
+        let intent_id = rewarded_intent::<T>(&owner);
+        let solver = registered_solver::<T>();
+        ChainGhost::<T>::claim_intent(RawOrigin::Signed(solver.clone()).into(), intent_id)
+            .expect("Claim should succeed");
+
+        #[extrinsic_call]
+        fulfil_intent(RawOrigin::Signed(solver), intent_id, vec![0xff; 256]);
+
+        let intent = ChainGhost::<T>::intent_by_id(intent_id).expect("Intent should exist");
+        assert_eq!(intent.status, IntentStatus::Executed);
+    }
+
+    #[benchmark]
+    fn slash_expired_claim() {
+        let owner: T::AccountId = account("owner", 0, 0);
+        let intent_id = rewarded_intent::<T>(&owner);
+        let solver = registered_solver::<T>();
+        ChainGhost::<T>::claim_intent(RawOrigin::Signed(solver.clone()).into(), intent_id)
+            .expect("Claim should succeed");
+
+        let expired = frame_system::Pallet::<T>::block_number()
+            .saturating_add(T::ClaimTimeout::get())
+            .saturating_add(One::one());
+        frame_system::Pallet::<T>::set_block_number(expired);
         let caller: T::AccountId = whitelisted_caller();
 
-        // Setup: Create an intent first
-        let _ = ChainGhost::<T>::execute_intent(RawOrigin::Signed(caller.clone()).into(), metadata);
+        #[extrinsic_call]
+        slash_expired_claim(RawOrigin::Signed(caller), intent_id);
+
+        assert!(ChainGhost::<T>::intent_claim(intent_id).is_none());
+        assert_eq!(ChainGhost::<T>::solver(&solver).expect("Solver should exist").slashed, 1);
+    }
+
+    #[benchmark]
+    fn dispute_fulfilment() {
+        let owner: T::AccountId = account("owner", 0, 0);
+        let intent_id = rewarded_intent::<T>(&owner);
+        let solver = registered_solver::<T>();
+        ChainGhost::<T>::claim_intent(RawOrigin::Signed(solver.clone()).into(), intent_id)
+            .expect("Claim should succeed");
+        ChainGhost::<T>::fulfil_intent(
+            RawOrigin::Signed(solver.clone()).into(),
+            intent_id,
+            vec![0xff; 256],
+        )
+        .expect("Fulfilment should succeed");
 
         #[extrinsic_call]
-        update_intent_status(RawOrigin::Signed(caller), 0, 1); // 1 = Executed
+        dispute_fulfilment(RawOrigin::Signed(owner), intent_id);
+
+        let intent = ChainGhost::<T>::intent_by_id(intent_id).expect("Intent should exist");
+        assert_eq!(intent.status, IntentStatus::Pending);
+        assert_eq!(ChainGhost::<T>::solver(&solver).expect("Solver should exist").slashed, 1);
+    }
+
+    #[benchmark]
+    fn collect_reward() {
+        let owner: T::AccountId = account("owner", 0, 0);
+        let intent_id = rewarded_intent::<T>(&owner);
+        let solver = registered_solver::<T>();
+        ChainGhost::<T>::claim_intent(RawOrigin::Signed(solver.clone()).into(), intent_id)
+            .expect("Claim should succeed");
+        ChainGhost::<T>::fulfil_intent(
+            RawOrigin::Signed(solver.clone()).into(),
+            intent_id,
+            vec![0xff; 256],
+        )
+        .expect("Fulfilment should succeed");
+
+        let undisputed = frame_system::Pallet::<T>::block_number()
+            .saturating_add(T::DisputePeriod::get())
+            .saturating_add(One::one());
+        frame_system::Pallet::<T>::set_block_number(undisputed);
+        let caller: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        collect_reward(RawOrigin::Signed(caller), intent_id);
+
+        assert!(ChainGhost::<T>::pending_fulfilment(intent_id).is_none());
+        assert!(!T::Currency::balance(&solver).is_zero());
+    }
+
+    #[benchmark]
+    fn add_delegate() {
+        let caller: T::AccountId = whitelisted_caller();
+        let delegate: T::AccountId = account("delegate", 0, 0);
+
+        #[extrinsic_call]
+        add_delegate(
+            RawOrigin::Signed(caller.clone()),
+            delegate.clone(),
+            DelegateScope::Full,
+            None,
+        );
+
+        assert!(ChainGhost::<T>::delegation(&caller, &delegate).is_some());
+        assert_eq!(ChainGhost::<T>::delegate_count(&caller), 1);
+    }
+
+    #[benchmark]
+    fn remove_delegate() {
+        let caller: T::AccountId = whitelisted_caller();
+        let delegate: T::AccountId = account("delegate", 0, 0);
+        ChainGhost::<T>::add_delegate(
+            RawOrigin::Signed(caller.clone()).into(),
+            delegate.clone(),
+            DelegateScope::Full,
+            None,
+        )
+        .expect("Delegation should succeed");
+
+        #[extrinsic_call]
+        remove_delegate(RawOrigin::Signed(caller.clone()), delegate.clone());
+
+        assert!(ChainGhost::<T>::delegation(&caller, &delegate).is_none());
+        assert_eq!(ChainGhost::<T>::delegate_count(&caller), 0);
+    }
+
+    #[benchmark]
+    fn create_multi_step_intent(s: Linear<1, 16>) {
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+        let sub_intents = worst_case_sub_intents::<T>(s);
+
+        #[extrinsic_call]
+        create_multi_step_intent(
+            RawOrigin::Signed(caller),
+            b"Stake on Lido, bridge and deposit to Aave".to_vec(),
+            sub_intents,
+        );
+
+        let sub_intents = ChainGhost::<T>::sub_intents(0).expect("Sub-intents should exist");
+        assert_eq!(sub_intents.len(), s as usize);
+    }
+
+    #[benchmark]
+    fn update_sub_intent_status() {
+        // Worst case: the last sub-intent depends on all others and completes the parent,
+        // releasing its reward
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+        let last = MaxBatchActions::get() - 1;
+        ChainGhost::<T>::create_multi_step_intent(
+            RawOrigin::Signed(caller.clone()).into(),
+            b"Test intent".to_vec(),
+            worst_case_sub_intents::<T>(last + 1),
+        )
+        .expect("Intent creation should succeed");
+        ChainGhost::<T>::offer_reward(RawOrigin::Signed(caller.clone()).into(), 0, reward::<T>())
+            .expect("Reward offer should succeed");
+        for index in 0..last {
+            ChainGhost::<T>::update_sub_intent_status(
+                RawOrigin::Signed(caller.clone()).into(),
+                0,
+                index,
+                1,
+            )
+            .expect("Sub-intent update should succeed");
+        }
+
+        #[extrinsic_call]
+        update_sub_intent_status(RawOrigin::Signed(caller), 0, last, 1);
 
         let intent = ChainGhost::<T>::intent_by_id(0).expect("Intent should exist");
         assert_eq!(intent.status, IntentStatus::Executed);
+        assert!(ChainGhost::<T>::intent_reward(0).is_zero());
+    }
+
+    #[benchmark]
+    fn add_relayer() {
+        let relayer: T::AccountId = account("relayer", 0, 0);
+
+        #[extrinsic_call]
+        add_relayer(RawOrigin::Root, relayer.clone());
+
+        assert!(ChainGhost::<T>::is_relayer(&relayer).is_some());
+    }
+
+    #[benchmark]
+    fn remove_relayer() {
+        let relayer: T::AccountId = account("relayer", 0, 0);
+        ChainGhost::<T>::add_relayer(RawOrigin::Root.into(), relayer.clone())
+            .expect("Relayer registration should succeed");
+
+        #[extrinsic_call]
+        remove_relayer(RawOrigin::Root, relayer.clone());
+
+        assert!(ChainGhost::<T>::is_relayer(&relayer).is_none());
+    }
+
+    #[benchmark]
+    fn attach_external_tx() {
+        // Worst case: the transaction is attached by the claiming solver
+        let owner: T::AccountId = account("owner", 0, 0);
+        let intent_id = rewarded_intent::<T>(&owner);
+        let solver = registered_solver::<T>();
+        ChainGhost::<T>::claim_intent(RawOrigin::Signed(solver.clone()).into(), intent_id)
+            .expect("Claim should succeed");
+
+        #[extrinsic_call]
+        attach_external_tx(RawOrigin::Signed(solver), intent_id, 1, vec![0xff; 64], u64::MAX);
+
+        assert_eq!(ChainGhost::<T>::chain_refs(intent_id).len(), 1);
+    }
+
+    #[benchmark]
+    fn attest_external_execution() {
+        let owner: T::AccountId = account("owner", 0, 0);
+        let intent_id = rewarded_intent::<T>(&owner);
+        ChainGhost::<T>::attach_external_tx(
+            RawOrigin::Signed(owner).into(),
+            intent_id,
+            1,
+            vec![0xff; 64],
+            u64::MAX,
+        )
+        .expect("Attaching should succeed");
+        let relayer: T::AccountId = account("relayer", 0, 0);
+        ChainGhost::<T>::add_relayer(RawOrigin::Root.into(), relayer.clone())
+            .expect("Relayer registration should succeed");
+
+        #[extrinsic_call]
+        attest_external_execution(RawOrigin::Signed(relayer), intent_id, 0);
+
+        let intent = ChainGhost::<T>::intent_by_id(intent_id).expect("Intent should exist");
+        assert_eq!(intent.status, IntentStatus::Executed);
+        assert!(ChainGhost::<T>::chain_refs(intent_id)[0].attested);
+    }
+
+    #[benchmark]
+    fn remove_intent(s: Linear<0, 50>) {
+        // Worst case: the intent carries `s` journey steps and an external chain reference
+        let caller: T::AccountId = whitelisted_caller();
+        let intent_id = rewarded_intent::<T>(&caller);
+        for _ in 0..s.min(T::MaxJourneyStepsPerIntent::get()) {
+            ChainGhost::<T>::record_journey(
+                RawOrigin::Signed(caller.clone()).into(),
+                intent_id,
+                JourneyStepKind::Note,
+                vec![b'a'; T::MaxDescriptionLen::get() as usize],
+                None,
+                None,
+            )
+            .expect("Journey recording should succeed");
+        }
+        ChainGhost::<T>::attach_external_tx(
+            RawOrigin::Signed(caller.clone()).into(),
+            intent_id,
+            1,
+            vec![0xff; 64],
+            u64::MAX,
+        )
+        .expect("Attaching should succeed");
+        ChainGhost::<T>::update_intent_status(
+            RawOrigin::Signed(caller.clone()).into(),
+            intent_id,
+            1,
+        )
+        .expect("Status update should succeed");
+
+        #[extrinsic_call]
+        remove_intent(RawOrigin::Signed(caller.clone()), intent_id);
+
+        assert!(ChainGhost::<T>::intent_by_id(intent_id).is_none());
+        assert!(ChainGhost::<T>::journey_by_intent(intent_id).is_empty());
+        assert_eq!(ChainGhost::<T>::intent_count(&caller), 0);
+    }
+
+    #[benchmark]
+    fn cancel_intent() {
+        // Worst case: a reward is refunded
+        let caller: T::AccountId = whitelisted_caller();
+        let intent_id = rewarded_intent::<T>(&caller);
+
+        #[extrinsic_call]
+        cancel_intent(RawOrigin::Signed(caller), intent_id, CancelReason::Superseded(u64::MAX));
+
+        assert_eq!(
+            ChainGhost::<T>::intent_by_id(intent_id).map(|intent| intent.status),
+            Some(IntentStatus::Cancelled)
+        );
+    }
+
+    #[benchmark]
+    fn create_template() {
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+        let template_id = ChainGhost::<T>::next_template_id();
+
+        #[extrinsic_call]
+        create_template(
+            RawOrigin::Signed(caller.clone()),
+            worst_case_kind::<T>(),
+            vec![b'a'; T::MaxMetadataLen::get() as usize],
+        );
+
+        assert!(ChainGhost::<T>::template(&caller, template_id).is_some());
+    }
+
+    #[benchmark]
+    fn remove_template() {
+        let caller: T::AccountId = whitelisted_caller();
+        let template_id = max_template::<T>(&caller);
+
+        #[extrinsic_call]
+        remove_template(RawOrigin::Signed(caller.clone()), template_id);
+
+        assert!(ChainGhost::<T>::template(&caller, template_id).is_none());
+    }
+
+    #[benchmark]
+    fn schedule_recurring_intent() {
+        let caller: T::AccountId = whitelisted_caller();
+        let template_id = max_template::<T>(&caller);
+        let schedule_id = ChainGhost::<T>::next_schedule_id();
+
+        #[extrinsic_call]
+        schedule_recurring_intent(
+            RawOrigin::Signed(caller),
+            template_id,
+            One::one(),
+            u32::MAX,
+        );
+
+        assert!(ChainGhost::<T>::recurring_intent(schedule_id).is_some());
+    }
+
+    #[benchmark]
+    fn cancel_recurring_intent() {
+        let caller: T::AccountId = whitelisted_caller();
+        let template_id = max_template::<T>(&caller);
+        let schedule_id = ChainGhost::<T>::next_schedule_id();
+        ChainGhost::<T>::schedule_recurring_intent(
+            RawOrigin::Signed(caller.clone()).into(),
+            template_id,
+            One::one(),
+            u32::MAX,
+        )
+        .expect("Scheduling should succeed");
+
+        #[extrinsic_call]
+        cancel_recurring_intent(RawOrigin::Signed(caller), schedule_id);
+
+        assert!(ChainGhost::<T>::recurring_intent(schedule_id).is_none());
+    }
+
+    #[benchmark]
+    fn process_agenda(b: Linear<1, 20>, n: Linear<0, 20>) {
+        // Worst case: `b` agendas are visited and `n` occurrences of maximum-size templates, owned
+        // by distinct funded accounts, are due in the last one
+        let limit = T::MaxInstantiationsPerBlock::get();
+        let (b, n) = (b.min(limit), n.min(limit));
+        let period: BlockNumberFor<T> = b.into();
+        frame_system::Pallet::<T>::set_block_number(One::one());
+        for index in 0..n {
+            let owner: T::AccountId = account("owner", index, 0);
+            let template_id = max_template::<T>(&owner);
+            ChainGhost::<T>::schedule_recurring_intent(
+                RawOrigin::Signed(owner).into(),
+                template_id,
+                period,
+                2,
+            )
+            .expect("Scheduling should succeed");
+        }
+        let now = period.saturating_add(One::one());
+        AgendaCursor::<T>::put(now.saturating_sub(period).saturating_add(One::one()));
+        let next_intent_id = ChainGhost::<T>::next_intent_id();
+
+        #[block]
+        {
+            ChainGhost::<T>::process_agenda(now);
+        }
+
+        assert_eq!(ChainGhost::<T>::next_intent_id(), next_intent_id + n as IntentId);
     }
 
     impl_benchmark_test_suite!(ChainGhost, crate::mock::new_test_ext(), crate::mock::Test);
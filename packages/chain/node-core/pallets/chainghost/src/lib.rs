@@ -7,32 +7,116 @@
 //! This pallet enables users to:
 //! - Execute intent-based transactions with on-chain tracking
 //! - Record journey steps for narrative visualization
-//! - Manage intent status transitions (Pending → Executed/Failed)
-//! - Track per-account intent history with bounded collections
+//! - Manage intent status transitions (Pending → Executed/Failed/Cancelled)
+//! - Track per-account intent history in a paginated index backed by deposits
 //!
 //! ## Key Features
 //!
 //! - **Intent Execution**: Create and track user intents with unique IDs
-//! - **Journey Recording**: Build narrative timelines by recording journey steps
+//! - **Typed Payloads**: Transfer, swap, bridge, contract call and batch intents validated per kind
+//! - **Call Dispatch**: Intents may carry runtime calls dispatched atomically on the owner's behalf,
+//!   with the resulting status, error and per-call journey steps recorded on-chain
+//! - **Multi-Step Intents**: Ordered sub-intents with a bounded dependency DAG, whose
+//!   statuses roll up into the parent intent
+//! - **Cross-Chain Tracking**: Intents reference their transactions on other chains, and
+//!   authorized relayers attest external execution
+//! - **Personas**: Per-account activity counters unlock soulbound achievement badges at
+//!   configured thresholds. Only executions attested by a dispatch, a solver or a relayer count.
+//! - **Journey Recording**: Build narrative timelines from typed journey steps, linked to
+//!   transactions and attachments, recorded by intent owners or their solvers
 //! - **Status Management**: Update intent status with ownership validation
-//! - **Resource Limits**: Enforce per-account intent caps and journey step limits
+//! - **Delegation**: Owners grant backends scoped, expiring permissions over their intents
+//! - **Solver Marketplace**: Bonded solvers claim and fulfil intents for rewards escrowed by
+//!   their creators, and are slashed when a claim times out or a fulfilment is disputed
+//! - **Resource Limits**: Hold a deposit per stored intent and enforce journey step limits
 //!
 //! ## Storage Items
 //!
 //! - `NextIntentId`: Counter for generating unique intent IDs
 //! - `IntentById`: Main storage mapping IntentId → Intent struct
-//! - `IntentsByAccount`: Index mapping AccountId → BoundedVec<IntentId>
-//! - `JourneyByIntent`: Journey data mapping IntentId → BoundedVec<JourneyStep>
+//! - `IntentsByAccount`: Index mapping (AccountId, IntentKey) → (), in creation order
+//! - `IntentCountByAccount`: Number of intents owned by each account
+//! - `IntentDeposits`: Deposit held for each intent
+//! - `JourneySteps`: Append-only journey steps mapping (IntentId, StepId) → JourneyStep
+//! - `JourneyHeads`: Journey hash chain heads mapping IntentId → JourneyHead
+//! - `SubIntents`: Sub-intents of multi-step intents mapping IntentId → BoundedVec<SubIntent>
+//! - `ChainRefs`: External chain transactions mapping IntentId → BoundedVec<ChainRef>
+//! - `Relayers`: Accounts authorized to attest external execution
+//! - `Personas`: Activity counters mapping AccountId → PersonaStats
+//! - `AttestedExecutions`: Executed intents whose execution was attested, counted by personas
+//! - `UnlockedAchievements`: Soulbound badges mapping (AccountId, Achievement) → BlockNumber
+//! - `CancelReasons`: Reason of cancelled intents mapping IntentId → CancelReason
+//! - `IntentErrors`: Failure of an intent's dispatched calls mapping IntentId → ExecutionError
+//! - `Solvers`: Registered solvers mapping AccountId → SolverInfo
+//! - `IntentRewards`: Escrowed rewards mapping IntentId → Balance
+//! - `IntentClaims`: Active solver claims mapping IntentId → IntentClaim
+//! - `FulfilmentProofs`: Proofs submitted by solvers mapping IntentId → BoundedVec<u8>
+//! - `PendingFulfilments`: Fulfilments awaiting their dispute period mapping IntentId →
+//!   PendingFulfilment
+//! - `Delegates`: Scoped, expiring delegations mapping (owner, delegate) → Delegation
+//! - `DelegateCount`: Number of delegates granted mapping AccountId → u32
+//! - `Templates`: Reusable intent templates mapping (AccountId, TemplateId) → IntentTemplate
+//! - `TemplateCount`: Number of templates stored mapping AccountId → u32
+//! - `TemplateDeposits`: Deposits held for templates mapping (AccountId, TemplateId) → Balance
+//! - `RecurringIntents`: Active recurring schedules mapping ScheduleId → RecurringIntent
+//! - `RecurringCount`: Number of active schedules mapping AccountId → u32
+//! - `RecurringDeposits`: Deposits held for active schedules mapping ScheduleId → Balance
+//! - `Agenda`: Due schedule occurrences mapping (BlockNumber, ScheduleId) → ()
+//! - `AgendaCursor`: Oldest block whose agenda may still hold occurrences
 //!
 //! ## Dispatchable Functions
 //!
-//! - `execute_intent`: Creates new intent record with metadata
-//! - `record_journey`: Adds journey step to existing intent
-//! - `update_intent_status`: Updates intent status (requires ownership)
+//! - `execute_intent`: Creates new intent record with a typed payload and metadata
+//! - `record_journey`: Adds a typed journey step, optionally linking a transaction and an
+//!   attachment CID (owner, delegate or claiming solver)
+//! - `update_intent_status`: Updates intent status (owner or delegate)
+//! - `register_solver` / `deregister_solver`: Join or leave the solver set, holding a bond
+//! - `offer_reward`: Escrow a reward for fulfilling an intent
+//! - `claim_intent`: Claim a pending intent as a solver
+//! - `fulfil_intent`: Fulfil a claimed intent with a proof, opening its dispute period
+//! - `slash_expired_claim`: Slash a solver whose claim has timed out
+//! - `dispute_fulfilment`: Owner rejection of a fulfilment, slashing the solver
+//! - `collect_reward`: Pay the reward of a fulfilment once its dispute period has passed
+//! - `add_relayer` / `remove_relayer`: Manage the relayers attesting external execution
+//! - `attach_external_tx`: Attaches a transaction executed on another chain to an intent
+//! - `attest_external_execution`: Relayer confirmation moving an intent to `Executed`
+//! - `create_multi_step_intent`: Creates an intent made of sub-intents with dependencies
+//! - `update_sub_intent_status`: Resolves a sub-intent, rolling up the parent status
+//! - `remove_intent`: Removes a resolved intent, releasing its deposit
+//! - `cancel_intent`: Cancels a pending intent with a reason, refunding its reward; the deposit
+//!   stays held until the intent is removed
+//! - `add_delegate` / `remove_delegate`: Let another account record journey steps or update
+//!   statuses on the caller's intents
+//! - `create_template` / `remove_template`: Manage reusable intent templates, each holding
+//!   `TemplateDeposit`
+//! - `schedule_recurring_intent`: Instantiates a template every `period` blocks, `count` times,
+//!   holding `RecurringDeposit` while the schedule is active
+//! - `cancel_recurring_intent`: Stops a recurring schedule
+//!
+//! ## Hooks
+//!
+//! - `on_initialize`: Instantiates the recurring intents due, up to `MaxInstantiationsPerBlock`
+//!   per block. Occurrences over the limit are carried over to the following blocks.
+//!
+//! ## Migrations
+//!
+//! - `migrations::v1::MigrateV0ToV1`: Adds the optional typed `kind` to stored intents
+//! - `migrations::v2::MigrateV1ToV2`: Adds step kind, author, linked transaction and attachment
+//!   to stored journey steps
+//! - `migrations::v3::MigrateV2ToV3`: Moves the per-account intent lists into the
+//!   `IntentsByAccount` double map
+//! - `migrations::v4::MigrateV3ToV4`: Moves journeys into the `JourneySteps` double map and
+//!   builds their hash chains
+//! - `migrations::v5::MigrateV4ToV5`: Applies `MaxMetadataLen` and `MaxDescriptionLen` to stored
+//!   intents and journey steps
+//! - `migrations::v6::MigrateV5ToV6`: Rekeys `IntentsByAccount` by big-endian intent ID, so that
+//!   it iterates in creation order
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use pallet::*;
 
+pub mod migrations;
+
 #[cfg(test)]
 mod mock;
 
@@ -47,7 +131,20 @@ pub use weights::*;
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
-    use frame_support::pallet_prelude::*;
+    use frame_support::{
+        dispatch::{GetDispatchInfo, PostDispatchInfo},
+        pallet_prelude::*,
+        sp_runtime::{
+            traits::{Dispatchable, Hash, One, Saturating, Zero},
+            Perbill,
+        },
+        storage::{with_storage_layer, with_transaction, TransactionOutcome},
+        traits::{
+            fungible::{self, MutateHold},
+            tokens::{Fortitude, Precision, Restriction},
+            GetCallMetadata, IsSubType,
+        },
+    };
     use frame_system::pallet_prelude::*;
     use sp_std::vec::Vec;
 
@@ -60,6 +157,8 @@ pub mod pallet {
         Executed,
         /// Intent execution has failed
         Failed,
+        /// Intent has been cancelled before being carried out
+        Cancelled,
     }
 
     impl IntentStatus {
@@ -69,6 +168,7 @@ pub mod pallet {
                 IntentStatus::Pending => 0,
                 IntentStatus::Executed => 1,
                 IntentStatus::Failed => 2,
+                IntentStatus::Cancelled => 3,
             }
         }
 
@@ -78,17 +178,138 @@ pub mod pallet {
                 0 => Some(IntentStatus::Pending),
                 1 => Some(IntentStatus::Executed),
                 2 => Some(IntentStatus::Failed),
+                3 => Some(IntentStatus::Cancelled),
                 _ => None,
             }
         }
     }
 
+    /// Why an intent has been cancelled
+    #[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+    pub enum CancelReason {
+        /// The owner no longer wants the intent carried out
+        UserRequested,
+        /// The intent was not carried out in time
+        Expired,
+        /// Market conditions moved beyond the intent's tolerance
+        MarketMoved,
+        /// The owner lacks the funds to carry out the intent
+        InsufficientFunds,
+        /// The intent has been replaced by another one
+        Superseded(IntentId),
+    }
+
+    /// Type alias for the balance type of the configured currency
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as fungible::Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
     /// Type alias for Intent ID
     pub type IntentId = u64;
 
+    /// Key of an intent in `IntentsByAccount`: its ID in big-endian byte order
+    pub type IntentKey = [u8; 8];
+
+    /// `IntentsByAccount` key of `intent_id`
+    pub fn intent_key(intent_id: IntentId) -> IntentKey {
+        intent_id.to_be_bytes()
+    }
+
     /// Type alias for Moment (timestamp)
     pub type Moment = u64;
 
+    /// Type alias for the identifier of an asset referenced by an intent
+    pub type AssetId = u32;
+
+    /// Type alias for an amount of an asset referenced by an intent
+    pub type IntentBalance = u128;
+
+    /// Type alias for the identifier of a destination chain
+    pub type ChainId = u32;
+
+    /// Maximum number of actions in a batch intent
+    pub type MaxBatchActions = ConstU32<16>;
+
+    /// Transfer `amount` of `asset` to `dest`
+    #[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+    pub struct TransferIntent<AccountId> {
+        /// Recipient of the transfer
+        pub dest: AccountId,
+        /// Asset being transferred
+        pub asset: AssetId,
+        /// Amount being transferred
+        pub amount: IntentBalance,
+    }
+
+    /// Swap `amount_in` of `asset_in` for at least `min_amount_out` of `asset_out`
+    #[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+    pub struct SwapIntent {
+        /// Asset being sold
+        pub asset_in: AssetId,
+        /// Asset being bought
+        pub asset_out: AssetId,
+        /// Amount of `asset_in` being sold
+        pub amount_in: IntentBalance,
+        /// Minimum acceptable amount of `asset_out`
+        pub min_amount_out: IntentBalance,
+    }
+
+    /// Bridge `amount` of `asset` to `recipient` on `dest_chain`
+    #[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+    pub struct BridgeIntent {
+        /// Asset being bridged
+        pub asset: AssetId,
+        /// Amount being bridged
+        pub amount: IntentBalance,
+        /// Destination chain identifier
+        pub dest_chain: ChainId,
+        /// Recipient address on the destination chain, in its native encoding (bounded to 64 bytes)
+        pub recipient: BoundedVec<u8, ConstU32<64>>,
+    }
+
+    /// Call the EVM contract at `contract` with `input`, sending `value`
+    #[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+    pub struct ContractCallIntent {
+        /// H160 address of the contract
+        pub contract: [u8; 20],
+        /// Native value sent with the call
+        pub value: IntentBalance,
+        /// ABI-encoded call data (bounded to 256 bytes)
+        pub input: BoundedVec<u8, ConstU32<256>>,
+    }
+
+    /// A single action within a batch intent
+    #[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+    pub enum IntentAction<AccountId> {
+        Transfer(TransferIntent<AccountId>),
+        Swap(SwapIntent),
+        Bridge(BridgeIntent),
+        ContractCall(ContractCallIntent),
+    }
+
+    /// Typed description of what an intent should accomplish
+    #[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+    pub enum IntentKind<AccountId> {
+        Transfer(TransferIntent<AccountId>),
+        Swap(SwapIntent),
+        Bridge(BridgeIntent),
+        ContractCall(ContractCallIntent),
+        /// Actions to be carried out in order
+        Batch(BoundedVec<IntentAction<AccountId>, MaxBatchActions>),
+    }
+
+    impl<AccountId> IntentKind<AccountId> {
+        /// Position of the variant, used as bit index in `PersonaStats::kinds_executed`
+        pub fn kind_index(&self) -> u8 {
+            match self {
+                IntentKind::Transfer(_) => 0,
+                IntentKind::Swap(_) => 1,
+                IntentKind::Bridge(_) => 2,
+                IntentKind::ContractCall(_) => 3,
+                IntentKind::Batch(_) => 4,
+            }
+        }
+    }
+
     /// Intent struct containing all intent-related data
     #[derive(Clone, Encode, Decode, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
     #[scale_info(skip_type_params(T))]
@@ -101,8 +322,198 @@ pub mod pallet {
         pub status: IntentStatus,
         /// Block number when intent was created
         pub timestamp: BlockNumberFor<T>,
-        /// Metadata describing the intent (bounded to 256 bytes)
-        pub metadata: BoundedVec<u8, ConstU32<256>>,
+        /// Metadata describing the intent (bounded to `MaxMetadataLen` bytes)
+        pub metadata: BoundedVec<u8, T::MaxMetadataLen>,
+        /// Typed payload of the intent (`None` for intents created before typed payloads)
+        pub kind: Option<IntentKind<T::AccountId>>,
+    }
+
+    /// A step of a multi-step intent
+    ///
+    /// Its action is the batch action at the same index in the parent intent's payload.
+    #[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+    pub struct SubIntent {
+        /// Indices of the sub-intents that must be executed first, all lower than this one's
+        pub depends_on: BoundedVec<u32, MaxBatchActions>,
+        /// Current status of the sub-intent
+        pub status: IntentStatus,
+    }
+
+    /// A transaction on another chain carrying out (part of) an intent
+    #[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+    pub struct ChainRef {
+        /// Chain the transaction was executed on
+        pub chain_id: ChainId,
+        /// Transaction hash or signature in the chain's native encoding (bounded to 64 bytes)
+        pub tx_hash: BoundedVec<u8, ConstU32<64>>,
+        /// Block of the external chain containing the transaction
+        pub block_number: u64,
+        /// Whether a relayer has attested the transaction
+        pub attested: bool,
+    }
+
+    /// Activity counters of an account, derived from its intents
+    #[derive(Clone, Encode, Decode, Debug, Default, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+    pub struct PersonaStats {
+        /// Number of intents created
+        pub intents_created: u32,
+        /// Number of intents currently `Executed` whose execution was attested
+        pub intents_executed: u32,
+        /// Number of intents currently `Failed`
+        pub intents_failed: u32,
+        /// Bitmask of the intent kinds with an attested execution, see `IntentKind::kind_index`
+        pub kinds_executed: u8,
+        /// Number of journey steps recorded on the account's intents
+        pub journey_steps: u32,
+    }
+
+    impl PersonaStats {
+        /// Percentage of resolved intents that were executed, `None` if none is resolved
+        pub fn success_ratio(&self) -> Option<u32> {
+            let resolved = self.intents_executed.saturating_add(self.intents_failed);
+            (resolved > 0)
+                .then(|| (u64::from(self.intents_executed) * 100 / u64::from(resolved)) as u32)
+        }
+
+        /// Number of distinct intent kinds executed
+        pub fn distinct_kinds(&self) -> u32 {
+            self.kinds_executed.count_ones()
+        }
+
+        /// Value of the counter measured by `kind`
+        ///
+        /// The success ratio only counts once `min_resolved` intents are resolved, so that a
+        /// single lucky intent does not earn it.
+        pub fn progress(&self, kind: &AchievementKind, min_resolved: u32) -> u32 {
+            match kind {
+                AchievementKind::IntentsExecuted => self.intents_executed,
+                AchievementKind::SuccessRatio => {
+                    let resolved = self.intents_executed.saturating_add(self.intents_failed);
+                    if resolved < min_resolved {
+                        0
+                    } else {
+                        self.success_ratio().unwrap_or_default()
+                    }
+                },
+                AchievementKind::DistinctKinds => self.distinct_kinds(),
+                AchievementKind::JourneySteps => self.journey_steps,
+            }
+        }
+    }
+
+    /// Persona counter an achievement is awarded on
+    #[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+    pub enum AchievementKind {
+        /// Number of executed intents
+        IntentsExecuted,
+        /// Percentage of resolved intents that were executed
+        SuccessRatio,
+        /// Number of distinct intent kinds executed
+        DistinctKinds,
+        /// Number of journey steps recorded
+        JourneySteps,
+    }
+
+    /// A soulbound badge, unlocked once a persona counter reaches `threshold`
+    #[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+    pub struct Achievement {
+        /// Counter the achievement is awarded on
+        pub kind: AchievementKind,
+        /// Value the counter has to reach
+        pub threshold: u32,
+    }
+
+    /// Failure of an intent's dispatched calls
+    #[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+    pub struct ExecutionError {
+        /// Index of the call that failed
+        pub call_index: u32,
+        /// Error returned by the failed call
+        pub error: DispatchError,
+    }
+
+    /// A registered solver and its bond
+    #[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+    pub struct SolverInfo<Balance> {
+        /// Amount currently held as bond
+        pub bond: Balance,
+        /// Number of intents currently claimed by the solver
+        pub active_claims: u32,
+        /// Number of intents fulfilled by the solver
+        pub fulfilled: u32,
+        /// Number of claims slashed after timing out
+        pub slashed: u32,
+    }
+
+    /// A solver's claim on an intent
+    #[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+    pub struct IntentClaim<AccountId, BlockNumber> {
+        /// Solver that claimed the intent
+        pub solver: AccountId,
+        /// Last block at which the solver may fulfil the intent
+        pub deadline: BlockNumber,
+    }
+
+    /// A fulfilment whose reward is withheld until the owner can no longer dispute it
+    #[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+    pub struct PendingFulfilment<AccountId, Balance, BlockNumber> {
+        /// Solver that fulfilled the intent
+        pub solver: AccountId,
+        /// Reward payable to the solver, still held from the intent owner
+        pub reward: Balance,
+        /// Last block at which the owner may dispute the fulfilment
+        pub dispute_until: BlockNumber,
+    }
+
+    /// Permissions granted to a delegate over the owner's intents
+    #[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+    pub enum DelegateScope {
+        /// May record journey steps
+        RecordJourney,
+        /// May update intent statuses
+        UpdateStatus,
+        /// May record journey steps and update intent statuses
+        Full,
+    }
+
+    impl DelegateScope {
+        /// Whether this scope grants the permissions of `required`
+        pub fn covers(&self, required: &DelegateScope) -> bool {
+            matches!(self, DelegateScope::Full) || self == required
+        }
+    }
+
+    /// A delegation from an intent owner
+    #[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+    pub struct Delegation<BlockNumber> {
+        /// Permissions granted to the delegate
+        pub scope: DelegateScope,
+        /// Last block at which the delegate may act, `None` if it does not expire
+        pub expires_at: Option<BlockNumber>,
+    }
+
+    /// What a journey step describes
+    #[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+    pub enum JourneyStepKind {
+        /// A transaction has been submitted
+        TxSubmitted,
+        /// A transaction has been included and confirmed
+        TxConfirmed,
+        /// Funds have moved across a bridge
+        BridgeHop,
+        /// Free-form commentary
+        Note,
+        /// A notable point in the journey
+        Milestone,
+    }
+
+    /// A transaction linked to a journey step
+    #[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+    pub enum LinkedTx {
+        /// Hash of a Substrate extrinsic
+        Substrate([u8; 32]),
+        /// Hash of an Ethereum transaction
+        Ethereum([u8; 32]),
     }
 
     /// Journey step struct representing a single step in the user's journey
@@ -111,31 +522,201 @@ pub mod pallet {
     pub struct JourneyStep<T: Config> {
         /// Sequential step identifier within the intent
         pub step_id: u32,
-        /// Description of the journey step (bounded to 512 bytes)
-        pub description: BoundedVec<u8, ConstU32<512>>,
+        /// What the step describes
+        pub step_kind: JourneyStepKind,
+        /// Description of the journey step (bounded to `MaxDescriptionLen` bytes)
+        pub description: BoundedVec<u8, T::MaxDescriptionLen>,
         /// Block number when step was recorded
         pub timestamp: BlockNumberFor<T>,
+        /// Account that recorded the step: the owner, a delegate or the claiming solver
+        pub author: T::AccountId,
+        /// Transaction the step refers to, if any
+        pub linked_tx: Option<LinkedTx>,
+        /// CID of an attachment stored off-chain, e.g. on IPFS (bounded to 128 bytes)
+        pub attachment: Option<BoundedVec<u8, ConstU32<128>>>,
+    }
+
+    /// Type of journey step identifiers, sequential within an intent
+    pub type StepId = u32;
+
+    /// Tip of an intent's journey hash chain
+    ///
+    /// Each recorded step extends the chain as `hash = H(previous hash, step)`, starting from the
+    /// all-zero hash. Steps cannot be edited or reordered once recorded, so a head commits to
+    /// every step before it.
+    #[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+    pub struct JourneyHead<Hash> {
+        /// Number of steps covered by `hash`
+        pub step_count: u32,
+        /// Hash chain head after the last step
+        pub hash: Hash,
     }
 
+    /// Type alias for intent template IDs
+    pub type TemplateId = u64;
+
+    /// Type alias for recurring schedule IDs
+    pub type ScheduleId = u64;
+
+    /// A reusable intent stored by an account
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct IntentTemplate<T: Config> {
+        /// Typed payload of the intents created from the template
+        pub kind: IntentKind<T::AccountId>,
+        /// Metadata of the intents created from the template
+        pub metadata: BoundedVec<u8, T::MaxMetadataLen>,
+    }
+
+    /// A template instantiated every `period` blocks
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct RecurringIntent<T: Config> {
+        /// Account owning the template and the created intents
+        pub owner: T::AccountId,
+        /// Template instantiated at each occurrence
+        pub template_id: TemplateId,
+        /// Blocks between two occurrences
+        pub period: BlockNumberFor<T>,
+        /// Occurrences left, including the next one
+        pub remaining: u32,
+        /// Block at which the next occurrence is due
+        pub next_at: BlockNumberFor<T>,
+    }
+
+    /// The in-code storage version
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(6);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
+    /// Reasons for the pallet placing a hold on funds
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// Bond held while an account is registered as a solver
+        SolverBond,
+        /// Reward escrowed by an intent creator until the intent is fulfilled
+        IntentReward,
+        /// Deposit held from an intent creator for as long as the intent is stored
+        IntentDeposit,
+        /// Deposit held from a template owner for as long as the template is stored
+        TemplateDeposit,
+        /// Deposit held from a schedule owner for as long as the schedule is active
+        RecurringDeposit,
+    }
+
     /// Configuration trait for the ChainGhost pallet
     #[pallet::config]
     pub trait Config: frame_system::Config {
         /// The overarching runtime event type
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
+        /// The overarching hold reason
+        type RuntimeHoldReason: From<HoldReason>;
+
+        /// Currency used for solver bonds, intent rewards and deposits
+        type Currency: fungible::Mutate<Self::AccountId>
+            + fungible::MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+
         /// Weight information for extrinsics in this pallet
         type WeightInfo: WeightInfo;
 
-        /// Maximum number of intents per account
+        /// Deposit held for every stored intent, released when the intent is removed
+        #[pallet::constant]
+        type IntentDeposit: Get<BalanceOf<Self>>;
+
+        /// Deposit held for every stored template, released when the template is removed
+        #[pallet::constant]
+        type TemplateDeposit: Get<BalanceOf<Self>>;
+
+        /// Deposit held for every active recurring schedule, released when the schedule ends
         #[pallet::constant]
-        type MaxIntentsPerAccount: Get<u32>;
+        type RecurringDeposit: Get<BalanceOf<Self>>;
 
         /// Maximum number of journey steps per intent
         #[pallet::constant]
         type MaxJourneyStepsPerIntent: Get<u32>;
+
+        /// Maximum length of intent metadata, in bytes
+        #[pallet::constant]
+        type MaxMetadataLen: Get<u32>;
+
+        /// Maximum length of a journey step description, in bytes
+        #[pallet::constant]
+        type MaxDescriptionLen: Get<u32>;
+
+        /// The runtime call type dispatched on behalf of intent owners
+        type RuntimeCall: Parameter
+            + Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
+            + GetDispatchInfo
+            + GetCallMetadata
+            + From<frame_system::Call<Self>>
+            + IsSubType<Call<Self>>;
+
+        /// Maximum number of calls dispatched by a single intent
+        ///
+        /// Each call records a journey step, so this must not exceed `MaxJourneyStepsPerIntent`.
+        #[pallet::constant]
+        type MaxCallsPerIntent: Get<u32>;
+
+        /// Bond held from an account registering as a solver
+        ///
+        /// Also the minimum bond a solver must still hold to claim intents.
+        #[pallet::constant]
+        type SolverBond: Get<BalanceOf<Self>>;
+
+        /// Number of blocks a solver has to fulfil a claimed intent
+        #[pallet::constant]
+        type ClaimTimeout: Get<BlockNumberFor<Self>>;
+
+        /// Portion of `SolverBond` paid to the intent creator when a claim times out or a
+        /// fulfilment is disputed
+        #[pallet::constant]
+        type ClaimSlash: Get<Perbill>;
+
+        /// Maximum number of intents a solver may have claimed at once
+        ///
+        /// Keep `ClaimSlash` times this at or below 100%, so that every open claim is backed
+        /// by a full slash.
+        #[pallet::constant]
+        type MaxActiveClaims: Get<u32>;
+
+        /// Number of blocks the intent owner has to dispute a fulfilment before its reward
+        /// can be paid
+        #[pallet::constant]
+        type DisputePeriod: Get<BlockNumberFor<Self>>;
+
+        /// Maximum number of delegates per account
+        #[pallet::constant]
+        type MaxDelegates: Get<u32>;
+
+        /// Maximum number of external chain transactions attached to an intent
+        #[pallet::constant]
+        type MaxChainRefsPerIntent: Get<u32>;
+
+        /// Origin allowed to add and remove relayers
+        type RelayerAdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Achievements awarded when a persona counter reaches their threshold
+        #[pallet::constant]
+        type AchievementThresholds: Get<Vec<Achievement>>;
+
+        /// Resolved intents required before the success ratio earns achievements
+        #[pallet::constant]
+        type MinResolvedForSuccessRatio: Get<u32>;
+
+        /// Maximum number of intent templates per account
+        #[pallet::constant]
+        type MaxTemplatesPerAccount: Get<u32>;
+
+        /// Maximum number of active recurring schedules per account
+        #[pallet::constant]
+        type MaxRecurringPerAccount: Get<u32>;
+
+        /// Maximum number of agenda occurrences processed in a block, any excess is carried over
+        #[pallet::constant]
+        type MaxInstantiationsPerBlock: Get<u32>;
     }
 
     /// Storage for the next intent ID (auto-incrementing counter)
@@ -148,28 +729,258 @@ pub mod pallet {
     #[pallet::getter(fn intent_by_id)]
     pub type IntentById<T: Config> = StorageMap<_, Blake2_128Concat, IntentId, Intent<T>>;
 
-    /// Storage mapping from AccountId to list of IntentIds
+    /// Storage double map indexing intents by owner: (AccountId, IntentKey) → ()
+    ///
+    /// Intent IDs are stored unhashed and big-endian, so each account's intents iterate in
+    /// creation order.
     #[pallet::storage]
-    #[pallet::getter(fn intents_by_account)]
-    pub type IntentsByAccount<T: Config> = StorageMap<
+    pub type IntentsByAccount<T: Config> = StorageDoubleMap<
         _,
         Blake2_128Concat,
         T::AccountId,
-        BoundedVec<IntentId, T::MaxIntentsPerAccount>,
+        Identity,
+        IntentKey,
+        (),
+        OptionQuery,
+    >;
+
+    /// Storage mapping from AccountId to the number of intents it owns
+    #[pallet::storage]
+    #[pallet::getter(fn intent_count)]
+    pub type IntentCountByAccount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Storage mapping from IntentId to the deposit held for it
+    ///
+    /// Intents created before deposits were introduced have no deposit.
+    #[pallet::storage]
+    #[pallet::getter(fn intent_deposit)]
+    pub type IntentDeposits<T: Config> =
+        StorageMap<_, Blake2_128Concat, IntentId, BalanceOf<T>, ValueQuery>;
+
+    /// Storage mapping from IntentId to the sub-intents of a multi-step intent
+    #[pallet::storage]
+    #[pallet::getter(fn sub_intents)]
+    pub type SubIntents<T: Config> =
+        StorageMap<_, Blake2_128Concat, IntentId, BoundedVec<SubIntent, MaxBatchActions>>;
+
+    /// Storage mapping from IntentId to the external chain transactions attached to it
+    #[pallet::storage]
+    #[pallet::getter(fn chain_refs)]
+    pub type ChainRefs<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        IntentId,
+        BoundedVec<ChainRef, T::MaxChainRefsPerIntent>,
         ValueQuery,
     >;
 
-    /// Storage mapping from IntentId to list of JourneySteps
+    /// Storage mapping of relayers authorized to attest external execution
+    #[pallet::storage]
+    #[pallet::getter(fn is_relayer)]
+    pub type Relayers<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+    /// Storage mapping from AccountId to its persona counters
+    #[pallet::storage]
+    #[pallet::getter(fn persona)]
+    pub type Personas<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, PersonaStats, ValueQuery>;
+
+    /// Storage set of the `Executed` intents whose execution was attested
+    ///
+    /// Intents dispatched on chain, fulfilled by a solver or attested by a relayer. Only these
+    /// count as executed in persona counters, so that owners cannot earn badges by reporting
+    /// their own intents as executed.
+    #[pallet::storage]
+    #[pallet::getter(fn is_execution_attested)]
+    pub type AttestedExecutions<T: Config> =
+        StorageMap<_, Blake2_128Concat, IntentId, (), OptionQuery>;
+
+    /// Storage double map of (AccountId, Achievement) to the block it was unlocked at
+    ///
+    /// Achievements are soulbound: they are never transferred nor revoked.
+    #[pallet::storage]
+    #[pallet::getter(fn achievement_unlocked_at)]
+    pub type UnlockedAchievements<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        Achievement,
+        BlockNumberFor<T>,
+        OptionQuery,
+    >;
+
+    /// Storage mapping from IntentId to the reason it was cancelled
+    #[pallet::storage]
+    #[pallet::getter(fn cancel_reason)]
+    pub type CancelReasons<T: Config> =
+        StorageMap<_, Blake2_128Concat, IntentId, CancelReason, OptionQuery>;
+
+    /// Storage mapping from IntentId to the failure of its dispatched calls
+    #[pallet::storage]
+    #[pallet::getter(fn intent_error)]
+    pub type IntentErrors<T: Config> = StorageMap<_, Blake2_128Concat, IntentId, ExecutionError>;
+
+    /// Storage mapping from AccountId to solver registration
+    #[pallet::storage]
+    #[pallet::getter(fn solver)]
+    pub type Solvers<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, SolverInfo<BalanceOf<T>>>;
+
+    /// Storage mapping from IntentId to the reward escrowed by its creator
+    #[pallet::storage]
+    #[pallet::getter(fn intent_reward)]
+    pub type IntentRewards<T: Config> =
+        StorageMap<_, Blake2_128Concat, IntentId, BalanceOf<T>, ValueQuery>;
+
+    /// Storage mapping from IntentId to the solver's active claim
+    #[pallet::storage]
+    #[pallet::getter(fn intent_claim)]
+    pub type IntentClaims<T: Config> =
+        StorageMap<_, Blake2_128Concat, IntentId, IntentClaim<T::AccountId, BlockNumberFor<T>>>;
+
+    /// Storage mapping from IntentId to the proof submitted by the fulfilling solver
+    #[pallet::storage]
+    #[pallet::getter(fn fulfilment_proof)]
+    pub type FulfilmentProofs<T: Config> =
+        StorageMap<_, Blake2_128Concat, IntentId, BoundedVec<u8, ConstU32<256>>>;
+
+    /// Storage mapping from IntentId to the fulfilment awaiting the end of its dispute period
+    #[pallet::storage]
+    #[pallet::getter(fn pending_fulfilment)]
+    pub type PendingFulfilments<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        IntentId,
+        PendingFulfilment<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+    >;
+
+    /// Storage double map from (owner, delegate) to the delegation granted by the owner
+    #[pallet::storage]
+    #[pallet::getter(fn delegation)]
+    pub type Delegates<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        Delegation<BlockNumberFor<T>>,
+    >;
+
+    /// Storage mapping from AccountId to the number of delegates it has granted
+    #[pallet::storage]
+    #[pallet::getter(fn delegate_count)]
+    pub type DelegateCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Storage double map from (IntentId, StepId) to the recorded JourneyStep
+    ///
+    /// Read a whole journey in order with [`Pallet::journey_by_intent`].
     #[pallet::storage]
-    #[pallet::getter(fn journey_by_intent)]
-    pub type JourneyByIntent<T: Config> = StorageMap<
+    #[pallet::getter(fn journey_step)]
+    pub type JourneySteps<T: Config> = StorageDoubleMap<
         _,
         Blake2_128Concat,
         IntentId,
-        BoundedVec<JourneyStep<T>, T::MaxJourneyStepsPerIntent>,
+        Twox64Concat,
+        StepId,
+        JourneyStep<T>,
+        OptionQuery,
+    >;
+
+    /// Storage mapping from IntentId to the head of its journey hash chain
+    #[pallet::storage]
+    #[pallet::getter(fn journey_head)]
+    pub type JourneyHeads<T: Config> =
+        StorageMap<_, Blake2_128Concat, IntentId, JourneyHead<T::Hash>, OptionQuery>;
+
+    /// Storage for the next template ID (auto-incrementing counter)
+    #[pallet::storage]
+    #[pallet::getter(fn next_template_id)]
+    pub type NextTemplateId<T> = StorageValue<_, TemplateId, ValueQuery>;
+
+    /// Storage double map from (owner, TemplateId) to the intent template
+    #[pallet::storage]
+    #[pallet::getter(fn template)]
+    pub type Templates<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Twox64Concat,
+        TemplateId,
+        IntentTemplate<T>,
+        OptionQuery,
+    >;
+
+    /// Storage mapping from AccountId to the number of templates it stores
+    #[pallet::storage]
+    #[pallet::getter(fn template_count)]
+    pub type TemplateCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Storage double map from (owner, TemplateId) to the deposit held for the template
+    ///
+    /// Templates created before deposits were introduced have no deposit.
+    #[pallet::storage]
+    #[pallet::getter(fn template_deposit)]
+    pub type TemplateDeposits<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Twox64Concat,
+        TemplateId,
+        BalanceOf<T>,
         ValueQuery,
     >;
 
+    /// Storage for the next recurring schedule ID (auto-incrementing counter)
+    #[pallet::storage]
+    #[pallet::getter(fn next_schedule_id)]
+    pub type NextScheduleId<T> = StorageValue<_, ScheduleId, ValueQuery>;
+
+    /// Storage mapping from ScheduleId to the active recurring schedule
+    #[pallet::storage]
+    #[pallet::getter(fn recurring_intent)]
+    pub type RecurringIntents<T: Config> =
+        StorageMap<_, Twox64Concat, ScheduleId, RecurringIntent<T>, OptionQuery>;
+
+    /// Storage mapping from AccountId to the number of its active recurring schedules
+    #[pallet::storage]
+    #[pallet::getter(fn recurring_count)]
+    pub type RecurringCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Storage mapping from ScheduleId to the deposit held for the active schedule
+    ///
+    /// Schedules created before deposits were introduced have no deposit.
+    #[pallet::storage]
+    #[pallet::getter(fn recurring_deposit)]
+    pub type RecurringDeposits<T: Config> =
+        StorageMap<_, Twox64Concat, ScheduleId, BalanceOf<T>, ValueQuery>;
+
+    /// Storage double map of the schedule occurrences due at each block
+    ///
+    /// Entries of cancelled schedules are left in place and skipped when processed.
+    #[pallet::storage]
+    pub type Agenda<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Twox64Concat,
+        ScheduleId,
+        (),
+        OptionQuery,
+    >;
+
+    /// Oldest block whose agenda may still hold occurrences
+    ///
+    /// Together with the agendas of the blocks up to the current one, it forms the carry-over
+    /// queue of occurrences that did not fit in `MaxInstantiationsPerBlock`.
+    #[pallet::storage]
+    #[pallet::getter(fn agenda_cursor)]
+    pub type AgendaCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
     /// Events emitted by the ChainGhost pallet
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -187,9 +998,9 @@ pub mod pallet {
         IntentStatusUpdated {
             /// The unique intent ID
             intent_id: IntentId,
-            /// Previous status (as u8: 0=Pending, 1=Executed, 2=Failed)
+            /// Previous status (as u8: 0=Pending, 1=Executed, 2=Failed, 3=Cancelled)
             old_status: u8,
-            /// New status (as u8: 0=Pending, 1=Executed, 2=Failed)
+            /// New status (as u8: 0=Pending, 1=Executed, 2=Failed, 3=Cancelled)
             new_status: u8,
         },
         /// A journey step has been recorded for an intent
@@ -199,104 +1010,437 @@ pub mod pallet {
             /// Total number of journey steps for this intent
             step_count: u32,
         },
-    }
-
-    /// Errors that can be returned by the ChainGhost pallet
-    #[pallet::error]
-    pub enum Error<T> {
-        /// The specified intent does not exist
-        IntentNotFound,
-        /// The caller is not the owner of the intent
-        NotIntentOwner,
-        /// The account has reached the maximum number of intents
-        MaxIntentsPerAccountExceeded,
-        /// The intent has reached the maximum number of journey steps
-        MaxJourneyStepsExceeded,
-        /// The intent status is invalid for this operation
-        InvalidIntentStatus,
-    }
-
-    #[pallet::call]
-    impl<T: Config> Pallet<T> {
-        /// Execute a new intent with the provided metadata
-        ///
-        /// Creates a new intent record, assigns it a unique ID, and stores it in the blockchain.
-        /// Enforces per-account intent limits to prevent spam.
-        ///
-        /// # Parameters
+        /// The calls carried by an intent have been dispatched
+        IntentCallsDispatched {
+            /// The unique intent ID
+            intent_id: IntentId,
+            /// Number of calls carried by the intent
+            call_count: u32,
+            /// Result of the batch; on error, no call had any effect
+            result: DispatchResult,
+        },
+        /// An account has registered as a solver
+        SolverRegistered {
+            /// The solver account
+            solver: T::AccountId,
+            /// Bond held from the solver
+            bond: BalanceOf<T>,
+        },
+        /// A solver has deregistered and its remaining bond was released
+        SolverDeregistered {
+            /// The solver account
+            solver: T::AccountId,
+            /// Bond released to the solver
+            bond: BalanceOf<T>,
+        },
+        /// An intent creator has escrowed a reward for fulfilling the intent
+        RewardOffered {
+            /// The unique intent ID
+            intent_id: IntentId,
+            /// Escrowed reward
+            reward: BalanceOf<T>,
+        },
+        /// A solver has claimed an intent
+        IntentClaimed {
+            /// The unique intent ID
+            intent_id: IntentId,
+            /// The claiming solver
+            solver: T::AccountId,
+            /// Last block at which the solver may fulfil the intent
+            deadline: BlockNumberFor<T>,
+        },
+        /// A solver has fulfilled a claimed intent, opening its dispute period
+        IntentFulfilled {
+            /// The unique intent ID
+            intent_id: IntentId,
+            /// The fulfilling solver
+            solver: T::AccountId,
+            /// Reward payable to the solver once the dispute period has passed
+            reward: BalanceOf<T>,
+            /// Last block at which the owner may dispute the fulfilment
+            dispute_until: BlockNumberFor<T>,
+        },
+        /// A solver's claim timed out and part of its bond was paid to the intent creator
+        ClaimSlashed {
+            /// The unique intent ID
+            intent_id: IntentId,
+            /// The slashed solver
+            solver: T::AccountId,
+            /// Amount moved from the solver's bond to the intent creator
+            amount: BalanceOf<T>,
+        },
+        /// The intent owner disputed a fulfilment, reopening the intent and slashing the solver
+        FulfilmentDisputed {
+            /// The unique intent ID
+            intent_id: IntentId,
+            /// The slashed solver
+            solver: T::AccountId,
+            /// Amount moved from the solver's bond to the intent owner
+            amount: BalanceOf<T>,
+        },
+        /// The reward of an undisputed fulfilment was paid to the solver
+        RewardPaid {
+            /// The unique intent ID
+            intent_id: IntentId,
+            /// The solver paid
+            solver: T::AccountId,
+            /// Reward paid
+            reward: BalanceOf<T>,
+        },
+        /// A sub-intent of a multi-step intent has changed status
+        SubIntentStatusUpdated {
+            /// The parent intent ID
+            intent_id: IntentId,
+            /// Index of the sub-intent
+            index: u32,
+            /// Previous status (as u8: 0=Pending, 1=Executed, 2=Failed, 3=Cancelled)
+            old_status: u8,
+            /// New status (as u8: 0=Pending, 1=Executed, 2=Failed, 3=Cancelled)
+            new_status: u8,
+        },
+        /// A relayer has been authorized to attest external execution
+        RelayerAdded {
+            /// The relayer account
+            relayer: T::AccountId,
+        },
+        /// A relayer has lost its authorization
+        RelayerRemoved {
+            /// The relayer account
+            relayer: T::AccountId,
+        },
+        /// An external chain transaction has been attached to an intent
+        ExternalTxAttached {
+            /// The unique intent ID
+            intent_id: IntentId,
+            /// Index of the reference in the intent's list
+            index: u32,
+            /// Chain the transaction was executed on
+            chain_id: ChainId,
+        },
+        /// A relayer has attested an external transaction, executing the intent
+        ExternalExecutionAttested {
+            /// The unique intent ID
+            intent_id: IntentId,
+            /// Index of the attested reference
+            index: u32,
+            /// The attesting relayer
+            relayer: T::AccountId,
+        },
+        /// An intent has been cancelled and its reward refunded; its deposit stays held until
+        /// the intent is removed
+        IntentCancelled {
+            /// The unique intent ID
+            intent_id: IntentId,
+            /// Why the intent was cancelled
+            reason: CancelReason,
+        },
+        /// A resolved intent has been removed and its deposit released
+        IntentRemoved {
+            /// The unique intent ID
+            intent_id: IntentId,
+            /// The owner of the intent
+            account: T::AccountId,
+        },
+        /// An intent template has been stored
+        TemplateCreated {
+            /// The template owner
+            account: T::AccountId,
+            /// The new template ID
+            template_id: TemplateId,
+        },
+        /// An intent template has been removed
+        TemplateRemoved {
+            /// The template owner
+            account: T::AccountId,
+            /// The removed template ID
+            template_id: TemplateId,
+        },
+        /// A template has been scheduled to be instantiated periodically
+        RecurringIntentScheduled {
+            /// The new schedule ID
+            schedule_id: ScheduleId,
+            /// The schedule owner
+            account: T::AccountId,
+            /// The scheduled template
+            template_id: TemplateId,
+            /// Blocks between two occurrences
+            period: BlockNumberFor<T>,
+            /// Number of occurrences
+            count: u32,
+            /// Block at which the first occurrence is due
+            first_at: BlockNumberFor<T>,
+        },
+        /// A recurring schedule has created an intent
+        RecurringIntentInstantiated {
+            /// The schedule ID
+            schedule_id: ScheduleId,
+            /// The created intent, also announced by `IntentExecuted`
+            intent_id: IntentId,
+            /// Occurrences left
+            remaining: u32,
+        },
+        /// An occurrence of a recurring schedule could not create its intent
+        RecurringIntentFailed {
+            /// The schedule ID
+            schedule_id: ScheduleId,
+            /// Why the intent could not be created
+            error: DispatchError,
+            /// Occurrences left
+            remaining: u32,
+        },
+        /// A recurring schedule has been cancelled by its owner, or because its template was
+        /// removed
+        RecurringIntentCancelled {
+            /// The schedule ID
+            schedule_id: ScheduleId,
+        },
+        /// An account has unlocked an achievement
+        AchievementUnlocked {
+            /// The account earning the achievement
+            account: T::AccountId,
+            /// The unlocked achievement
+            achievement: Achievement,
+        },
+        /// An account has granted or updated a delegation
+        DelegateAdded {
+            /// The delegating account
+            owner: T::AccountId,
+            /// The delegate
+            delegate: T::AccountId,
+            /// Permissions granted to the delegate
+            scope: DelegateScope,
+            /// Last block at which the delegate may act, `None` if it does not expire
+            expires_at: Option<BlockNumberFor<T>>,
+        },
+        /// An account has revoked a delegation
+        DelegateRemoved {
+            /// The delegating account
+            owner: T::AccountId,
+            /// The former delegate
+            delegate: T::AccountId,
+        },
+    }
+
+    /// Errors that can be returned by the ChainGhost pallet
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The specified intent does not exist
+        IntentNotFound,
+        /// The caller is not the owner of the intent
+        NotIntentOwner,
+        /// The intent has reached the maximum number of journey steps
+        MaxJourneyStepsExceeded,
+        /// The intent status is invalid for this operation
+        InvalidIntentStatus,
+        /// A transfer, swap, bridge or batch action moves a zero amount
+        ZeroAmount,
+        /// A swap sells and buys the same asset
+        SameAssetSwap,
+        /// A bridge action has an empty recipient
+        EmptyBridgeRecipient,
+        /// A contract call targets the zero address
+        InvalidContractAddress,
+        /// A contract call carries neither value nor call data
+        EmptyContractCall,
+        /// A batch intent contains no actions
+        EmptyBatch,
+        /// The intent carries more calls than `MaxCallsPerIntent`
+        TooManyCalls,
+        /// The account is already registered as a solver
+        AlreadySolver,
+        /// The account is not registered as a solver
+        NotSolver,
+        /// The solver cannot deregister while it has claimed intents
+        SolverHasActiveClaims,
+        /// The intent has already been claimed by a solver
+        IntentAlreadyClaimed,
+        /// The intent has not been claimed
+        IntentNotClaimed,
+        /// The caller is not the solver that claimed the intent
+        NotClaimant,
+        /// The claim deadline has passed
+        ClaimExpired,
+        /// The claim deadline has not passed yet
+        ClaimNotExpired,
+        /// A reward has already been offered for the intent
+        RewardAlreadyOffered,
+        /// The fulfilment proof exceeds 256 bytes
+        ProofTooLong,
+        /// The solver's bond has been slashed below `SolverBond`
+        SolverBondTooLow,
+        /// The solver already has `MaxActiveClaims` claimed intents
+        TooManyActiveClaims,
+        /// The intent has no fulfilment awaiting its dispute period
+        FulfilmentNotPending,
+        /// The intent's fulfilment can no longer be disputed
+        DisputePeriodOver,
+        /// The intent's fulfilment can still be disputed
+        DisputePeriodNotOver,
+        /// The intent's fulfilment is awaiting its dispute period
+        FulfilmentPending,
+        /// The journey step attachment CID exceeds 128 bytes
+        AttachmentTooLong,
+        /// An account cannot delegate to itself
+        CannotDelegateToSelf,
+        /// The account has reached the maximum number of delegates
+        TooManyDelegates,
+        /// The delegation expiry is in the past
+        DelegationExpired,
+        /// The account has not delegated to the given account
+        DelegateNotFound,
+        /// A multi-step intent has more than `MaxBatchActions` sub-intents
+        TooManySubIntents,
+        /// A sub-intent depends on itself, a later sub-intent, or the same sub-intent twice
+        InvalidDependency,
+        /// The intent has no sub-intent at the given index
+        SubIntentNotFound,
+        /// A sub-intent cannot be executed before the sub-intents it depends on
+        DependenciesNotMet,
+        /// The status of a multi-step intent is derived from its sub-intents
+        StatusDerivedFromSubIntents,
+        /// The intent has reached the maximum number of external chain references
+        TooManyChainRefs,
+        /// The external transaction hash is empty or exceeds 64 bytes
+        InvalidTxHash,
+        /// The intent has no external chain reference at the given index
+        ChainRefNotFound,
+        /// The account is already a relayer
+        AlreadyRelayer,
+        /// The account is not a relayer
+        NotRelayer,
+        /// The intent metadata exceeds `MaxMetadataLen`
+        MetadataTooLong,
+        /// The journey step description exceeds `MaxDescriptionLen`
+        DescriptionTooLong,
+        /// The account has reached the maximum number of templates
+        TooManyTemplates,
+        /// The account has no template with the given ID
+        TemplateNotFound,
+        /// The account has reached the maximum number of recurring schedules
+        TooManyRecurringIntents,
+        /// The period or the number of occurrences of a recurring schedule is zero
+        InvalidRecurrence,
+        /// No active recurring schedule has the given ID
+        RecurringIntentNotFound,
+        /// The intent carries a call to this pallet, which could act on the intent itself
+        IntentCallNotAllowed,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            Self::process_agenda(now)
+        }
+
+        fn integrity_test() {
+            assert!(
+                T::MaxCallsPerIntent::get() <= T::MaxJourneyStepsPerIntent::get(),
+                "MaxCallsPerIntent must not exceed MaxJourneyStepsPerIntent"
+            );
+            assert!(
+                T::MaxInstantiationsPerBlock::get() > 0,
+                "MaxInstantiationsPerBlock must allow the agenda to make progress"
+            );
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Execute a new intent with the provided payload and metadata
+        ///
+        /// Creates a new intent record, assigns it a unique ID, and stores it in the blockchain.
+        /// Holds `IntentDeposit` from the caller for as long as the intent is stored.
+        ///
+        /// If `calls` is not empty, they are dispatched atomically with the caller's origin:
+        /// either all of them take effect or none do. The intent is then marked `Executed` or
+        /// `Failed` from the outcome, the failure is stored in `IntentErrors`, and a journey
+        /// step is recorded for every call. Calls to this pallet are rejected, as they could
+        /// act on the intent being dispatched.
+        ///
+        /// # Parameters
         ///
         /// - `origin`: The account executing the intent (must be signed)
-        /// - `metadata`: Intent metadata (max 256 bytes)
+        /// - `kind`: Typed intent payload, validated per kind
+        /// - `metadata`: Intent metadata (max `MaxMetadataLen` bytes)
+        /// - `calls`: Calls to dispatch on the caller's behalf (max `MaxCallsPerIntent`)
         ///
         /// # Errors
         ///
-        /// - `MaxIntentsPerAccountExceeded`: Account has too many intents
+        /// - `ZeroAmount`, `SameAssetSwap`, `EmptyBridgeRecipient`, `InvalidContractAddress`,
+        ///   `EmptyContractCall`, `EmptyBatch`: The payload is invalid
+        /// - `TooManyCalls`: More calls than `MaxCallsPerIntent`
+        /// - `IntentCallNotAllowed`: A call targets this pallet
+        /// - `MetadataTooLong`: Metadata exceeds `MaxMetadataLen`
+        /// - `FundsUnavailable`: Caller cannot cover `IntentDeposit`
+        ///
+        /// A failing call does not fail the extrinsic; it is recorded on the intent instead.
         ///
         /// # Events
         ///
         /// - `IntentExecuted`: Emitted when intent is successfully created
+        /// - `IntentCallsDispatched`: Emitted after the calls have been dispatched
+        /// - `IntentStatusUpdated`: Emitted when dispatch moves the intent out of `Pending`
         #[pallet::call_index(0)]
-        #[pallet::weight(T::WeightInfo::execute_intent())]
-        pub fn execute_intent(origin: OriginFor<T>, metadata: Vec<u8>) -> DispatchResult {
+        #[pallet::weight({
+            let dispatch_weight = calls
+                .iter()
+                .map(|call| call.get_dispatch_info().call_weight)
+                .fold(Weight::zero(), |total, weight| total.saturating_add(weight));
+            T::WeightInfo::execute_intent()
+                .saturating_add(dispatch_weight)
+                .saturating_add(T::WeightInfo::record_journey().saturating_mul(calls.len() as u64))
+        })]
+        pub fn execute_intent(
+            origin: OriginFor<T>,
+            kind: IntentKind<T::AccountId>,
+            metadata: Vec<u8>,
+            calls: Vec<<T as Config>::RuntimeCall>,
+        ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
 
-            // Get next intent ID and increment counter
-            let intent_id = NextIntentId::<T>::get();
-            NextIntentId::<T>::put(intent_id.saturating_add(1));
-
-            // Convert metadata to BoundedVec (automatically truncates if too long)
-            let bounded_metadata: BoundedVec<u8, ConstU32<256>> = metadata
-                .try_into()
-                .map_err(|_| Error::<T>::InvalidIntentStatus)?;
-
-            // Get current block number as timestamp
-            let timestamp = frame_system::Pallet::<T>::block_number();
-
-            // Create intent struct
-            let intent = Intent {
-                intent_id,
-                account: who.clone(),
-                status: IntentStatus::Pending,
-                timestamp,
-                metadata: bounded_metadata,
-            };
+            Self::validate_intent_kind(&kind)?;
+            ensure!(
+                calls.len() <= T::MaxCallsPerIntent::get() as usize,
+                Error::<T>::TooManyCalls
+            );
+            ensure!(
+                calls.iter().all(|call| call.is_sub_type().is_none()),
+                Error::<T>::IntentCallNotAllowed
+            );
 
-            // Store intent by ID
-            IntentById::<T>::insert(intent_id, intent);
+            let intent_id = Self::create_intent(&who, kind, metadata)?;
 
-            // Add intent ID to account's intent list (enforce max limit)
-            IntentsByAccount::<T>::try_mutate(&who, |intents| -> DispatchResult {
-                intents
-                    .try_push(intent_id)
-                    .map_err(|_| Error::<T>::MaxIntentsPerAccountExceeded)?;
-                Ok(())
-            })?;
+            if calls.is_empty() {
+                return Ok(Some(T::WeightInfo::execute_intent()).into());
+            }
 
-            // Emit event
-            Self::deposit_event(Event::IntentExecuted {
-                intent_id,
-                account: who,
-                timestamp,
-            });
+            let dispatch_weight = Self::dispatch_intent_calls(&who, intent_id, calls);
 
-            Ok(())
+            Ok(Some(T::WeightInfo::execute_intent().saturating_add(dispatch_weight)).into())
         }
 
         /// Record a journey step for an existing intent
         ///
-        /// Adds a new step to the intent's journey timeline. Only the intent owner can record steps.
+        /// Adds a new step to the intent's journey timeline. Steps can be recorded by the intent
+        /// owner, its delegates with the `RecordJourney` scope, or the solver currently holding
+        /// a claim on it.
         /// Journey steps are used for narrative visualization and AI story generation.
         ///
         /// # Parameters
         ///
-        /// - `origin`: The account recording the journey step (must be intent owner)
+        /// - `origin`: The account recording the journey step (owner, delegate or claiming solver)
         /// - `intent_id`: The intent to add the step to
-        /// - `description`: Step description (max 512 bytes)
+        /// - `step_kind`: What the step describes
+        /// - `description`: Step description (max `MaxDescriptionLen` bytes)
+        /// - `linked_tx`: Substrate extrinsic or Ethereum transaction the step refers to
+        /// - `attachment`: CID of an off-chain attachment (max 128 bytes)
         ///
         /// # Errors
         ///
         /// - `IntentNotFound`: Intent does not exist
-        /// - `NotIntentOwner`: Caller is not the intent owner
+        /// - `NotIntentOwner`: Caller is not the intent owner, a delegate or its claiming solver
+        /// - `DescriptionTooLong`: Description exceeds `MaxDescriptionLen`
+        /// - `AttachmentTooLong`: Attachment CID exceeds 128 bytes
         /// - `MaxJourneyStepsExceeded`: Intent has too many journey steps
         ///
         /// # Events
@@ -307,43 +1451,49 @@ pub mod pallet {
         pub fn record_journey(
             origin: OriginFor<T>,
             intent_id: IntentId,
+            step_kind: JourneyStepKind,
             description: Vec<u8>,
+            linked_tx: Option<LinkedTx>,
+            attachment: Option<Vec<u8>>,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
 
             // Verify intent exists
             let intent = IntentById::<T>::get(intent_id).ok_or(Error::<T>::IntentNotFound)?;
 
-            // Verify caller is intent owner
-            ensure!(intent.account == who, Error::<T>::NotIntentOwner);
+            // Verify caller may append to the journey
+            ensure!(
+                Self::can_record_journey(&who, intent_id, &intent),
+                Error::<T>::NotIntentOwner
+            );
 
             // Convert description to BoundedVec
-            let bounded_description: BoundedVec<u8, ConstU32<512>> = description
+            let bounded_description: BoundedVec<u8, T::MaxDescriptionLen> = description
                 .try_into()
-                .map_err(|_| Error::<T>::InvalidIntentStatus)?;
+                .map_err(|_| Error::<T>::DescriptionTooLong)?;
+
+            let attachment = attachment
+                .map(|cid| cid.try_into().map_err(|_| Error::<T>::AttachmentTooLong))
+                .transpose()?;
 
             // Get current block number as timestamp
             let timestamp = frame_system::Pallet::<T>::block_number();
 
             // Add journey step (enforce max limit)
-            JourneyByIntent::<T>::try_mutate(intent_id, |steps| -> DispatchResult {
-                let step_id = steps.len() as u32;
-
-                let journey_step = JourneyStep {
-                    step_id,
+            let step_count = Self::append_journey_step(
+                intent_id,
+                JourneyStep {
+                    step_id: 0,
+                    step_kind,
                     description: bounded_description,
                     timestamp,
-                };
-
-                steps
-                    .try_push(journey_step)
-                    .map_err(|_| Error::<T>::MaxJourneyStepsExceeded)?;
+                    author: who,
+                    linked_tx,
+                    attachment,
+                },
+            )?;
 
-                Ok(())
-            })?;
-
-            // Get updated step count
-            let step_count = JourneyByIntent::<T>::get(intent_id).len() as u32;
+            Self::update_persona(&intent.account, |persona| persona.journey_steps.saturating_inc());
 
             // Emit event
             Self::deposit_event(Event::JourneyRecorded {
@@ -357,11 +1507,15 @@ pub mod pallet {
         /// Update the status of an existing intent
         ///
         /// Transitions an intent from one status to another (e.g., Pending → Executed).
-        /// Only the intent owner can update the status.
+        /// Only the intent owner or its delegates with the `UpdateStatus` scope can update the
+        /// status, and not while a solver has claimed it.
+        /// Leaving `Pending` returns any escrowed reward to the owner.
+        /// Executions reported this way are not attested, so they do not count towards the
+        /// owner's persona.
         ///
         /// # Parameters
         ///
-        /// - `origin`: The account updating the status (must be intent owner)
+        /// - `origin`: The account updating the status (intent owner or delegate)
         /// - `intent_id`: The intent to update
         /// - `new_status`: The new status to set
         ///
@@ -369,6 +1523,10 @@ pub mod pallet {
         ///
         /// - `IntentNotFound`: Intent does not exist
         /// - `NotIntentOwner`: Caller is not the intent owner
+        /// - `InvalidIntentStatus`: The status is `Cancelled`, or the intent is cancelled
+        /// - `IntentAlreadyClaimed`: A solver has claimed the intent
+        /// - `FulfilmentPending`: A solver's fulfilment is awaiting its dispute period
+        /// - `StatusDerivedFromSubIntents`: The intent is multi-step
         ///
         /// # Events
         ///
@@ -386,18 +1544,58 @@ pub mod pallet {
             let new_status =
                 IntentStatus::from_u8(new_status).ok_or(Error::<T>::InvalidIntentStatus)?;
 
+            // Intents are cancelled with a reason through `cancel_intent`
+            ensure!(
+                new_status != IntentStatus::Cancelled,
+                Error::<T>::InvalidIntentStatus
+            );
+
             // Verify intent exists and update status
             IntentById::<T>::try_mutate(intent_id, |maybe_intent| -> DispatchResult {
                 let intent = maybe_intent.as_mut().ok_or(Error::<T>::IntentNotFound)?;
 
-                // Verify caller is intent owner
-                ensure!(intent.account == who, Error::<T>::NotIntentOwner);
+                // Cancellation is final
+                ensure!(
+                    intent.status != IntentStatus::Cancelled,
+                    Error::<T>::InvalidIntentStatus
+                );
+
+                // Verify caller is intent owner or a delegate allowed to update statuses
+                ensure!(
+                    intent.account == who
+                        || Self::is_delegate(&intent.account, &who, &DelegateScope::UpdateStatus),
+                    Error::<T>::NotIntentOwner
+                );
+
+                // A claimed intent is resolved by its solver
+                ensure!(
+                    !IntentClaims::<T>::contains_key(intent_id),
+                    Error::<T>::IntentAlreadyClaimed
+                );
+
+                // A fulfilment is rejected through `dispute_fulfilment`
+                ensure!(
+                    !PendingFulfilments::<T>::contains_key(intent_id),
+                    Error::<T>::FulfilmentPending
+                );
+
+                // A multi-step intent is resolved through its sub-intents
+                ensure!(
+                    !SubIntents::<T>::contains_key(intent_id),
+                    Error::<T>::StatusDerivedFromSubIntents
+                );
+
+                // Resolving the intent returns any escrowed reward to the owner
+                if new_status != IntentStatus::Pending {
+                    Self::release_reward(intent_id, &intent.account)?;
+                }
 
                 // Store old status for event
                 let old_status = intent.status.clone();
 
                 // Update status
                 intent.status = new_status.clone();
+                Self::note_status_change(intent, &old_status, false);
 
                 // Emit event (convert statuses to u8)
                 Self::deposit_event(Event::IntentStatusUpdated {
@@ -409,5 +1607,1904 @@ pub mod pallet {
                 Ok(())
             })
         }
+
+        /// Register the caller as a solver
+        ///
+        /// Holds `SolverBond` from the caller for as long as it stays registered.
+        ///
+        /// # Parameters
+        ///
+        /// - `origin`: The account registering (must be signed)
+        ///
+        /// # Errors
+        ///
+        /// - `AlreadySolver`: Caller is already registered
+        ///
+        /// # Events
+        ///
+        /// - `SolverRegistered`: Emitted when the solver is registered
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::register_solver())]
+        pub fn register_solver(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(!Solvers::<T>::contains_key(&who), Error::<T>::AlreadySolver);
+
+            let bond = T::SolverBond::get();
+            T::Currency::hold(&HoldReason::SolverBond.into(), &who, bond)?;
+
+            Solvers::<T>::insert(
+                &who,
+                SolverInfo {
+                    bond,
+                    active_claims: 0,
+                    fulfilled: 0,
+                    slashed: 0,
+                },
+            );
+
+            Self::deposit_event(Event::SolverRegistered { solver: who, bond });
+
+            Ok(())
+        }
+
+        /// Deregister the caller as a solver, releasing its remaining bond
+        ///
+        /// # Parameters
+        ///
+        /// - `origin`: The solver deregistering (must be signed)
+        ///
+        /// # Errors
+        ///
+        /// - `NotSolver`: Caller is not registered
+        /// - `SolverHasActiveClaims`: Caller still has claimed intents
+        ///
+        /// # Events
+        ///
+        /// - `SolverDeregistered`: Emitted when the solver is deregistered
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::deregister_solver())]
+        pub fn deregister_solver(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let info = Solvers::<T>::get(&who).ok_or(Error::<T>::NotSolver)?;
+            ensure!(info.active_claims == 0, Error::<T>::SolverHasActiveClaims);
+
+            let bond = T::Currency::release(
+                &HoldReason::SolverBond.into(),
+                &who,
+                info.bond,
+                Precision::BestEffort,
+            )?;
+            Solvers::<T>::remove(&who);
+
+            Self::deposit_event(Event::SolverDeregistered { solver: who, bond });
+
+            Ok(())
+        }
+
+        /// Escrow a reward for the solver that fulfils an intent
+        ///
+        /// The reward is held from the caller and paid out by `fulfil_intent`. It is returned
+        /// if the owner resolves the intent itself.
+        ///
+        /// # Parameters
+        ///
+        /// - `origin`: The intent owner (must be signed)
+        /// - `intent_id`: The intent to reward
+        /// - `reward`: Amount to escrow
+        ///
+        /// # Errors
+        ///
+        /// - `IntentNotFound`: Intent does not exist
+        /// - `NotIntentOwner`: Caller is not the intent owner
+        /// - `InvalidIntentStatus`: Intent is not pending
+        /// - `IntentAlreadyClaimed`: Intent has already been claimed
+        /// - `ZeroAmount`: Reward is zero
+        /// - `RewardAlreadyOffered`: A reward is already escrowed
+        ///
+        /// # Events
+        ///
+        /// - `RewardOffered`: Emitted when the reward is escrowed
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::offer_reward())]
+        pub fn offer_reward(
+            origin: OriginFor<T>,
+            intent_id: IntentId,
+            reward: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let intent = IntentById::<T>::get(intent_id).ok_or(Error::<T>::IntentNotFound)?;
+            ensure!(intent.account == who, Error::<T>::NotIntentOwner);
+            ensure!(
+                intent.status == IntentStatus::Pending,
+                Error::<T>::InvalidIntentStatus
+            );
+            ensure!(
+                !IntentClaims::<T>::contains_key(intent_id),
+                Error::<T>::IntentAlreadyClaimed
+            );
+            ensure!(!reward.is_zero(), Error::<T>::ZeroAmount);
+            ensure!(
+                IntentRewards::<T>::get(intent_id).is_zero(),
+                Error::<T>::RewardAlreadyOffered
+            );
+
+            T::Currency::hold(&HoldReason::IntentReward.into(), &who, reward)?;
+            IntentRewards::<T>::insert(intent_id, reward);
+
+            Self::deposit_event(Event::RewardOffered { intent_id, reward });
+
+            Ok(())
+        }
+
+        /// Claim a pending intent for fulfilment
+        ///
+        /// The solver must fulfil the intent within `ClaimTimeout` blocks, or part of its bond
+        /// can be slashed to the intent owner. A solver holds at most `MaxActiveClaims` claims,
+        /// and only while its bond has not been slashed below `SolverBond`.
+        ///
+        /// # Parameters
+        ///
+        /// - `origin`: The claiming solver (must be signed)
+        /// - `intent_id`: The intent to claim
+        ///
+        /// # Errors
+        ///
+        /// - `NotSolver`: Caller is not registered
+        /// - `SolverBondTooLow`: Caller's bond was slashed below `SolverBond`
+        /// - `TooManyActiveClaims`: Caller already holds `MaxActiveClaims` claims
+        /// - `IntentNotFound`: Intent does not exist
+        /// - `InvalidIntentStatus`: Intent is not pending
+        /// - `StatusDerivedFromSubIntents`: The intent is multi-step and progresses through its
+        ///   sub-intents
+        /// - `IntentAlreadyClaimed`: Intent has already been claimed
+        ///
+        /// # Events
+        ///
+        /// - `IntentClaimed`: Emitted when the intent is claimed
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::claim_intent())]
+        pub fn claim_intent(origin: OriginFor<T>, intent_id: IntentId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let intent = IntentById::<T>::get(intent_id).ok_or(Error::<T>::IntentNotFound)?;
+            ensure!(
+                intent.status == IntentStatus::Pending,
+                Error::<T>::InvalidIntentStatus
+            );
+            // Fulfilling would mark the intent executed without executing its sub-intents
+            ensure!(
+                !SubIntents::<T>::contains_key(intent_id),
+                Error::<T>::StatusDerivedFromSubIntents
+            );
+            ensure!(
+                !IntentClaims::<T>::contains_key(intent_id),
+                Error::<T>::IntentAlreadyClaimed
+            );
+
+            Solvers::<T>::try_mutate(&who, |maybe_info| -> DispatchResult {
+                let info = maybe_info.as_mut().ok_or(Error::<T>::NotSolver)?;
+                ensure!(info.bond >= T::SolverBond::get(), Error::<T>::SolverBondTooLow);
+                ensure!(
+                    info.active_claims < T::MaxActiveClaims::get(),
+                    Error::<T>::TooManyActiveClaims
+                );
+                info.active_claims.saturating_inc();
+                Ok(())
+            })?;
+
+            let deadline =
+                frame_system::Pallet::<T>::block_number().saturating_add(T::ClaimTimeout::get());
+            IntentClaims::<T>::insert(
+                intent_id,
+                IntentClaim {
+                    solver: who.clone(),
+                    deadline,
+                },
+            );
+
+            Self::deposit_event(Event::IntentClaimed {
+                intent_id,
+                solver: who,
+                deadline,
+            });
+
+            Ok(())
+        }
+
+        /// Fulfil a claimed intent
+        ///
+        /// Marks the intent `Executed` and stores `proof`. The proof is not verified on chain:
+        /// it is trusted, and the owner has `DisputePeriod` blocks to reject it through
+        /// `dispute_fulfilment`. The escrowed reward, if any, stays held until then and is paid
+        /// to the solver by `collect_reward`.
+        ///
+        /// # Parameters
+        ///
+        /// - `origin`: The solver that claimed the intent (must be signed)
+        /// - `intent_id`: The intent being fulfilled
+        /// - `proof`: Evidence of fulfilment, e.g. a transaction hash (max 256 bytes)
+        ///
+        /// # Errors
+        ///
+        /// - `IntentNotClaimed`: Intent has no active claim
+        /// - `NotClaimant`: Caller is not the claiming solver
+        /// - `ClaimExpired`: The claim deadline has passed
+        /// - `ProofTooLong`: Proof exceeds 256 bytes
+        ///
+        /// # Events
+        ///
+        /// - `IntentStatusUpdated`: Emitted when the intent is marked `Executed`
+        /// - `IntentFulfilled`: Emitted when the dispute period opens
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::fulfil_intent())]
+        pub fn fulfil_intent(
+            origin: OriginFor<T>,
+            intent_id: IntentId,
+            proof: Vec<u8>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let claim = IntentClaims::<T>::get(intent_id).ok_or(Error::<T>::IntentNotClaimed)?;
+            ensure!(claim.solver == who, Error::<T>::NotClaimant);
+            ensure!(
+                frame_system::Pallet::<T>::block_number() <= claim.deadline,
+                Error::<T>::ClaimExpired
+            );
+
+            let proof: BoundedVec<u8, ConstU32<256>> =
+                proof.try_into().map_err(|_| Error::<T>::ProofTooLong)?;
+
+            IntentById::<T>::try_mutate(intent_id, |maybe_intent| -> DispatchResult {
+                let intent = maybe_intent.as_mut().ok_or(Error::<T>::IntentNotFound)?;
+                intent.status = IntentStatus::Executed;
+                Self::note_status_change(intent, &IntentStatus::Pending, true);
+                Ok(())
+            })?;
+
+            let reward = IntentRewards::<T>::take(intent_id);
+            let dispute_until =
+                frame_system::Pallet::<T>::block_number().saturating_add(T::DisputePeriod::get());
+            PendingFulfilments::<T>::insert(
+                intent_id,
+                PendingFulfilment {
+                    solver: who.clone(),
+                    reward,
+                    dispute_until,
+                },
+            );
+
+            IntentClaims::<T>::remove(intent_id);
+            FulfilmentProofs::<T>::insert(intent_id, proof);
+            Solvers::<T>::mutate(&who, |maybe_info| {
+                if let Some(info) = maybe_info {
+                    info.active_claims.saturating_dec();
+                    info.fulfilled.saturating_inc();
+                }
+            });
+
+            Self::deposit_event(Event::IntentStatusUpdated {
+                intent_id,
+                old_status: IntentStatus::Pending.as_u8(),
+                new_status: IntentStatus::Executed.as_u8(),
+            });
+            Self::deposit_event(Event::IntentFulfilled {
+                intent_id,
+                solver: who,
+                reward,
+                dispute_until,
+            });
+
+            Ok(())
+        }
+
+        /// Slash a solver whose claim on an intent has timed out
+        ///
+        /// Moves `ClaimSlash` of `SolverBond` from the solver's bond to the intent owner and
+        /// frees the intent to be claimed again. The escrowed reward stays in place. Callable
+        /// by anyone.
+        ///
+        /// # Parameters
+        ///
+        /// - `origin`: Any signed account
+        /// - `intent_id`: The intent with the expired claim
+        ///
+        /// # Errors
+        ///
+        /// - `IntentNotClaimed`: Intent has no active claim
+        /// - `ClaimNotExpired`: The claim deadline has not passed yet
+        ///
+        /// # Events
+        ///
+        /// - `ClaimSlashed`: Emitted when the solver has been slashed
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::slash_expired_claim())]
+        pub fn slash_expired_claim(origin: OriginFor<T>, intent_id: IntentId) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let claim = IntentClaims::<T>::get(intent_id).ok_or(Error::<T>::IntentNotClaimed)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() > claim.deadline,
+                Error::<T>::ClaimNotExpired
+            );
+
+            let owner = IntentById::<T>::get(intent_id)
+                .ok_or(Error::<T>::IntentNotFound)?
+                .account;
+
+            let amount = Self::slash_solver(&claim.solver, &owner, |info| {
+                info.active_claims.saturating_dec();
+            });
+
+            IntentClaims::<T>::remove(intent_id);
+
+            Self::deposit_event(Event::ClaimSlashed {
+                intent_id,
+                solver: claim.solver,
+                amount,
+            });
+
+            Ok(())
+        }
+
+        /// Dispute the fulfilment of an intent
+        ///
+        /// Rejects the solver's proof before the reward is paid: the intent returns to
+        /// `Pending` with its reward escrowed again, the proof is dropped, and `ClaimSlash` of
+        /// `SolverBond` is moved from the solver's bond to the owner.
+        ///
+        /// # Parameters
+        ///
+        /// - `origin`: The intent owner (must be signed)
+        /// - `intent_id`: The fulfilled intent
+        ///
+        /// # Errors
+        ///
+        /// - `FulfilmentNotPending`: Intent has no fulfilment awaiting its dispute period
+        /// - `IntentNotFound`: Intent does not exist
+        /// - `NotIntentOwner`: Caller is not the intent owner
+        /// - `DisputePeriodOver`: The dispute period has passed
+        ///
+        /// # Events
+        ///
+        /// - `IntentStatusUpdated`: Emitted when the intent returns to `Pending`
+        /// - `FulfilmentDisputed`: Emitted when the solver has been slashed
+        #[pallet::call_index(23)]
+        #[pallet::weight(T::WeightInfo::dispute_fulfilment())]
+        pub fn dispute_fulfilment(origin: OriginFor<T>, intent_id: IntentId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let fulfilment =
+                PendingFulfilments::<T>::get(intent_id).ok_or(Error::<T>::FulfilmentNotPending)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() <= fulfilment.dispute_until,
+                Error::<T>::DisputePeriodOver
+            );
+
+            IntentById::<T>::try_mutate(intent_id, |maybe_intent| -> DispatchResult {
+                let intent = maybe_intent.as_mut().ok_or(Error::<T>::IntentNotFound)?;
+                ensure!(intent.account == who, Error::<T>::NotIntentOwner);
+                intent.status = IntentStatus::Pending;
+                Self::note_status_change(intent, &IntentStatus::Executed, false);
+                Ok(())
+            })?;
+
+            PendingFulfilments::<T>::remove(intent_id);
+            FulfilmentProofs::<T>::remove(intent_id);
+            if !fulfilment.reward.is_zero() {
+                IntentRewards::<T>::insert(intent_id, fulfilment.reward);
+            }
+
+            let amount = Self::slash_solver(&fulfilment.solver, &who, |info| {
+                info.fulfilled.saturating_dec();
+            });
+
+            Self::deposit_event(Event::IntentStatusUpdated {
+                intent_id,
+                old_status: IntentStatus::Executed.as_u8(),
+                new_status: IntentStatus::Pending.as_u8(),
+            });
+            Self::deposit_event(Event::FulfilmentDisputed {
+                intent_id,
+                solver: fulfilment.solver,
+                amount,
+            });
+
+            Ok(())
+        }
+
+        /// Pay the reward of a fulfilment whose dispute period has passed
+        ///
+        /// Callable by anyone. The reward is moved from the owner's hold to the solver.
+        ///
+        /// # Parameters
+        ///
+        /// - `origin`: Any signed account
+        /// - `intent_id`: The fulfilled intent
+        ///
+        /// # Errors
+        ///
+        /// - `FulfilmentNotPending`: Intent has no fulfilment awaiting its dispute period
+        /// - `DisputePeriodNotOver`: The owner may still dispute the fulfilment
+        /// - `IntentNotFound`: Intent does not exist
+        ///
+        /// # Events
+        ///
+        /// - `RewardPaid`: Emitted when the reward is paid
+        #[pallet::call_index(24)]
+        #[pallet::weight(T::WeightInfo::collect_reward())]
+        pub fn collect_reward(origin: OriginFor<T>, intent_id: IntentId) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let fulfilment =
+                PendingFulfilments::<T>::get(intent_id).ok_or(Error::<T>::FulfilmentNotPending)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() > fulfilment.dispute_until,
+                Error::<T>::DisputePeriodNotOver
+            );
+
+            let owner = IntentById::<T>::get(intent_id)
+                .ok_or(Error::<T>::IntentNotFound)?
+                .account;
+
+            if !fulfilment.reward.is_zero() {
+                T::Currency::transfer_on_hold(
+                    &HoldReason::IntentReward.into(),
+                    &owner,
+                    &fulfilment.solver,
+                    fulfilment.reward,
+                    Precision::Exact,
+                    Restriction::Free,
+                    Fortitude::Polite,
+                )?;
+            }
+
+            PendingFulfilments::<T>::remove(intent_id);
+
+            Self::deposit_event(Event::RewardPaid {
+                intent_id,
+                solver: fulfilment.solver,
+                reward: fulfilment.reward,
+            });
+
+            Ok(())
+        }
+
+        /// Grant `delegate` permissions over the caller's intents
+        ///
+        /// Replaces any existing delegation to the same account.
+        ///
+        /// # Parameters
+        ///
+        /// - `origin`: The delegating account (must be signed)
+        /// - `delegate`: The account receiving the permissions
+        /// - `scope`: Permissions granted
+        /// - `expires_at`: Last block at which the delegate may act, `None` for no expiry
+        ///
+        /// # Errors
+        ///
+        /// - `CannotDelegateToSelf`: `delegate` is the caller
+        /// - `DelegationExpired`: `expires_at` is in the past
+        /// - `TooManyDelegates`: Caller already has `MaxDelegates` delegates
+        ///
+        /// # Events
+        ///
+        /// - `DelegateAdded`: Emitted when the delegation is stored
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::add_delegate())]
+        pub fn add_delegate(
+            origin: OriginFor<T>,
+            delegate: T::AccountId,
+            scope: DelegateScope,
+            expires_at: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(who != delegate, Error::<T>::CannotDelegateToSelf);
+            if let Some(expires_at) = expires_at {
+                ensure!(
+                    expires_at >= frame_system::Pallet::<T>::block_number(),
+                    Error::<T>::DelegationExpired
+                );
+            }
+
+            if !Delegates::<T>::contains_key(&who, &delegate) {
+                DelegateCount::<T>::try_mutate(&who, |count| -> DispatchResult {
+                    ensure!(*count < T::MaxDelegates::get(), Error::<T>::TooManyDelegates);
+                    count.saturating_inc();
+                    Ok(())
+                })?;
+            }
+
+            Delegates::<T>::insert(
+                &who,
+                &delegate,
+                Delegation {
+                    scope: scope.clone(),
+                    expires_at,
+                },
+            );
+
+            Self::deposit_event(Event::DelegateAdded {
+                owner: who,
+                delegate,
+                scope,
+                expires_at,
+            });
+
+            Ok(())
+        }
+
+        /// Revoke the delegation granted to `delegate`
+        ///
+        /// Expired delegations can be removed as well, freeing their slot.
+        ///
+        /// # Parameters
+        ///
+        /// - `origin`: The delegating account (must be signed)
+        /// - `delegate`: The account losing its permissions
+        ///
+        /// # Errors
+        ///
+        /// - `DelegateNotFound`: Caller has not delegated to `delegate`
+        ///
+        /// # Events
+        ///
+        /// - `DelegateRemoved`: Emitted when the delegation is removed
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::remove_delegate())]
+        pub fn remove_delegate(origin: OriginFor<T>, delegate: T::AccountId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(
+                Delegates::<T>::contains_key(&who, &delegate),
+                Error::<T>::DelegateNotFound
+            );
+
+            Delegates::<T>::remove(&who, &delegate);
+            DelegateCount::<T>::mutate(&who, |count| count.saturating_dec());
+
+            Self::deposit_event(Event::DelegateRemoved {
+                owner: who,
+                delegate,
+            });
+
+            Ok(())
+        }
+
+        /// Authorize `relayer` to attest external execution of intents
+        ///
+        /// # Parameters
+        ///
+        /// - `origin`: Must satisfy `RelayerAdminOrigin`
+        /// - `relayer`: The account to authorize
+        ///
+        /// # Errors
+        ///
+        /// - `AlreadyRelayer`: The account is already a relayer
+        ///
+        /// # Events
+        ///
+        /// - `RelayerAdded`: Emitted when the relayer is authorized
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::add_relayer())]
+        pub fn add_relayer(origin: OriginFor<T>, relayer: T::AccountId) -> DispatchResult {
+            T::RelayerAdminOrigin::ensure_origin(origin)?;
+
+            ensure!(!Relayers::<T>::contains_key(&relayer), Error::<T>::AlreadyRelayer);
+            Relayers::<T>::insert(&relayer, ());
+
+            Self::deposit_event(Event::RelayerAdded { relayer });
+
+            Ok(())
+        }
+
+        /// Revoke the authorization of `relayer`
+        ///
+        /// # Parameters
+        ///
+        /// - `origin`: Must satisfy `RelayerAdminOrigin`
+        /// - `relayer`: The account to remove
+        ///
+        /// # Errors
+        ///
+        /// - `NotRelayer`: The account is not a relayer
+        ///
+        /// # Events
+        ///
+        /// - `RelayerRemoved`: Emitted when the relayer is removed
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::remove_relayer())]
+        pub fn remove_relayer(origin: OriginFor<T>, relayer: T::AccountId) -> DispatchResult {
+            T::RelayerAdminOrigin::ensure_origin(origin)?;
+
+            ensure!(Relayers::<T>::contains_key(&relayer), Error::<T>::NotRelayer);
+            Relayers::<T>::remove(&relayer);
+
+            Self::deposit_event(Event::RelayerRemoved { relayer });
+
+            Ok(())
+        }
+
+        /// Attach a transaction executed on another chain to an intent
+        ///
+        /// Callable by anyone allowed to record the intent's journey: its owner, a delegate
+        /// with the `RecordJourney` scope, or its claiming solver.
+        ///
+        /// # Parameters
+        ///
+        /// - `origin`: The account attaching the transaction
+        /// - `intent_id`: The intent carried out by the transaction
+        /// - `chain_id`: Chain the transaction was executed on
+        /// - `tx_hash`: Transaction hash in the chain's native encoding (max 64 bytes)
+        /// - `block_number`: Block of the external chain containing the transaction
+        ///
+        /// # Errors
+        ///
+        /// - `IntentNotFound`: Intent does not exist
+        /// - `NotIntentOwner`: Caller may not record the intent's journey
+        /// - `InvalidTxHash`: Hash is empty or exceeds 64 bytes
+        /// - `TooManyChainRefs`: Intent has `MaxChainRefsPerIntent` references
+        ///
+        /// # Events
+        ///
+        /// - `ExternalTxAttached`: Emitted when the reference is stored
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::attach_external_tx())]
+        pub fn attach_external_tx(
+            origin: OriginFor<T>,
+            intent_id: IntentId,
+            chain_id: ChainId,
+            tx_hash: Vec<u8>,
+            block_number: u64,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let intent = IntentById::<T>::get(intent_id).ok_or(Error::<T>::IntentNotFound)?;
+            ensure!(
+                Self::can_record_journey(&who, intent_id, &intent),
+                Error::<T>::NotIntentOwner
+            );
+
+            ensure!(!tx_hash.is_empty(), Error::<T>::InvalidTxHash);
+            let tx_hash: BoundedVec<u8, ConstU32<64>> =
+                tx_hash.try_into().map_err(|_| Error::<T>::InvalidTxHash)?;
+
+            let index = ChainRefs::<T>::try_mutate(intent_id, |refs| {
+                refs.try_push(ChainRef {
+                    chain_id,
+                    tx_hash,
+                    block_number,
+                    attested: false,
+                })
+                .map_err(|_| Error::<T>::TooManyChainRefs)?;
+                Ok::<_, DispatchError>(refs.len() as u32 - 1)
+            })?;
+
+            Self::deposit_event(Event::ExternalTxAttached {
+                intent_id,
+                index,
+                chain_id,
+            });
+
+            Ok(())
+        }
+
+        /// Attest that an attached external transaction executed the intent
+        ///
+        /// Marks the reference as attested and the intent as `Executed`, returning any
+        /// escrowed reward to the owner. Intents claimed by a solver are resolved through
+        /// `fulfil_intent` instead, and multi-step intents through their sub-intents.
+        ///
+        /// # Parameters
+        ///
+        /// - `origin`: An authorized relayer
+        /// - `intent_id`: The intent being confirmed
+        /// - `index`: Index of the attested reference in the intent's list
+        ///
+        /// # Errors
+        ///
+        /// - `NotRelayer`: Caller is not a relayer
+        /// - `IntentNotFound`: Intent does not exist
+        /// - `InvalidIntentStatus`: Intent is not pending
+        /// - `IntentAlreadyClaimed`: A solver has claimed the intent
+        /// - `StatusDerivedFromSubIntents`: The intent is multi-step
+        /// - `ChainRefNotFound`: The intent has no reference at `index`
+        ///
+        /// # Events
+        ///
+        /// - `ExternalExecutionAttested`: Emitted when the reference is attested
+        /// - `IntentStatusUpdated`: Emitted when the intent is marked `Executed`
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::attest_external_execution())]
+        pub fn attest_external_execution(
+            origin: OriginFor<T>,
+            intent_id: IntentId,
+            index: u32,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(Relayers::<T>::contains_key(&who), Error::<T>::NotRelayer);
+
+            let owner = IntentById::<T>::try_mutate(intent_id, |maybe_intent| {
+                let intent = maybe_intent.as_mut().ok_or(Error::<T>::IntentNotFound)?;
+                ensure!(
+                    intent.status == IntentStatus::Pending,
+                    Error::<T>::InvalidIntentStatus
+                );
+                ensure!(
+                    !IntentClaims::<T>::contains_key(intent_id),
+                    Error::<T>::IntentAlreadyClaimed
+                );
+                ensure!(
+                    !SubIntents::<T>::contains_key(intent_id),
+                    Error::<T>::StatusDerivedFromSubIntents
+                );
+                intent.status = IntentStatus::Executed;
+                Self::note_status_change(intent, &IntentStatus::Pending, true);
+                Ok::<_, DispatchError>(intent.account.clone())
+            })?;
+
+            ChainRefs::<T>::try_mutate(intent_id, |refs| {
+                let chain_ref = refs
+                    .get_mut(index as usize)
+                    .ok_or(Error::<T>::ChainRefNotFound)?;
+                chain_ref.attested = true;
+                Ok::<_, DispatchError>(())
+            })?;
+
+            Self::release_reward(intent_id, &owner)?;
+
+            Self::deposit_event(Event::ExternalExecutionAttested {
+                intent_id,
+                index,
+                relayer: who,
+            });
+            Self::deposit_event(Event::IntentStatusUpdated {
+                intent_id,
+                old_status: IntentStatus::Pending.as_u8(),
+                new_status: IntentStatus::Executed.as_u8(),
+            });
+
+            Ok(())
+        }
+
+        /// Create a multi-step intent made of ordered sub-intents
+        ///
+        /// Each sub-intent is an action and the indices of the earlier sub-intents it depends
+        /// on, so the dependencies always form a DAG. The parent intent stores the actions as
+        /// its `Batch` payload and its status is rolled up from the sub-intents.
+        ///
+        /// # Parameters
+        ///
+        /// - `origin`: The account creating the intent (must be signed)
+        /// - `metadata`: Intent metadata (max `MaxMetadataLen` bytes)
+        /// - `sub_intents`: Actions with their dependencies (max `MaxBatchActions`)
+        ///
+        /// # Errors
+        ///
+        /// - `EmptyBatch`: No sub-intents
+        /// - `TooManySubIntents`: More than `MaxBatchActions` sub-intents
+        /// - `InvalidDependency`: A dependency does not point to an earlier sub-intent, or is
+        ///   repeated
+        /// - `ZeroAmount`, `SameAssetSwap`, `EmptyBridgeRecipient`, `InvalidContractAddress`,
+        ///   `EmptyContractCall`: An action is invalid
+        /// - `MetadataTooLong`: Metadata exceeds `MaxMetadataLen`
+        /// - `FundsUnavailable`: Caller cannot cover `IntentDeposit`
+        ///
+        /// # Events
+        ///
+        /// - `IntentExecuted`: Emitted when the parent intent is created
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::create_multi_step_intent(sub_intents.len() as u32))]
+        pub fn create_multi_step_intent(
+            origin: OriginFor<T>,
+            metadata: Vec<u8>,
+            sub_intents: Vec<(IntentAction<T::AccountId>, Vec<u32>)>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(!sub_intents.is_empty(), Error::<T>::EmptyBatch);
+            ensure!(
+                sub_intents.len() <= MaxBatchActions::get() as usize,
+                Error::<T>::TooManySubIntents
+            );
+
+            let mut actions = Vec::with_capacity(sub_intents.len());
+            let mut nodes = Vec::with_capacity(sub_intents.len());
+            for (index, (action, mut depends_on)) in sub_intents.into_iter().enumerate() {
+                // Pointing only backwards keeps the graph acyclic
+                depends_on.sort_unstable();
+                ensure!(
+                    depends_on.iter().all(|dep| (*dep as usize) < index)
+                        && depends_on.windows(2).all(|pair| pair[0] != pair[1]),
+                    Error::<T>::InvalidDependency
+                );
+
+                actions.push(action);
+                nodes.push(SubIntent {
+                    // Distinct and lower than `index`, so within bounds
+                    depends_on: BoundedVec::truncate_from(depends_on),
+                    status: IntentStatus::Pending,
+                });
+            }
+
+            let kind = IntentKind::Batch(BoundedVec::truncate_from(actions));
+            Self::validate_intent_kind(&kind)?;
+
+            let intent_id = Self::create_intent(&who, kind, metadata)?;
+            SubIntents::<T>::insert(intent_id, BoundedVec::truncate_from(nodes));
+
+            Ok(())
+        }
+
+        /// Update the status of a sub-intent of a multi-step intent
+        ///
+        /// A sub-intent can only be marked `Executed` once the sub-intents it depends on are.
+        /// The parent intent becomes `Failed` as soon as a sub-intent fails and `Executed` once
+        /// all of them are, returning any escrowed reward to the owner. Like owner status
+        /// updates, the rolled up execution does not count towards the owner's persona.
+        ///
+        /// # Parameters
+        ///
+        /// - `origin`: The intent owner or a delegate with the `UpdateStatus` scope
+        /// - `intent_id`: The parent intent
+        /// - `index`: Index of the sub-intent
+        /// - `new_status`: 1 (Executed) or 2 (Failed)
+        ///
+        /// # Errors
+        ///
+        /// - `IntentNotFound`: Intent does not exist
+        /// - `NotIntentOwner`: Caller is not the intent owner or a delegate
+        /// - `InvalidIntentStatus`: Invalid new status, or the parent or sub-intent is resolved
+        /// - `IntentAlreadyClaimed`: A solver has claimed the intent
+        /// - `SubIntentNotFound`: The intent has no sub-intent at `index`
+        /// - `DependenciesNotMet`: A dependency of the sub-intent is not executed
+        ///
+        /// # Events
+        ///
+        /// - `SubIntentStatusUpdated`: Emitted when the sub-intent status is updated
+        /// - `IntentStatusUpdated`: Emitted when the parent status is rolled up
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::update_sub_intent_status())]
+        pub fn update_sub_intent_status(
+            origin: OriginFor<T>,
+            intent_id: IntentId,
+            index: u32,
+            new_status: u8,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let new_status =
+                IntentStatus::from_u8(new_status).ok_or(Error::<T>::InvalidIntentStatus)?;
+            ensure!(
+                matches!(new_status, IntentStatus::Executed | IntentStatus::Failed),
+                Error::<T>::InvalidIntentStatus
+            );
+
+            let intent = IntentById::<T>::get(intent_id).ok_or(Error::<T>::IntentNotFound)?;
+            ensure!(
+                intent.account == who
+                    || Self::is_delegate(&intent.account, &who, &DelegateScope::UpdateStatus),
+                Error::<T>::NotIntentOwner
+            );
+            ensure!(
+                intent.status == IntentStatus::Pending,
+                Error::<T>::InvalidIntentStatus
+            );
+            ensure!(
+                !IntentClaims::<T>::contains_key(intent_id),
+                Error::<T>::IntentAlreadyClaimed
+            );
+
+            let mut sub_intents =
+                SubIntents::<T>::get(intent_id).ok_or(Error::<T>::SubIntentNotFound)?;
+            let sub_intent = sub_intents
+                .get(index as usize)
+                .ok_or(Error::<T>::SubIntentNotFound)?;
+            ensure!(
+                sub_intent.status == IntentStatus::Pending,
+                Error::<T>::InvalidIntentStatus
+            );
+            if new_status == IntentStatus::Executed {
+                ensure!(
+                    sub_intent
+                        .depends_on
+                        .iter()
+                        .all(|dep| sub_intents[*dep as usize].status == IntentStatus::Executed),
+                    Error::<T>::DependenciesNotMet
+                );
+            }
+
+            sub_intents[index as usize].status = new_status.clone();
+            let rolled_up = Self::rollup_status(&sub_intents);
+            SubIntents::<T>::insert(intent_id, sub_intents);
+
+            Self::deposit_event(Event::SubIntentStatusUpdated {
+                intent_id,
+                index,
+                old_status: IntentStatus::Pending.as_u8(),
+                new_status: new_status.as_u8(),
+            });
+
+            if let Some(parent_status) = rolled_up {
+                Self::release_reward(intent_id, &intent.account)?;
+                IntentById::<T>::mutate(intent_id, |maybe_intent| {
+                    if let Some(intent) = maybe_intent {
+                        intent.status = parent_status.clone();
+                        Self::note_status_change(intent, &IntentStatus::Pending, false);
+                    }
+                });
+
+                Self::deposit_event(Event::IntentStatusUpdated {
+                    intent_id,
+                    old_status: IntentStatus::Pending.as_u8(),
+                    new_status: parent_status.as_u8(),
+                });
+            }
+
+            Ok(())
+        }
+
+        /// Remove a resolved intent and release its deposit
+        ///
+        /// Clears the intent together with its journey, sub-intents, external chain references,
+        /// execution error and fulfilment proof. Persona counters keep the intent's history.
+        ///
+        /// # Parameters
+        ///
+        /// - `origin`: The intent owner
+        /// - `intent_id`: The intent to remove
+        ///
+        /// # Errors
+        ///
+        /// - `IntentNotFound`: Intent does not exist
+        /// - `NotIntentOwner`: Caller is not the intent owner
+        /// - `InvalidIntentStatus`: Intent is still pending
+        /// - `FulfilmentPending`: A solver's fulfilment is awaiting its dispute period
+        ///
+        /// # Events
+        ///
+        /// - `IntentRemoved`: Emitted when the intent is removed
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::remove_intent(T::MaxJourneyStepsPerIntent::get()))]
+        pub fn remove_intent(
+            origin: OriginFor<T>,
+            intent_id: IntentId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let intent = IntentById::<T>::get(intent_id).ok_or(Error::<T>::IntentNotFound)?;
+            ensure!(intent.account == who, Error::<T>::NotIntentOwner);
+            ensure!(
+                intent.status != IntentStatus::Pending,
+                Error::<T>::InvalidIntentStatus
+            );
+            ensure!(
+                !PendingFulfilments::<T>::contains_key(intent_id),
+                Error::<T>::FulfilmentPending
+            );
+
+            let deposit = IntentDeposits::<T>::take(intent_id);
+            if !deposit.is_zero() {
+                T::Currency::release(
+                    &HoldReason::IntentDeposit.into(),
+                    &who,
+                    deposit,
+                    Precision::BestEffort,
+                )?;
+            }
+
+            IntentById::<T>::remove(intent_id);
+            IntentsByAccount::<T>::remove(&who, intent_key(intent_id));
+            IntentCountByAccount::<T>::mutate(&who, |count| count.saturating_dec());
+            let step_count = JourneyHeads::<T>::take(intent_id).map_or(0, |head| head.step_count);
+            let _ = JourneySteps::<T>::clear_prefix(intent_id, step_count, None);
+            SubIntents::<T>::remove(intent_id);
+            ChainRefs::<T>::remove(intent_id);
+            IntentErrors::<T>::remove(intent_id);
+            CancelReasons::<T>::remove(intent_id);
+            FulfilmentProofs::<T>::remove(intent_id);
+            AttestedExecutions::<T>::remove(intent_id);
+
+            Self::deposit_event(Event::IntentRemoved {
+                intent_id,
+                account: who,
+            });
+
+            Ok(Some(T::WeightInfo::remove_intent(step_count)).into())
+        }
+
+        /// Cancel a pending intent, refunding its reward
+        ///
+        /// Callable by the intent owner or its delegates with the `UpdateStatus` scope, and not
+        /// while a solver has claimed the intent. The intent stays stored as `Cancelled`, with
+        /// `reason` kept in `CancelReasons`, so its deposit stays held until `remove_intent`.
+        ///
+        /// # Parameters
+        ///
+        /// - `origin`: The intent owner or delegate
+        /// - `intent_id`: The intent to cancel
+        /// - `reason`: Why the intent is cancelled
+        ///
+        /// # Errors
+        ///
+        /// - `IntentNotFound`: Intent does not exist
+        /// - `NotIntentOwner`: Caller is not the intent owner
+        /// - `InvalidIntentStatus`: Intent is no longer pending
+        /// - `IntentAlreadyClaimed`: A solver has claimed the intent
+        ///
+        /// # Events
+        ///
+        /// - `IntentCancelled`: Emitted with the reason of the cancellation
+        /// - `IntentStatusUpdated`: Emitted when the intent is marked `Cancelled`
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::cancel_intent())]
+        pub fn cancel_intent(
+            origin: OriginFor<T>,
+            intent_id: IntentId,
+            reason: CancelReason,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let owner = IntentById::<T>::try_mutate(intent_id, |maybe_intent| {
+                let intent = maybe_intent.as_mut().ok_or(Error::<T>::IntentNotFound)?;
+                ensure!(
+                    intent.account == who
+                        || Self::is_delegate(&intent.account, &who, &DelegateScope::UpdateStatus),
+                    Error::<T>::NotIntentOwner
+                );
+                ensure!(
+                    intent.status == IntentStatus::Pending,
+                    Error::<T>::InvalidIntentStatus
+                );
+                ensure!(
+                    !IntentClaims::<T>::contains_key(intent_id),
+                    Error::<T>::IntentAlreadyClaimed
+                );
+                intent.status = IntentStatus::Cancelled;
+                Ok::<_, DispatchError>(intent.account.clone())
+            })?;
+
+            Self::release_reward(intent_id, &owner)?;
+            CancelReasons::<T>::insert(intent_id, &reason);
+
+            Self::deposit_event(Event::IntentCancelled { intent_id, reason });
+            Self::deposit_event(Event::IntentStatusUpdated {
+                intent_id,
+                old_status: IntentStatus::Pending.as_u8(),
+                new_status: IntentStatus::Cancelled.as_u8(),
+            });
+
+            Ok(())
+        }
+
+        /// Store a reusable intent template
+        ///
+        /// Templates are validated like the intents created from them. `TemplateDeposit` is
+        /// held until the template is removed.
+        ///
+        /// # Parameters
+        ///
+        /// - `origin`: The template owner (must be signed)
+        /// - `kind`: Typed payload of the intents created from the template
+        /// - `metadata`: Metadata of the intents created from the template (max `MaxMetadataLen`
+        ///   bytes)
+        ///
+        /// # Errors
+        ///
+        /// - `ZeroAmount`, `SameAssetSwap`, `EmptyBridgeRecipient`, `InvalidContractAddress`,
+        ///   `EmptyContractCall`, `EmptyBatch`: The payload is invalid
+        /// - `MetadataTooLong`: Metadata exceeds `MaxMetadataLen`
+        /// - `TooManyTemplates`: Caller already has `MaxTemplatesPerAccount` templates
+        /// - `FundsUnavailable`: Caller cannot cover `TemplateDeposit`
+        ///
+        /// # Events
+        ///
+        /// - `TemplateCreated`: Emitted with the new template ID
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::create_template())]
+        pub fn create_template(
+            origin: OriginFor<T>,
+            kind: IntentKind<T::AccountId>,
+            metadata: Vec<u8>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::validate_intent_kind(&kind)?;
+            let metadata: BoundedVec<u8, T::MaxMetadataLen> =
+                metadata.try_into().map_err(|_| Error::<T>::MetadataTooLong)?;
+
+            TemplateCount::<T>::try_mutate(&who, |count| -> DispatchResult {
+                ensure!(
+                    *count < T::MaxTemplatesPerAccount::get(),
+                    Error::<T>::TooManyTemplates
+                );
+                count.saturating_inc();
+                Ok(())
+            })?;
+
+            let deposit = T::TemplateDeposit::get();
+            if !deposit.is_zero() {
+                T::Currency::hold(&HoldReason::TemplateDeposit.into(), &who, deposit)?;
+            }
+
+            let template_id = NextTemplateId::<T>::get();
+            NextTemplateId::<T>::put(template_id.saturating_add(1));
+            Templates::<T>::insert(&who, template_id, IntentTemplate { kind, metadata });
+            if !deposit.is_zero() {
+                TemplateDeposits::<T>::insert(&who, template_id, deposit);
+            }
+
+            Self::deposit_event(Event::TemplateCreated {
+                account: who,
+                template_id,
+            });
+
+            Ok(())
+        }
+
+        /// Remove an intent template, releasing its deposit
+        ///
+        /// Recurring schedules of the template are cancelled at their next occurrence, which
+        /// fails with `TemplateNotFound`.
+        ///
+        /// # Parameters
+        ///
+        /// - `origin`: The template owner
+        /// - `template_id`: The template to remove
+        ///
+        /// # Errors
+        ///
+        /// - `TemplateNotFound`: Caller has no template with this ID
+        ///
+        /// # Events
+        ///
+        /// - `TemplateRemoved`: Emitted when the template is removed
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::remove_template())]
+        pub fn remove_template(origin: OriginFor<T>, template_id: TemplateId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Templates::<T>::take(&who, template_id).ok_or(Error::<T>::TemplateNotFound)?;
+            TemplateCount::<T>::mutate(&who, |count| count.saturating_dec());
+
+            let deposit = TemplateDeposits::<T>::take(&who, template_id);
+            if !deposit.is_zero() {
+                T::Currency::release(
+                    &HoldReason::TemplateDeposit.into(),
+                    &who,
+                    deposit,
+                    Precision::BestEffort,
+                )?;
+            }
+
+            Self::deposit_event(Event::TemplateRemoved {
+                account: who,
+                template_id,
+            });
+
+            Ok(())
+        }
+
+        /// Instantiate a template every `period` blocks, `count` times
+        ///
+        /// The first intent is created `period` blocks from now. Each occurrence creates a
+        /// pending intent owned by the caller, holding `IntentDeposit` and emitting
+        /// `IntentExecuted` like `execute_intent`. When more occurrences are due in a block than
+        /// `MaxInstantiationsPerBlock`, the excess is carried over to the following blocks; later
+        /// occurrences stay aligned on the original period. `RecurringDeposit` is held until the
+        /// last occurrence or the cancellation of the schedule.
+        ///
+        /// # Parameters
+        ///
+        /// - `origin`: The template owner
+        /// - `template_id`: The template to instantiate
+        /// - `period`: Blocks between two occurrences
+        /// - `count`: Number of occurrences
+        ///
+        /// # Errors
+        ///
+        /// - `InvalidRecurrence`: `period` or `count` is zero
+        /// - `TemplateNotFound`: Caller has no template with this ID
+        /// - `TooManyRecurringIntents`: Caller already has `MaxRecurringPerAccount` schedules
+        /// - `FundsUnavailable`: Caller cannot cover `RecurringDeposit`
+        ///
+        /// # Events
+        ///
+        /// - `RecurringIntentScheduled`: Emitted with the new schedule ID
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::schedule_recurring_intent())]
+        pub fn schedule_recurring_intent(
+            origin: OriginFor<T>,
+            template_id: TemplateId,
+            period: BlockNumberFor<T>,
+            count: u32,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(
+                !period.is_zero() && count > 0,
+                Error::<T>::InvalidRecurrence
+            );
+            ensure!(
+                Templates::<T>::contains_key(&who, template_id),
+                Error::<T>::TemplateNotFound
+            );
+
+            RecurringCount::<T>::try_mutate(&who, |active| -> DispatchResult {
+                ensure!(
+                    *active < T::MaxRecurringPerAccount::get(),
+                    Error::<T>::TooManyRecurringIntents
+                );
+                active.saturating_inc();
+                Ok(())
+            })?;
+
+            let deposit = T::RecurringDeposit::get();
+            if !deposit.is_zero() {
+                T::Currency::hold(&HoldReason::RecurringDeposit.into(), &who, deposit)?;
+            }
+
+            let schedule_id = NextScheduleId::<T>::get();
+            NextScheduleId::<T>::put(schedule_id.saturating_add(1));
+            if !deposit.is_zero() {
+                RecurringDeposits::<T>::insert(schedule_id, deposit);
+            }
+
+            let first_at = frame_system::Pallet::<T>::block_number().saturating_add(period);
+            RecurringIntents::<T>::insert(
+                schedule_id,
+                RecurringIntent {
+                    owner: who.clone(),
+                    template_id,
+                    period,
+                    remaining: count,
+                    next_at: first_at,
+                },
+            );
+            Agenda::<T>::insert(first_at, schedule_id, ());
+
+            Self::deposit_event(Event::RecurringIntentScheduled {
+                schedule_id,
+                account: who,
+                template_id,
+                period,
+                count,
+                first_at,
+            });
+
+            Ok(())
+        }
+
+        /// Stop a recurring schedule, releasing its deposit
+        ///
+        /// Intents already created are left untouched.
+        ///
+        /// # Parameters
+        ///
+        /// - `origin`: The schedule owner
+        /// - `schedule_id`: The schedule to cancel
+        ///
+        /// # Errors
+        ///
+        /// - `RecurringIntentNotFound`: No active schedule has this ID
+        /// - `NotIntentOwner`: Caller does not own the schedule
+        ///
+        /// # Events
+        ///
+        /// - `RecurringIntentCancelled`: Emitted when the schedule is cancelled
+        #[pallet::call_index(22)]
+        #[pallet::weight(T::WeightInfo::cancel_recurring_intent())]
+        pub fn cancel_recurring_intent(
+            origin: OriginFor<T>,
+            schedule_id: ScheduleId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let schedule = RecurringIntents::<T>::get(schedule_id)
+                .ok_or(Error::<T>::RecurringIntentNotFound)?;
+            ensure!(schedule.owner == who, Error::<T>::NotIntentOwner);
+
+            // The pending agenda entry is skipped once the schedule is gone
+            Self::end_schedule(schedule_id, &who);
+
+            Self::deposit_event(Event::RecurringIntentCancelled { schedule_id });
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Store a new pending intent owned by `who` and index it under the account
+        fn create_intent(
+            who: &T::AccountId,
+            kind: IntentKind<T::AccountId>,
+            metadata: Vec<u8>,
+        ) -> Result<IntentId, DispatchError> {
+            // Convert metadata to BoundedVec
+            let bounded_metadata: BoundedVec<u8, T::MaxMetadataLen> =
+                metadata.try_into().map_err(|_| Error::<T>::MetadataTooLong)?;
+
+            // Hold the storage deposit
+            let deposit = T::IntentDeposit::get();
+            if !deposit.is_zero() {
+                T::Currency::hold(&HoldReason::IntentDeposit.into(), who, deposit)?;
+            }
+
+            // Only allocate the next intent ID once nothing can fail anymore
+            let intent_id = NextIntentId::<T>::get();
+            NextIntentId::<T>::put(intent_id.saturating_add(1));
+
+            // Get current block number as timestamp
+            let timestamp = frame_system::Pallet::<T>::block_number();
+
+            // Create intent struct
+            let intent = Intent {
+                intent_id,
+                account: who.clone(),
+                status: IntentStatus::Pending,
+                timestamp,
+                metadata: bounded_metadata,
+                kind: Some(kind),
+            };
+
+            // Store intent by ID
+            IntentById::<T>::insert(intent_id, intent);
+
+            // Record the deposit and index the intent under the account
+            if !deposit.is_zero() {
+                IntentDeposits::<T>::insert(intent_id, deposit);
+            }
+            IntentsByAccount::<T>::insert(who, intent_key(intent_id), ());
+            IntentCountByAccount::<T>::mutate(who, |count| count.saturating_inc());
+
+            Self::update_persona(who, |persona| persona.intents_created.saturating_inc());
+
+            // Emit event
+            Self::deposit_event(Event::IntentExecuted {
+                intent_id,
+                account: who.clone(),
+                timestamp,
+            });
+
+            Ok(intent_id)
+        }
+
+        /// Update the persona counters of `intent`'s owner after its status left `old_status`
+        ///
+        /// `attested` tells whether the new status was attested by a dispatch, a solver or a
+        /// relayer rather than reported by the owner. Only attested executions are counted.
+        fn note_status_change(intent: &Intent<T>, old_status: &IntentStatus, attested: bool) {
+            if intent.status == *old_status {
+                return;
+            }
+
+            let was_counted = *old_status == IntentStatus::Executed
+                && AttestedExecutions::<T>::take(intent.intent_id).is_some();
+            let counts = intent.status == IntentStatus::Executed && attested;
+            if counts {
+                AttestedExecutions::<T>::insert(intent.intent_id, ());
+            }
+
+            Self::update_persona(&intent.account, |persona| {
+                match old_status {
+                    IntentStatus::Executed if was_counted => {
+                        persona.intents_executed.saturating_dec()
+                    },
+                    IntentStatus::Failed => persona.intents_failed.saturating_dec(),
+                    IntentStatus::Executed | IntentStatus::Pending | IntentStatus::Cancelled => {},
+                }
+                match intent.status {
+                    IntentStatus::Executed if counts => {
+                        persona.intents_executed.saturating_inc();
+                        if let Some(kind) = &intent.kind {
+                            persona.kinds_executed |= 1 << kind.kind_index();
+                        }
+                    },
+                    IntentStatus::Failed => persona.intents_failed.saturating_inc(),
+                    IntentStatus::Executed | IntentStatus::Pending | IntentStatus::Cancelled => {},
+                }
+            });
+        }
+
+        /// Apply `update` to the persona of `who`, then unlock the achievements it now meets
+        fn update_persona(who: &T::AccountId, update: impl FnOnce(&mut PersonaStats)) {
+            let persona = Personas::<T>::mutate(who, |persona| {
+                update(persona);
+                persona.clone()
+            });
+
+            let min_resolved = T::MinResolvedForSuccessRatio::get();
+            for achievement in T::AchievementThresholds::get() {
+                if persona.progress(&achievement.kind, min_resolved) < achievement.threshold
+                    || UnlockedAchievements::<T>::contains_key(who, &achievement)
+                {
+                    continue;
+                }
+
+                UnlockedAchievements::<T>::insert(
+                    who,
+                    &achievement,
+                    frame_system::Pallet::<T>::block_number(),
+                );
+                Self::deposit_event(Event::AchievementUnlocked {
+                    account: who.clone(),
+                    achievement,
+                });
+            }
+        }
+
+        /// Status of a multi-step intent derived from its sub-intents
+        ///
+        /// `Failed` as soon as one sub-intent fails, `Executed` once all of them are executed,
+        /// and `None` while the intent should stay pending.
+        pub fn rollup_status(sub_intents: &[SubIntent]) -> Option<IntentStatus> {
+            if sub_intents.iter().any(|sub| sub.status == IntentStatus::Failed) {
+                Some(IntentStatus::Failed)
+            } else if sub_intents.iter().all(|sub| sub.status == IntentStatus::Executed) {
+                Some(IntentStatus::Executed)
+            } else {
+                None
+            }
+        }
+
+        /// Indices of the pending sub-intents of `intent_id` whose dependencies are all executed
+        ///
+        /// Empty once the parent intent has been resolved.
+        pub fn ready_sub_intents(intent_id: IntentId) -> Vec<u32> {
+            let parent_pending = IntentById::<T>::get(intent_id)
+                .is_some_and(|intent| intent.status == IntentStatus::Pending);
+            if !parent_pending {
+                return Vec::new();
+            }
+
+            let sub_intents = SubIntents::<T>::get(intent_id).unwrap_or_default();
+            sub_intents
+                .iter()
+                .enumerate()
+                .filter(|(_, sub)| sub.status == IntentStatus::Pending)
+                .filter(|(_, sub)| {
+                    sub.depends_on.iter().all(|dep| {
+                        sub_intents
+                            .get(*dep as usize)
+                            .is_some_and(|dep| dep.status == IntentStatus::Executed)
+                    })
+                })
+                .map(|(index, _)| index as u32)
+                .collect()
+        }
+
+        /// Up to `limit` intents owned by `account`, oldest first
+        ///
+        /// Starts after `cursor`, the last intent of the previous page, or at the beginning
+        /// of the index when `None`.
+        pub fn intents_of(
+            account: &T::AccountId,
+            cursor: Option<IntentId>,
+            limit: u32,
+        ) -> Vec<IntentId> {
+            let keys = match cursor {
+                Some(cursor) => IntentsByAccount::<T>::iter_key_prefix_from(
+                    account,
+                    IntentsByAccount::<T>::hashed_key_for(account, intent_key(cursor)),
+                ),
+                None => IntentsByAccount::<T>::iter_key_prefix(account),
+            };
+            keys.take(limit as usize).map(IntentId::from_be_bytes).collect()
+        }
+
+        /// Up to `limit` of the newest intents owned by `account`, newest first
+        ///
+        /// Reads the end of the index: the intents created in a window ending at
+        /// `NextIntentId`, doubling the window until it holds `limit` intents or the whole
+        /// index.
+        pub fn latest_intents_of(account: &T::AccountId, limit: u32) -> Vec<IntentId> {
+            let wanted = limit.min(IntentCountByAccount::<T>::get(account)) as usize;
+            if wanted == 0 {
+                return Vec::new();
+            }
+
+            let next_id = NextIntentId::<T>::get();
+            let mut window = wanted as IntentId;
+            loop {
+                // Pages start after their cursor, so the window starts after `start - 1`
+                let cursor = next_id.saturating_sub(window).checked_sub(1);
+                let mut intents = Self::intents_of(account, cursor, u32::MAX);
+                if intents.len() >= wanted || cursor.is_none() {
+                    intents.reverse();
+                    intents.truncate(wanted);
+                    return intents;
+                }
+                window = window.saturating_mul(2);
+            }
+        }
+
+        /// Sub-intents of `intent_id` paired with their actions, in order
+        ///
+        /// Empty for intents that are not multi-step.
+        pub fn sub_intent_tree(
+            intent_id: IntentId,
+        ) -> Vec<(IntentAction<T::AccountId>, SubIntent)> {
+            let actions = match IntentById::<T>::get(intent_id).and_then(|intent| intent.kind) {
+                Some(IntentKind::Batch(actions)) => actions.into_inner(),
+                _ => return Vec::new(),
+            };
+            let sub_intents = SubIntents::<T>::get(intent_id).unwrap_or_default();
+            actions.into_iter().zip(sub_intents).collect()
+        }
+
+        /// Whether `who` may append journey steps to `intent`: its owner, a delegate with the
+        /// `RecordJourney` scope, or its claiming solver
+        pub fn can_record_journey(
+            who: &T::AccountId,
+            intent_id: IntentId,
+            intent: &Intent<T>,
+        ) -> bool {
+            intent.account == *who
+                || Self::is_delegate(&intent.account, who, &DelegateScope::RecordJourney)
+                || IntentClaims::<T>::get(intent_id).is_some_and(|claim| claim.solver == *who)
+        }
+
+        /// Whether `owner` has granted `delegate` an unexpired delegation covering `scope`
+        pub fn is_delegate(
+            owner: &T::AccountId,
+            delegate: &T::AccountId,
+            scope: &DelegateScope,
+        ) -> bool {
+            let now = frame_system::Pallet::<T>::block_number();
+            Delegates::<T>::get(owner, delegate).is_some_and(|delegation| {
+                delegation.scope.covers(scope)
+                    && delegation.expires_at.is_none_or(|expires_at| now <= expires_at)
+            })
+        }
+
+        /// Journey steps of `intent_id`, in recording order
+        pub fn journey_by_intent(intent_id: IntentId) -> Vec<JourneyStep<T>> {
+            let step_count = JourneyHeads::<T>::get(intent_id).map_or(0, |head| head.step_count);
+            (0..step_count)
+                .filter_map(|step_id| JourneySteps::<T>::get(intent_id, step_id))
+                .collect()
+        }
+
+        /// Append `step` to the journey of `intent_id` and extend its hash chain
+        ///
+        /// The step ID is assigned here, overriding the one of `step`. Returns the number of
+        /// steps in the journey afterwards.
+        pub(crate) fn append_journey_step(
+            intent_id: IntentId,
+            mut step: JourneyStep<T>,
+        ) -> Result<u32, DispatchError> {
+            JourneyHeads::<T>::try_mutate(intent_id, |maybe_head| {
+                let head = maybe_head.get_or_insert_with(|| JourneyHead {
+                    step_count: 0,
+                    hash: T::Hash::default(),
+                });
+                ensure!(
+                    head.step_count < T::MaxJourneyStepsPerIntent::get(),
+                    Error::<T>::MaxJourneyStepsExceeded
+                );
+
+                step.step_id = head.step_count;
+                head.hash = T::Hashing::hash_of(&(&head.hash, &step));
+                head.step_count.saturating_inc();
+                JourneySteps::<T>::insert(intent_id, step.step_id, step);
+
+                Ok(head.step_count)
+            })
+        }
+
+        /// Process the agenda from `AgendaCursor` up to `now`, within the per-block limit
+        ///
+        /// Both the occurrences processed and the agendas visited are bounded by
+        /// `MaxInstantiationsPerBlock`. A block whose agenda could not be drained stays at the
+        /// cursor, carrying its remaining occurrences over to the next block.
+        pub(crate) fn process_agenda(now: BlockNumberFor<T>) -> Weight {
+            let limit = T::MaxInstantiationsPerBlock::get();
+            let mut cursor = AgendaCursor::<T>::get().unwrap_or(now);
+            let mut blocks = 0u32;
+            let mut processed = 0u32;
+
+            while cursor <= now && blocks < limit && processed < limit {
+                blocks.saturating_inc();
+                let budget = limit.saturating_sub(processed);
+                let due: Vec<ScheduleId> =
+                    Agenda::<T>::iter_key_prefix(cursor).take(budget as usize).collect();
+                processed = processed.saturating_add(due.len() as u32);
+
+                for schedule_id in due.iter() {
+                    Agenda::<T>::remove(cursor, schedule_id);
+                    Self::instantiate_recurring_intent(*schedule_id, cursor);
+                }
+
+                // The agenda may hold more occurrences than the budget left
+                if due.len() as u32 == budget {
+                    break;
+                }
+                cursor = cursor.saturating_add(One::one());
+            }
+
+            AgendaCursor::<T>::put(cursor);
+            T::WeightInfo::process_agenda(blocks, processed)
+        }
+
+        /// Create the intent of the occurrence of `schedule_id` due at `due`
+        ///
+        /// Schedules cancelled since the occurrence was queued are skipped, and schedules whose
+        /// template was removed are cancelled.
+        fn instantiate_recurring_intent(schedule_id: ScheduleId, due: BlockNumberFor<T>) {
+            let Some(mut schedule) = RecurringIntents::<T>::get(schedule_id) else {
+                return;
+            };
+
+            let result = with_storage_layer(|| {
+                let template = Templates::<T>::get(&schedule.owner, schedule.template_id)
+                    .ok_or(Error::<T>::TemplateNotFound)?;
+                Self::create_intent(&schedule.owner, template.kind, template.metadata.into_inner())
+            });
+
+            schedule.remaining.saturating_dec();
+            let remaining = schedule.remaining;
+            // Every later occurrence would fail the same way
+            let template_removed =
+                matches!(&result, Err(error) if *error == Error::<T>::TemplateNotFound.into());
+            match result {
+                Ok(intent_id) => Self::deposit_event(Event::RecurringIntentInstantiated {
+                    schedule_id,
+                    intent_id,
+                    remaining,
+                }),
+                Err(error) => Self::deposit_event(Event::RecurringIntentFailed {
+                    schedule_id,
+                    error,
+                    remaining,
+                }),
+            }
+
+            if template_removed {
+                Self::end_schedule(schedule_id, &schedule.owner);
+                Self::deposit_event(Event::RecurringIntentCancelled { schedule_id });
+            } else if remaining == 0 {
+                Self::end_schedule(schedule_id, &schedule.owner);
+            } else {
+                // Aligned on the due block, so that carried over occurrences do not drift
+                schedule.next_at = due.saturating_add(schedule.period);
+                Agenda::<T>::insert(schedule.next_at, schedule_id, ());
+                RecurringIntents::<T>::insert(schedule_id, schedule);
+            }
+        }
+
+        /// Remove the schedule `schedule_id` of `owner`, releasing its deposit
+        fn end_schedule(schedule_id: ScheduleId, owner: &T::AccountId) {
+            RecurringIntents::<T>::remove(schedule_id);
+            RecurringCount::<T>::mutate(owner, |active| active.saturating_dec());
+
+            let deposit = RecurringDeposits::<T>::take(schedule_id);
+            if !deposit.is_zero() {
+                // Best effort: the whole recorded deposit is on hold
+                let _ = T::Currency::release(
+                    &HoldReason::RecurringDeposit.into(),
+                    owner,
+                    deposit,
+                    Precision::BestEffort,
+                );
+            }
+        }
+
+        /// Move `ClaimSlash` of `SolverBond` from the bond of `solver` to `owner`
+        ///
+        /// `update` adjusts the solver's counters. Returns the amount slashed, capped by what
+        /// is left of the bond. A transfer the owner cannot receive (e.g. below the existential
+        /// deposit) slashes nothing rather than leaving the claim or fulfilment stuck.
+        fn slash_solver(
+            solver: &T::AccountId,
+            owner: &T::AccountId,
+            update: impl FnOnce(&mut SolverInfo<BalanceOf<T>>),
+        ) -> BalanceOf<T> {
+            let mut amount = Zero::zero();
+            Solvers::<T>::mutate(solver, |maybe_info| {
+                if let Some(info) = maybe_info {
+                    amount = T::Currency::transfer_on_hold(
+                        &HoldReason::SolverBond.into(),
+                        solver,
+                        owner,
+                        T::ClaimSlash::get().mul_floor(T::SolverBond::get()).min(info.bond),
+                        Precision::BestEffort,
+                        Restriction::Free,
+                        Fortitude::Force,
+                    )
+                    .unwrap_or_else(|_| Zero::zero());
+
+                    info.bond = info.bond.saturating_sub(amount);
+                    info.slashed.saturating_inc();
+                    update(info);
+                }
+            });
+            amount
+        }
+
+        /// Return the reward escrowed for `intent_id` to its owner
+        fn release_reward(intent_id: IntentId, owner: &T::AccountId) -> DispatchResult {
+            let reward = IntentRewards::<T>::take(intent_id);
+            if !reward.is_zero() {
+                T::Currency::release(
+                    &HoldReason::IntentReward.into(),
+                    owner,
+                    reward,
+                    Precision::BestEffort,
+                )?;
+            }
+            Ok(())
+        }
+
+        /// Dispatch `calls` atomically as `who`, then record the outcome on `intent_id`
+        ///
+        /// Returns the weight consumed by the calls and the journey steps recorded for them.
+        fn dispatch_intent_calls(
+            who: &T::AccountId,
+            intent_id: IntentId,
+            calls: Vec<<T as Config>::RuntimeCall>,
+        ) -> Weight {
+            let call_count = calls.len() as u32;
+            let call_names: Vec<_> = calls
+                .iter()
+                .map(|call| call.get_call_metadata())
+                .collect();
+
+            let mut weight = Weight::zero();
+            let mut failure: Option<ExecutionError> = None;
+
+            let result = with_transaction(|| {
+                for (index, call) in calls.into_iter().enumerate() {
+                    let info = call.get_dispatch_info();
+                    let origin = frame_system::RawOrigin::Signed(who.clone()).into();
+
+                    match call.dispatch(origin) {
+                        Ok(post_info) => {
+                            weight = weight.saturating_add(post_info.calc_actual_weight(&info));
+                        },
+                        Err(err) => {
+                            weight = weight.saturating_add(err.post_info.calc_actual_weight(&info));
+                            failure = Some(ExecutionError {
+                                call_index: index as u32,
+                                error: err.error,
+                            });
+                            return TransactionOutcome::Rollback(Err(err.error));
+                        },
+                    }
+                }
+                TransactionOutcome::Commit(Ok(()))
+            });
+
+            let new_status = if result.is_ok() {
+                IntentStatus::Executed
+            } else {
+                IntentStatus::Failed
+            };
+            Self::deposit_event(Event::IntentCallsDispatched {
+                intent_id,
+                call_count,
+                result,
+            });
+
+            // Nested calls (e.g. through `Sudo::sudo_as`) may have acted on the intent: its
+            // status only moves out of `Pending`, and nothing is recorded on a removed intent
+            let Some(mut intent) = IntentById::<T>::get(intent_id) else {
+                return weight;
+            };
+            let status_changed = intent.status == IntentStatus::Pending;
+            if status_changed {
+                intent.status = new_status.clone();
+                Self::note_status_change(&intent, &IntentStatus::Pending, true);
+                IntentById::<T>::insert(intent_id, intent);
+            }
+
+            if let Some(failure) = &failure {
+                IntentErrors::<T>::insert(intent_id, failure);
+            }
+
+            // One journey step per call, describing what happened to it
+            let timestamp = frame_system::Pallet::<T>::block_number();
+            let failed_index = failure.as_ref().map(|failure| failure.call_index);
+            let mut step_counts = Vec::with_capacity(call_names.len());
+            for (index, metadata) in call_names.iter().enumerate() {
+                let (step_kind, outcome): (_, &[u8]) = match failed_index {
+                    None => (JourneyStepKind::TxConfirmed, b"executed"),
+                    Some(failed) if (index as u32) < failed => {
+                        (JourneyStepKind::Note, b"rolled back")
+                    },
+                    Some(failed) if index as u32 == failed => (JourneyStepKind::Note, b"failed"),
+                    Some(_) => (JourneyStepKind::Note, b"skipped"),
+                };
+
+                let mut description = Vec::new();
+                description.extend_from_slice(metadata.pallet_name.as_bytes());
+                description.push(b'.');
+                description.extend_from_slice(metadata.function_name.as_bytes());
+                description.extend_from_slice(b": ");
+                description.extend_from_slice(outcome);
+
+                let step = JourneyStep {
+                    step_id: 0,
+                    step_kind,
+                    description: BoundedVec::truncate_from(description),
+                    timestamp,
+                    author: who.clone(),
+                    linked_tx: None,
+                    attachment: None,
+                };
+
+                // Nested calls may have filled the journey: the remaining steps are dropped
+                match Self::append_journey_step(intent_id, step) {
+                    Ok(step_count) => step_counts.push(step_count),
+                    Err(_) => break,
+                }
+            }
+            Self::update_persona(who, |persona| {
+                persona.journey_steps =
+                    persona.journey_steps.saturating_add(step_counts.len() as u32);
+            });
+
+            // One event per step, as for `record_journey`
+            for step_count in step_counts {
+                Self::deposit_event(Event::JourneyRecorded {
+                    intent_id,
+                    step_count,
+                });
+            }
+            if status_changed {
+                Self::deposit_event(Event::IntentStatusUpdated {
+                    intent_id,
+                    old_status: IntentStatus::Pending.as_u8(),
+                    new_status: new_status.as_u8(),
+                });
+            }
+
+            weight.saturating_add(T::WeightInfo::record_journey().saturating_mul(call_count as u64))
+        }
+
+        /// Validate a typed intent payload
+        pub fn validate_intent_kind(kind: &IntentKind<T::AccountId>) -> DispatchResult {
+            match kind {
+                IntentKind::Transfer(transfer) => Self::validate_transfer(transfer),
+                IntentKind::Swap(swap) => Self::validate_swap(swap),
+                IntentKind::Bridge(bridge) => Self::validate_bridge(bridge),
+                IntentKind::ContractCall(call) => Self::validate_contract_call(call),
+                IntentKind::Batch(actions) => {
+                    ensure!(!actions.is_empty(), Error::<T>::EmptyBatch);
+                    actions.iter().try_for_each(|action| match action {
+                        IntentAction::Transfer(transfer) => Self::validate_transfer(transfer),
+                        IntentAction::Swap(swap) => Self::validate_swap(swap),
+                        IntentAction::Bridge(bridge) => Self::validate_bridge(bridge),
+                        IntentAction::ContractCall(call) => Self::validate_contract_call(call),
+                    })
+                },
+            }
+        }
+
+        fn validate_transfer(transfer: &TransferIntent<T::AccountId>) -> DispatchResult {
+            ensure!(transfer.amount > 0, Error::<T>::ZeroAmount);
+            Ok(())
+        }
+
+        fn validate_swap(swap: &SwapIntent) -> DispatchResult {
+            ensure!(swap.amount_in > 0, Error::<T>::ZeroAmount);
+            ensure!(swap.asset_in != swap.asset_out, Error::<T>::SameAssetSwap);
+            Ok(())
+        }
+
+        fn validate_bridge(bridge: &BridgeIntent) -> DispatchResult {
+            ensure!(bridge.amount > 0, Error::<T>::ZeroAmount);
+            ensure!(!bridge.recipient.is_empty(), Error::<T>::EmptyBridgeRecipient);
+            Ok(())
+        }
+
+        fn validate_contract_call(call: &ContractCallIntent) -> DispatchResult {
+            ensure!(call.contract != [0u8; 20], Error::<T>::InvalidContractAddress);
+            ensure!(call.value > 0 || !call.input.is_empty(), Error::<T>::EmptyContractCall);
+            Ok(())
+        }
     }
 }
@@ -0,0 +1,612 @@
+//! Storage migrations for the ChainGhost pallet
+
+/// Migration to storage version 1
+///
+/// Adds the typed `kind` field to every stored `Intent`.
+pub mod v1 {
+    use crate::{Config, Intent, IntentById, IntentId, IntentStatus, Pallet};
+    use frame_support::{
+        migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+    };
+    use frame_system::pallet_prelude::BlockNumberFor;
+
+    #[cfg(feature = "try-runtime")]
+    use sp_std::vec::Vec;
+
+    /// Storage layout of version 0
+    pub mod v0 {
+        use super::*;
+
+        /// `Intent` as stored before version 1
+        #[derive(Clone, Encode, Decode, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+        #[scale_info(skip_type_params(T))]
+        pub struct OldIntent<T: Config> {
+            pub intent_id: IntentId,
+            pub account: T::AccountId,
+            pub status: IntentStatus,
+            pub timestamp: BlockNumberFor<T>,
+            pub metadata: BoundedVec<u8, ConstU32<256>>,
+        }
+
+        /// `IntentById` as stored before version 1
+        #[frame_support::storage_alias]
+        pub type IntentById<T: Config> =
+            StorageMap<Pallet<T>, Blake2_128Concat, IntentId, OldIntent<T>>;
+    }
+
+    /// Rewrites every stored intent with `kind` set to `None`
+    ///
+    /// Intents created before typed payloads only carry opaque metadata, so no kind
+    /// can be recovered for them.
+    pub struct InnerMigrateV0ToV1<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+
+            IntentById::<T>::translate::<v0::OldIntent<T>, _>(|_, old| {
+                translated += 1;
+                Some(Intent {
+                    intent_id: old.intent_id,
+                    account: old.account,
+                    status: old.status,
+                    timestamp: old.timestamp,
+                    metadata: BoundedVec::truncate_from(old.metadata.into_inner()),
+                    kind: None,
+                })
+            });
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+            Ok((v0::IntentById::<T>::iter_keys().count() as u64).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+            let expected = u64::decode(&mut &state[..])
+                .map_err(|_| "Failed to decode pre-upgrade intent count")?;
+
+            let migrated = IntentById::<T>::iter_values().count() as u64;
+            ensure!(migrated == expected, "Intent count changed during migration");
+
+            Ok(())
+        }
+    }
+
+    /// Migrates `IntentById` to version 1, only running while the on-chain version is 0
+    pub type MigrateV0ToV1<T> = VersionedMigration<
+        0,
+        1,
+        InnerMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migration to storage version 2
+///
+/// Adds the step kind, author, linked transaction and attachment to every stored `JourneyStep`.
+pub mod v2 {
+    use super::v4::v3::{JourneyByIntent, OldJourneyStep as JourneyStep};
+    use crate::{Config, IntentById, IntentId, JourneyStepKind, Pallet};
+    use frame_support::{
+        migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+    };
+    use frame_system::pallet_prelude::BlockNumberFor;
+
+    #[cfg(feature = "try-runtime")]
+    use sp_std::vec::Vec;
+
+    /// Storage layout of version 1
+    pub mod v1 {
+        use super::*;
+
+        /// `JourneyStep` as stored before version 2
+        #[derive(Clone, Encode, Decode, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+        #[scale_info(skip_type_params(T))]
+        pub struct OldJourneyStep<T: Config> {
+            pub step_id: u32,
+            pub description: BoundedVec<u8, ConstU32<512>>,
+            pub timestamp: BlockNumberFor<T>,
+        }
+
+        /// `JourneyByIntent` as stored before version 2
+        #[frame_support::storage_alias]
+        pub type JourneyByIntent<T: Config> = StorageMap<
+            Pallet<T>,
+            Blake2_128Concat,
+            IntentId,
+            BoundedVec<OldJourneyStep<T>, <T as Config>::MaxJourneyStepsPerIntent>,
+            ValueQuery,
+        >;
+    }
+
+    /// Rewrites every stored journey step as a `Note` authored by the intent owner
+    ///
+    /// Only owners could record steps before version 2, and free-text steps carry no
+    /// linked transaction or attachment.
+    pub struct InnerMigrateV1ToV2<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV1ToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+
+            JourneyByIntent::<T>::translate::<
+                BoundedVec<v1::OldJourneyStep<T>, T::MaxJourneyStepsPerIntent>,
+                _,
+            >(|intent_id, old_steps| {
+                translated += 1;
+                // Journeys are only recorded on existing intents, which are never removed
+                let owner = IntentById::<T>::get(intent_id)?.account;
+                let steps = old_steps
+                    .into_iter()
+                    .map(|old| JourneyStep {
+                        step_id: old.step_id,
+                        step_kind: JourneyStepKind::Note,
+                        description: old.description,
+                        timestamp: old.timestamp,
+                        author: owner.clone(),
+                        linked_tx: None,
+                        attachment: None,
+                    })
+                    .collect::<sp_std::vec::Vec<_>>();
+                Some(BoundedVec::truncate_from(steps))
+            });
+
+            T::DbWeight::get().reads_writes(translated.saturating_mul(2), translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+            let steps: u64 = v1::JourneyByIntent::<T>::iter_values()
+                .map(|steps| steps.len() as u64)
+                .sum();
+            Ok(steps.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+            let expected = u64::decode(&mut &state[..])
+                .map_err(|_| "Failed to decode pre-upgrade journey step count")?;
+
+            let migrated: u64 = JourneyByIntent::<T>::iter_values()
+                .map(|steps| steps.len() as u64)
+                .sum();
+            ensure!(migrated == expected, "Journey step count changed during migration");
+
+            Ok(())
+        }
+    }
+
+    /// Migrates `JourneyByIntent` to version 2, only running while the on-chain version is 1
+    pub type MigrateV1ToV2<T> = VersionedMigration<
+        1,
+        2,
+        InnerMigrateV1ToV2<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migration to storage version 3
+///
+/// Moves every per-account `BoundedVec` of intent IDs into the `IntentsByAccount` double map
+/// and records the number of intents of each account.
+pub mod v3 {
+    use super::v6::v5::IntentsByAccount;
+    use crate::{Config, IntentCountByAccount, IntentId, Pallet};
+    use frame_support::{
+        migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+    };
+    use sp_std::vec::Vec;
+
+    /// Storage layout of version 2
+    pub mod v2 {
+        use super::*;
+
+        /// Per-account intent cap before version 3
+        pub type MaxIntentsPerAccount = ConstU32<100>;
+
+        /// `IntentsByAccount` as stored before version 3
+        #[frame_support::storage_alias]
+        pub type IntentsByAccount<T: Config> = StorageMap<
+            Pallet<T>,
+            Blake2_128Concat,
+            <T as frame_system::Config>::AccountId,
+            BoundedVec<IntentId, MaxIntentsPerAccount>,
+            ValueQuery,
+        >;
+    }
+
+    /// Rewrites the per-account intent lists as double map entries
+    ///
+    /// Both layouts share the storage prefix, so the old lists are drained before any new
+    /// entry is written. No deposit is held for intents created before version 3.
+    pub struct InnerMigrateV2ToV3<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV2ToV3<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let lists: Vec<_> = v2::IntentsByAccount::<T>::drain().collect();
+            let accounts = lists.len() as u64;
+            let mut intents = 0u64;
+
+            for (account, intent_ids) in lists {
+                for intent_id in intent_ids.iter() {
+                    IntentsByAccount::<T>::insert(&account, intent_id, ());
+                }
+                intents += intent_ids.len() as u64;
+                IntentCountByAccount::<T>::insert(&account, intent_ids.len() as u32);
+            }
+
+            T::DbWeight::get().reads_writes(accounts, accounts.saturating_mul(2) + intents)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+            let intents: u64 = v2::IntentsByAccount::<T>::iter_values()
+                .map(|intent_ids| intent_ids.len() as u64)
+                .sum();
+            Ok(intents.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+            let expected = u64::decode(&mut &state[..])
+                .map_err(|_| "Failed to decode pre-upgrade intent index size")?;
+
+            let migrated = IntentsByAccount::<T>::iter_keys().count() as u64;
+            ensure!(migrated == expected, "Intent index size changed during migration");
+
+            let counted: u64 = IntentCountByAccount::<T>::iter_values().map(u64::from).sum();
+            ensure!(counted == expected, "Intent counts do not match the index");
+
+            Ok(())
+        }
+    }
+
+    /// Migrates `IntentsByAccount` to version 3, only running while the on-chain version is 2
+    pub type MigrateV2ToV3<T> = VersionedMigration<
+        2,
+        3,
+        InnerMigrateV2ToV3<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migration to storage version 4
+///
+/// Moves every journey from a `BoundedVec` per intent into the `JourneySteps` double map and
+/// builds the hash chain of each journey in `JourneyHeads`.
+pub mod v4 {
+    use crate::{Config, IntentId, JourneyHeads, JourneyStep, JourneySteps, Pallet};
+    use frame_support::{
+        migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+    };
+    use frame_system::pallet_prelude::BlockNumberFor;
+
+    #[cfg(feature = "try-runtime")]
+    use sp_std::vec::Vec;
+
+    /// Storage layout of version 3
+    pub mod v3 {
+        use super::*;
+        use crate::{JourneyStepKind, LinkedTx};
+
+        /// `JourneyStep` as stored from version 2 to version 4
+        #[derive(Clone, Encode, Decode, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+        #[scale_info(skip_type_params(T))]
+        pub struct OldJourneyStep<T: Config> {
+            pub step_id: u32,
+            pub step_kind: JourneyStepKind,
+            pub description: BoundedVec<u8, ConstU32<512>>,
+            pub timestamp: BlockNumberFor<T>,
+            pub author: T::AccountId,
+            pub linked_tx: Option<LinkedTx>,
+            pub attachment: Option<BoundedVec<u8, ConstU32<128>>>,
+        }
+
+        impl<T: Config> OldJourneyStep<T> {
+            /// Convert to the current layout, truncating the description to `MaxDescriptionLen`
+            pub fn upgrade(self) -> JourneyStep<T> {
+                JourneyStep {
+                    step_id: self.step_id,
+                    step_kind: self.step_kind,
+                    description: BoundedVec::truncate_from(self.description.into_inner()),
+                    timestamp: self.timestamp,
+                    author: self.author,
+                    linked_tx: self.linked_tx,
+                    attachment: self.attachment,
+                }
+            }
+        }
+
+        /// `JourneyByIntent` as stored from version 2 to version 3
+        #[frame_support::storage_alias]
+        pub type JourneyByIntent<T: Config> = StorageMap<
+            Pallet<T>,
+            Blake2_128Concat,
+            IntentId,
+            BoundedVec<OldJourneyStep<T>, <T as Config>::MaxJourneyStepsPerIntent>,
+            ValueQuery,
+        >;
+    }
+
+    /// Rewrites every journey step as a double map entry, hashing the steps in order
+    ///
+    /// Step IDs are kept, as they always matched the position of the step in its journey.
+    pub struct InnerMigrateV3ToV4<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV3ToV4<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut journeys = 0u64;
+            let mut steps = 0u64;
+
+            for (intent_id, journey) in v3::JourneyByIntent::<T>::drain() {
+                journeys += 1;
+                steps += journey.len() as u64;
+                for step in journey {
+                    // Journeys were already bounded by `MaxJourneyStepsPerIntent`
+                    let _ = Pallet::<T>::append_journey_step(intent_id, step.upgrade());
+                }
+            }
+
+            T::DbWeight::get().reads_writes(
+                journeys.saturating_add(steps),
+                journeys.saturating_mul(2).saturating_add(steps),
+            )
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+            let steps: u64 = v3::JourneyByIntent::<T>::iter_values()
+                .map(|journey| journey.len() as u64)
+                .sum();
+            Ok(steps.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+            let expected = u64::decode(&mut &state[..])
+                .map_err(|_| "Failed to decode pre-upgrade journey step count")?;
+
+            let migrated = JourneySteps::<T>::iter_keys().count() as u64;
+            ensure!(migrated == expected, "Journey step count changed during migration");
+
+            let counted: u64 = JourneyHeads::<T>::iter_values()
+                .map(|head| u64::from(head.step_count))
+                .sum();
+            ensure!(counted == expected, "Journey heads do not match the steps");
+
+            Ok(())
+        }
+    }
+
+    /// Migrates `JourneyByIntent` to version 4, only running while the on-chain version is 3
+    pub type MigrateV3ToV4<T> = VersionedMigration<
+        3,
+        4,
+        InnerMigrateV3ToV4<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migration to storage version 5
+///
+/// Rebounds intent metadata to `MaxMetadataLen` and journey step descriptions to
+/// `MaxDescriptionLen`, which replace the fixed 256 and 512 byte bounds.
+pub mod v5 {
+    use super::v4::v3::OldJourneyStep;
+    use crate::{
+        Config, Intent, IntentById, IntentId, IntentKind, IntentStatus, JourneyHeads, JourneySteps,
+        Pallet, StepId,
+    };
+    use frame_support::{
+        migrations::VersionedMigration,
+        pallet_prelude::*,
+        sp_runtime::traits::Hash,
+        traits::UncheckedOnRuntimeUpgrade,
+    };
+    use frame_system::pallet_prelude::BlockNumberFor;
+    use sp_std::collections::btree_set::BTreeSet;
+
+    #[cfg(feature = "try-runtime")]
+    use sp_std::vec::Vec;
+
+    /// Storage layout of version 4
+    pub mod v4 {
+        use super::*;
+
+        /// `Intent` as stored from version 1 to version 4
+        #[derive(Clone, Encode, Decode, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+        #[scale_info(skip_type_params(T))]
+        pub struct OldIntent<T: Config> {
+            pub intent_id: IntentId,
+            pub account: T::AccountId,
+            pub status: IntentStatus,
+            pub timestamp: BlockNumberFor<T>,
+            pub metadata: BoundedVec<u8, ConstU32<256>>,
+            pub kind: Option<IntentKind<T::AccountId>>,
+        }
+
+        /// `IntentById` as stored before version 5
+        #[frame_support::storage_alias]
+        pub type IntentById<T: Config> =
+            StorageMap<Pallet<T>, Blake2_128Concat, IntentId, OldIntent<T>>;
+
+        /// `JourneySteps` as stored in version 4
+        #[frame_support::storage_alias]
+        pub type JourneySteps<T: Config> = StorageDoubleMap<
+            Pallet<T>,
+            Blake2_128Concat,
+            IntentId,
+            Twox64Concat,
+            StepId,
+            OldJourneyStep<T>,
+        >;
+    }
+
+    /// Rewrites every intent and journey step with the configured bounds
+    ///
+    /// Values longer than the new bounds are truncated. The hash chain of a journey with a
+    /// truncated step is rebuilt, so its head commits to the steps as now stored.
+    pub struct InnerMigrateV4ToV5<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV4ToV5<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut intents = 0u64;
+            IntentById::<T>::translate::<v4::OldIntent<T>, _>(|_, old| {
+                intents += 1;
+                Some(Intent {
+                    intent_id: old.intent_id,
+                    account: old.account,
+                    status: old.status,
+                    timestamp: old.timestamp,
+                    metadata: BoundedVec::truncate_from(old.metadata.into_inner()),
+                    kind: old.kind,
+                })
+            });
+
+            let max_description = T::MaxDescriptionLen::get() as usize;
+            let mut steps = 0u64;
+            let mut truncated = BTreeSet::new();
+            JourneySteps::<T>::translate::<OldJourneyStep<T>, _>(|intent_id, _, old| {
+                steps += 1;
+                if old.description.len() > max_description {
+                    truncated.insert(intent_id);
+                }
+                Some(old.upgrade())
+            });
+
+            let journeys = truncated.len() as u64;
+            let mut rehashed = 0u64;
+            for intent_id in truncated {
+                JourneyHeads::<T>::mutate(intent_id, |maybe_head| {
+                    if let Some(head) = maybe_head {
+                        rehashed += u64::from(head.step_count);
+                        head.hash = (0..head.step_count)
+                            .filter_map(|step_id| JourneySteps::<T>::get(intent_id, step_id))
+                            .fold(T::Hash::default(), |hash, step| {
+                                T::Hashing::hash_of(&(&hash, &step))
+                            });
+                    }
+                });
+            }
+
+            let rewritten = intents.saturating_add(steps);
+            T::DbWeight::get().reads_writes(
+                rewritten.saturating_add(journeys).saturating_add(rehashed),
+                rewritten.saturating_add(journeys),
+            )
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+            let intents = v4::IntentById::<T>::iter_keys().count() as u64;
+            let steps = v4::JourneySteps::<T>::iter_keys().count() as u64;
+            Ok((intents, steps).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+            let (intents, steps) = <(u64, u64)>::decode(&mut &state[..])
+                .map_err(|_| "Failed to decode pre-upgrade intent and step counts")?;
+
+            let migrated = IntentById::<T>::iter_values().count() as u64;
+            ensure!(migrated == intents, "Intent count changed during migration");
+
+            let migrated = JourneySteps::<T>::iter_values().count() as u64;
+            ensure!(migrated == steps, "Journey step count changed during migration");
+
+            Ok(())
+        }
+    }
+
+    /// Migrates intents and journey steps to version 5, only running while the on-chain
+    /// version is 4
+    pub type MigrateV4ToV5<T> = VersionedMigration<
+        4,
+        5,
+        InnerMigrateV4ToV5<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migration to storage version 6
+///
+/// Rekeys `IntentsByAccount` from `Twox64Concat` hashed intent IDs to unhashed big-endian
+/// ones, so that each account's intents iterate in creation order.
+pub mod v6 {
+    use crate::{intent_key, Config, IntentId, IntentsByAccount, Pallet};
+    use frame_support::{
+        migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+    };
+    use sp_std::vec::Vec;
+
+    /// Storage layout of version 5
+    pub mod v5 {
+        use super::*;
+
+        /// `IntentsByAccount` as stored from version 3 to version 6
+        #[frame_support::storage_alias]
+        pub type IntentsByAccount<T: Config> = StorageDoubleMap<
+            Pallet<T>,
+            Blake2_128Concat,
+            <T as frame_system::Config>::AccountId,
+            Twox64Concat,
+            IntentId,
+            (),
+            OptionQuery,
+        >;
+    }
+
+    /// Rewrites every index entry under its big-endian key
+    ///
+    /// Both layouts share the storage prefix, so the old entries are drained before any new
+    /// entry is written.
+    pub struct InnerMigrateV5ToV6<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV5ToV6<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let entries: Vec<_> = v5::IntentsByAccount::<T>::drain().collect();
+            let migrated = entries.len() as u64;
+
+            for (account, intent_id, ()) in entries {
+                IntentsByAccount::<T>::insert(&account, intent_key(intent_id), ());
+            }
+
+            T::DbWeight::get().reads_writes(migrated, migrated.saturating_mul(2))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+            let entries = v5::IntentsByAccount::<T>::iter_keys().count() as u64;
+            Ok(entries.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+            let expected = u64::decode(&mut &state[..])
+                .map_err(|_| "Failed to decode pre-upgrade intent index size")?;
+
+            let migrated = IntentsByAccount::<T>::iter_keys().count() as u64;
+            ensure!(migrated == expected, "Intent index size changed during migration");
+
+            Ok(())
+        }
+    }
+
+    /// Migrates `IntentsByAccount` to version 6, only running while the on-chain version is 5
+    pub type MigrateV5ToV6<T> = VersionedMigration<
+        5,
+        6,
+        InnerMigrateV5ToV6<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
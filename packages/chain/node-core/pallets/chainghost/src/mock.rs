@@ -1,6 +1,8 @@
 use crate as pallet_chainghost;
-use frame_support::{derive_impl, parameter_types};
-use frame_support::sp_runtime::BuildStorage;
+use crate::{Achievement, AchievementKind, IntentKind, TransferIntent};
+use frame_support::{derive_impl, parameter_types, traits::Hooks};
+use frame_support::sp_runtime::{BuildStorage, Perbill};
+use frame_system::{pallet_prelude::BlockNumberFor, EnsureRoot};
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -27,29 +29,116 @@ mod runtime {
 
     #[runtime::pallet_index(1)]
     pub type ChainGhost = pallet_chainghost::Pallet<Test>;
+
+    #[runtime::pallet_index(2)]
+    pub type Balances = pallet_balances::Pallet<Test>;
 }
 
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
 impl frame_system::Config for Test {
     type Block = Block;
+    type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = System;
 }
 
 parameter_types! {
-    pub const MaxIntentsPerAccount: u32 = 100;
     pub const MaxJourneyStepsPerIntent: u32 = 50;
+    // Mutable so that migration tests can lower the bounds of stored values
+    pub static MaxMetadataLen: u32 = 256;
+    pub static MaxDescriptionLen: u32 = 512;
+    pub const MaxCallsPerIntent: u32 = 4;
+    pub const SolverBond: u64 = 100;
+    pub const ClaimTimeout: BlockNumberFor<Test> = 10;
+    pub const ClaimSlash: Perbill = Perbill::from_percent(50);
+    pub const MaxActiveClaims: u32 = 2;
+    pub const DisputePeriod: BlockNumberFor<Test> = 5;
+    pub const MaxDelegates: u32 = 2;
+    // No deposit by default, so that tests checking held balances only see rewards and bonds
+    pub static IntentDeposit: u64 = 0;
+    pub static TemplateDeposit: u64 = 0;
+    pub static RecurringDeposit: u64 = 0;
+    pub const MaxChainRefsPerIntent: u32 = 2;
+    pub AchievementThresholds: Vec<Achievement> = vec![
+        Achievement { kind: AchievementKind::IntentsExecuted, threshold: 1 },
+        Achievement { kind: AchievementKind::IntentsExecuted, threshold: 3 },
+        Achievement { kind: AchievementKind::SuccessRatio, threshold: 50 },
+        Achievement { kind: AchievementKind::DistinctKinds, threshold: 2 },
+        Achievement { kind: AchievementKind::JourneySteps, threshold: 3 },
+    ];
+    pub const MinResolvedForSuccessRatio: u32 = 2;
+    pub const MaxTemplatesPerAccount: u32 = 2;
+    pub const MaxRecurringPerAccount: u32 = 2;
+    pub const MaxInstantiationsPerBlock: u32 = 2;
 }
 
 impl pallet_chainghost::Config for Test {
     type RuntimeEvent = RuntimeEvent;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type Currency = Balances;
     type WeightInfo = ();
-    type MaxIntentsPerAccount = MaxIntentsPerAccount;
+    type IntentDeposit = IntentDeposit;
+    type TemplateDeposit = TemplateDeposit;
+    type RecurringDeposit = RecurringDeposit;
     type MaxJourneyStepsPerIntent = MaxJourneyStepsPerIntent;
+    type MaxMetadataLen = MaxMetadataLen;
+    type MaxDescriptionLen = MaxDescriptionLen;
+    type RuntimeCall = RuntimeCall;
+    type MaxCallsPerIntent = MaxCallsPerIntent;
+    type SolverBond = SolverBond;
+    type ClaimTimeout = ClaimTimeout;
+    type ClaimSlash = ClaimSlash;
+    type MaxActiveClaims = MaxActiveClaims;
+    type DisputePeriod = DisputePeriod;
+    type MaxDelegates = MaxDelegates;
+    type MaxChainRefsPerIntent = MaxChainRefsPerIntent;
+    type RelayerAdminOrigin = EnsureRoot<u64>;
+    type AchievementThresholds = AchievementThresholds;
+    type MinResolvedForSuccessRatio = MinResolvedForSuccessRatio;
+    type MaxTemplatesPerAccount = MaxTemplatesPerAccount;
+    type MaxRecurringPerAccount = MaxRecurringPerAccount;
+    type MaxInstantiationsPerBlock = MaxInstantiationsPerBlock;
 }
 
+/// Accounts 1 to 4 are endowed at genesis
+pub const ENDOWED_BALANCE: u64 = 1_000;
+
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-    frame_system::GenesisConfig::<Test>::default()
+    let mut t = frame_system::GenesisConfig::<Test>::default()
         .build_storage()
-        .unwrap()
-        .into()
+        .unwrap();
+
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![
+            (1, ENDOWED_BALANCE),
+            (2, ENDOWED_BALANCE),
+            (3, ENDOWED_BALANCE),
+            (4, ENDOWED_BALANCE),
+        ],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    t.into()
+}
+
+/// Advance to block `n`, running the ChainGhost block hook of each block on the way
+pub fn run_to_block(n: BlockNumberFor<Test>) {
+    while System::block_number() < n {
+        System::set_block_number(System::block_number() + 1);
+        ChainGhost::on_initialize(System::block_number());
+    }
+}
+
+// A valid transfer payload for tests that do not care about the intent kind.
+pub fn transfer_intent() -> IntentKind<u64> {
+    IntentKind::Transfer(TransferIntent {
+        dest: 2,
+        asset: 0,
+        amount: 100,
+    })
 }
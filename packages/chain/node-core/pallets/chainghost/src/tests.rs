@@ -1,5 +1,14 @@
-use crate::{mock::*, Error, Event, IntentStatus};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+    mock::*, Achievement, AchievementKind, BridgeIntent, CancelReason, ContractCallIntent,
+    DelegateScope,
+    Delegation, Error, Event, ExecutionError, IntentAction, IntentKind, IntentStatus,
+    JourneyStepKind, LinkedTx, RecurringIntent, SwapIntent, TransferIntent,
+};
+use frame_support::{
+    assert_noop, assert_ok,
+    sp_runtime::{DispatchError, TokenError},
+    traits::fungible::{Inspect, InspectHold},
+};
 
 #[test]
 fn execute_intent_works() {
@@ -11,7 +20,9 @@ fn execute_intent_works() {
         // Execute intent
         assert_ok!(ChainGhost::execute_intent(
             RuntimeOrigin::signed(1),
-            metadata.clone()
+            transfer_intent(),
+            metadata.clone(),
+            Vec::new()
         ));
 
         // Verify intent was created
@@ -20,11 +31,11 @@ fn execute_intent_works() {
         assert_eq!(intent.account, 1);
         assert_eq!(intent.status, IntentStatus::Pending);
         assert_eq!(intent.metadata.to_vec(), metadata);
+        assert_eq!(intent.kind, Some(transfer_intent()));
 
-        // Verify intent was added to account's list
-        let account_intents = ChainGhost::intents_by_account(1);
-        assert_eq!(account_intents.len(), 1);
-        assert_eq!(account_intents[0], 0);
+        // Verify intent was added to account's index
+        assert_eq!(ChainGhost::intents_of(&1, None, 10), vec![0]);
+        assert_eq!(ChainGhost::intent_count(1), 1);
 
         // Verify next intent ID was incremented
         assert_eq!(ChainGhost::next_intent_id(), 1);
@@ -49,13 +60,17 @@ fn execute_multiple_intents_works() {
         // Execute first intent
         assert_ok!(ChainGhost::execute_intent(
             RuntimeOrigin::signed(1),
-            b"First intent".to_vec()
+            transfer_intent(),
+            b"First intent".to_vec(),
+            Vec::new()
         ));
 
         // Execute second intent
         assert_ok!(ChainGhost::execute_intent(
             RuntimeOrigin::signed(1),
-            b"Second intent".to_vec()
+            transfer_intent(),
+            b"Second intent".to_vec(),
+            Vec::new()
         ));
 
         // Verify both intents were created
@@ -63,34 +78,282 @@ fn execute_multiple_intents_works() {
         assert!(ChainGhost::intent_by_id(1).is_some());
 
         // Verify account has both intents
-        let account_intents = ChainGhost::intents_by_account(1);
-        assert_eq!(account_intents.len(), 2);
-        assert_eq!(account_intents[0], 0);
-        assert_eq!(account_intents[1], 1);
+        let mut account_intents = ChainGhost::intents_of(&1, None, 10);
+        account_intents.sort();
+        assert_eq!(account_intents, vec![0, 1]);
+        assert_eq!(ChainGhost::intent_count(1), 2);
     });
 }
 
 #[test]
-fn execute_intent_max_intents_per_account_fails() {
+fn intents_by_account_is_paginated() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
 
-        // Fill up account's intent slots
-        for i in 0..100 {
+        // There is no per-account cap anymore
+        for i in 0..150 {
             assert_ok!(ChainGhost::execute_intent(
                 RuntimeOrigin::signed(1),
-                format!("Intent {}", i).as_bytes().to_vec()
+                transfer_intent(),
+                format!("Intent {}", i).as_bytes().to_vec(),
+                Vec::new()
             ));
         }
+        assert_eq!(ChainGhost::intent_count(1), 150);
+
+        // Walk the index in pages, each starting after the last intent of the previous one
+        let mut seen = Vec::new();
+        let mut cursor = None;
+        loop {
+            let page = ChainGhost::intents_of(&1, cursor, 40);
+            assert!(page.len() <= 40);
+            match page.last() {
+                Some(last) => cursor = Some(*last),
+                None => break,
+            }
+            seen.extend(page);
+        }
+
+        // The index iterates in creation order
+        assert_eq!(seen, (0..150).collect::<Vec<_>>());
+        assert!(ChainGhost::intents_of(&2, None, 40).is_empty());
+    });
+}
+
+#[test]
+fn latest_intents_are_read_from_the_end_of_the_index() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        // Account 2's intents are spread among many of account 1
+        let mut expected = Vec::new();
+        for i in 0..100 {
+            if i % 30 == 0 {
+                expected.push(create_intent(2));
+            }
+            create_intent(1);
+        }
+        expected.reverse();
+
+        assert_eq!(ChainGhost::latest_intents_of(&1, 3), vec![103, 102, 101]);
+        assert_eq!(ChainGhost::latest_intents_of(&2, 3), expected[..3].to_vec());
+        assert_eq!(ChainGhost::latest_intents_of(&2, 10), expected);
+        assert!(ChainGhost::latest_intents_of(&2, 0).is_empty());
+        assert!(ChainGhost::latest_intents_of(&3, 10).is_empty());
+    });
+}
+
+#[test]
+fn intent_deposit_is_held_until_removal() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        IntentDeposit::set(10);
+
+        let intent_id = create_intent(OWNER);
+        assert_eq!(Balances::total_balance_on_hold(&OWNER), 10);
+        assert_eq!(ChainGhost::intent_deposit(intent_id), 10);
+        assert_ok!(record_note(OWNER, intent_id));
+
+        // Only resolved intents can be removed, by their owner
+        assert_noop!(
+            ChainGhost::remove_intent(RuntimeOrigin::signed(OWNER), intent_id),
+            Error::<Test>::InvalidIntentStatus
+        );
+        assert_ok!(ChainGhost::update_intent_status(RuntimeOrigin::signed(OWNER), intent_id, 1));
+        assert_noop!(
+            ChainGhost::remove_intent(RuntimeOrigin::signed(2), intent_id),
+            Error::<Test>::NotIntentOwner
+        );
+
+        assert_ok!(ChainGhost::remove_intent(RuntimeOrigin::signed(OWNER), intent_id));
+        assert_eq!(Balances::total_balance_on_hold(&OWNER), 0);
+        assert!(ChainGhost::intent_by_id(intent_id).is_none());
+        assert!(ChainGhost::journey_by_intent(intent_id).is_empty());
+        assert!(ChainGhost::intents_of(&OWNER, None, 10).is_empty());
+        assert_eq!(ChainGhost::intent_count(OWNER), 0);
+        System::assert_last_event(
+            Event::IntentRemoved {
+                intent_id,
+                account: OWNER,
+            }
+            .into(),
+        );
+
+        // Persona counters keep the removed intent's history
+        assert_eq!(ChainGhost::persona(OWNER).intents_created, 1);
+
+        // Intents cannot be created without funds for the deposit
+        IntentDeposit::set(ENDOWED_BALANCE + 1);
+        assert_noop!(
+            ChainGhost::execute_intent(
+                RuntimeOrigin::signed(OWNER),
+                transfer_intent(),
+                Vec::new(),
+                Vec::new()
+            ),
+            TokenError::FundsUnavailable
+        );
+    });
+}
+
+#[test]
+fn journey_steps_extend_hash_chain() {
+    use frame_support::sp_runtime::traits::{BlakeTwo256, Hash};
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let intent_id = create_intent(OWNER);
+        assert_eq!(ChainGhost::journey_head(intent_id), None);
+
+        assert_ok!(record_note(OWNER, intent_id));
+        assert_ok!(record_note(OWNER, intent_id));
+        let steps = ChainGhost::journey_by_intent(intent_id);
+        assert_eq!(steps.len(), 2);
+        assert!(ChainGhost::journey_step(intent_id, 1) == Some(steps[1].clone()));
+
+        // Each step commits to the hash of the steps before it
+        let first = BlakeTwo256::hash_of(&(&sp_core::H256::zero(), &steps[0]));
+        let second = BlakeTwo256::hash_of(&(&first, &steps[1]));
+        let head = ChainGhost::journey_head(intent_id).expect("Journey should have a head");
+        assert_eq!(head.step_count, 2);
+        assert_eq!(head.hash, second);
+
+        // Journeys of other intents have their own chain
+        let other = create_intent(OWNER);
+        assert_ok!(record_note(OWNER, other));
+        assert_eq!(ChainGhost::journey_head(other).unwrap().step_count, 1);
+        assert_eq!(ChainGhost::journey_head(intent_id).unwrap().hash, second);
+    });
+}
+
+#[test]
+fn cancel_intent_refunds_reward_and_keeps_deposit() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        IntentDeposit::set(10);
+
+        let intent_id = create_intent(OWNER);
+        assert_ok!(ChainGhost::offer_reward(RuntimeOrigin::signed(OWNER), intent_id, 200));
+        assert_eq!(Balances::total_balance_on_hold(&OWNER), 210);
+
+        assert_noop!(
+            ChainGhost::cancel_intent(
+                RuntimeOrigin::signed(2),
+                intent_id,
+                CancelReason::UserRequested
+            ),
+            Error::<Test>::NotIntentOwner
+        );
+
+        assert_ok!(ChainGhost::cancel_intent(
+            RuntimeOrigin::signed(OWNER),
+            intent_id,
+            CancelReason::Superseded(7)
+        ));
+        assert_eq!(ChainGhost::intent_by_id(intent_id).unwrap().status, IntentStatus::Cancelled);
+        assert_eq!(ChainGhost::cancel_reason(intent_id), Some(CancelReason::Superseded(7)));
+        // The deposit covers the cancelled intent until it is removed
+        assert_eq!(Balances::total_balance_on_hold(&OWNER), 10);
+        assert_eq!(ChainGhost::intent_deposit(intent_id), 10);
+        assert_eq!(Balances::balance(&OWNER), ENDOWED_BALANCE - 10);
+        System::assert_has_event(
+            Event::IntentCancelled {
+                intent_id,
+                reason: CancelReason::Superseded(7),
+            }
+            .into(),
+        );
+        System::assert_last_event(
+            Event::IntentStatusUpdated {
+                intent_id,
+                old_status: 0,
+                new_status: 3,
+            }
+            .into(),
+        );
+
+        // Cancellation is final
+        assert_noop!(
+            ChainGhost::cancel_intent(
+                RuntimeOrigin::signed(OWNER),
+                intent_id,
+                CancelReason::UserRequested
+            ),
+            Error::<Test>::InvalidIntentStatus
+        );
+        assert_noop!(
+            ChainGhost::update_intent_status(RuntimeOrigin::signed(OWNER), intent_id, 1),
+            Error::<Test>::InvalidIntentStatus
+        );
+
+        // Cancelled intents can be removed like resolved ones
+        assert_ok!(ChainGhost::remove_intent(RuntimeOrigin::signed(OWNER), intent_id));
+        assert_eq!(ChainGhost::cancel_reason(intent_id), None);
+        assert_eq!(Balances::total_balance_on_hold(&OWNER), 0);
+        assert_eq!(Balances::balance(&OWNER), ENDOWED_BALANCE);
+    });
+}
+
+#[test]
+fn cancel_intent_is_rejected_outside_pending() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        // Cancelling only goes through `cancel_intent`, with a reason
+        let intent_id = create_intent(OWNER);
+        assert_noop!(
+            ChainGhost::update_intent_status(RuntimeOrigin::signed(OWNER), intent_id, 3),
+            Error::<Test>::InvalidIntentStatus
+        );
+
+        // Executed and failed intents are terminal
+        assert_ok!(ChainGhost::update_intent_status(RuntimeOrigin::signed(OWNER), intent_id, 2));
+        assert_noop!(
+            ChainGhost::cancel_intent(
+                RuntimeOrigin::signed(OWNER),
+                intent_id,
+                CancelReason::MarketMoved
+            ),
+            Error::<Test>::InvalidIntentStatus
+        );
 
-        // Verify account has 100 intents
-        let account_intents = ChainGhost::intents_by_account(1);
-        assert_eq!(account_intents.len(), 100);
+        // Claimed intents are left to their solver
+        let claimed = create_intent(OWNER);
+        assert_ok!(ChainGhost::offer_reward(RuntimeOrigin::signed(OWNER), claimed, 200));
+        assert_ok!(ChainGhost::register_solver(RuntimeOrigin::signed(SOLVER)));
+        assert_ok!(ChainGhost::claim_intent(RuntimeOrigin::signed(SOLVER), claimed));
+        assert_noop!(
+            ChainGhost::cancel_intent(
+                RuntimeOrigin::signed(OWNER),
+                claimed,
+                CancelReason::Expired
+            ),
+            Error::<Test>::IntentAlreadyClaimed
+        );
+
+        // Delegates with the `UpdateStatus` scope may cancel for the owner
+        let delegated = create_intent(OWNER);
+        assert_ok!(ChainGhost::add_delegate(
+            RuntimeOrigin::signed(OWNER),
+            BACKEND,
+            DelegateScope::UpdateStatus,
+            None
+        ));
+        assert_ok!(ChainGhost::cancel_intent(
+            RuntimeOrigin::signed(BACKEND),
+            delegated,
+            CancelReason::InsufficientFunds
+        ));
+        assert_eq!(ChainGhost::intent_by_id(delegated).unwrap().status, IntentStatus::Cancelled);
 
-        // Try to add one more intent (should fail)
         assert_noop!(
-            ChainGhost::execute_intent(RuntimeOrigin::signed(1), b"Extra intent".to_vec()),
-            Error::<Test>::MaxIntentsPerAccountExceeded
+            ChainGhost::cancel_intent(
+                RuntimeOrigin::signed(OWNER),
+                99,
+                CancelReason::UserRequested
+            ),
+            Error::<Test>::IntentNotFound
         );
     });
 }
@@ -103,7 +366,9 @@ fn record_journey_works() {
         // Create intent first
         assert_ok!(ChainGhost::execute_intent(
             RuntimeOrigin::signed(1),
-            b"Test intent".to_vec()
+            transfer_intent(),
+            b"Test intent".to_vec(),
+            Vec::new()
         ));
 
         System::set_block_number(2);
@@ -113,15 +378,20 @@ fn record_journey_works() {
         assert_ok!(ChainGhost::record_journey(
             RuntimeOrigin::signed(1),
             0,
-            description.clone()
+            JourneyStepKind::Note,
+            description.clone(),
+            None,
+            None
         ));
 
         // Verify journey step was recorded
         let journey_steps = ChainGhost::journey_by_intent(0);
         assert_eq!(journey_steps.len(), 1);
         assert_eq!(journey_steps[0].step_id, 0);
+        assert_eq!(journey_steps[0].step_kind, JourneyStepKind::Note);
         assert_eq!(journey_steps[0].description.to_vec(), description);
         assert_eq!(journey_steps[0].timestamp, 2);
+        assert_eq!(journey_steps[0].author, 1);
 
         // Verify event was emitted
         System::assert_last_event(
@@ -142,7 +412,9 @@ fn record_multiple_journey_steps_works() {
         // Create intent
         assert_ok!(ChainGhost::execute_intent(
             RuntimeOrigin::signed(1),
-            b"Test intent".to_vec()
+            transfer_intent(),
+            b"Test intent".to_vec(),
+            Vec::new()
         ));
 
         // Record multiple journey steps
@@ -151,7 +423,10 @@ fn record_multiple_journey_steps_works() {
             assert_ok!(ChainGhost::record_journey(
                 RuntimeOrigin::signed(1),
                 0,
-                format!("Step {}", i).as_bytes().to_vec()
+                JourneyStepKind::Note,
+                format!("Step {}", i).as_bytes().to_vec(),
+                None,
+                None
             ));
         }
 
@@ -173,7 +448,14 @@ fn record_journey_intent_not_found_fails() {
 
         // Try to record journey for non-existent intent
         assert_noop!(
-            ChainGhost::record_journey(RuntimeOrigin::signed(1), 999, b"Step 1".to_vec()),
+            ChainGhost::record_journey(
+                RuntimeOrigin::signed(1),
+                999,
+                JourneyStepKind::Note,
+                b"Step 1".to_vec(),
+                None,
+                None,
+            ),
             Error::<Test>::IntentNotFound
         );
     });
@@ -187,12 +469,21 @@ fn record_journey_not_owner_fails() {
         // Account 1 creates intent
         assert_ok!(ChainGhost::execute_intent(
             RuntimeOrigin::signed(1),
-            b"Test intent".to_vec()
+            transfer_intent(),
+            b"Test intent".to_vec(),
+            Vec::new()
         ));
 
         // Account 2 tries to record journey (should fail)
         assert_noop!(
-            ChainGhost::record_journey(RuntimeOrigin::signed(2), 0, b"Step 1".to_vec()),
+            ChainGhost::record_journey(
+                RuntimeOrigin::signed(2),
+                0,
+                JourneyStepKind::Note,
+                b"Step 1".to_vec(),
+                None,
+                None,
+            ),
             Error::<Test>::NotIntentOwner
         );
     });
@@ -206,7 +497,9 @@ fn record_journey_max_steps_exceeded_fails() {
         // Create intent
         assert_ok!(ChainGhost::execute_intent(
             RuntimeOrigin::signed(1),
-            b"Test intent".to_vec()
+            transfer_intent(),
+            b"Test intent".to_vec(),
+            Vec::new()
         ));
 
         // Fill up journey steps (max is 50)
@@ -214,7 +507,10 @@ fn record_journey_max_steps_exceeded_fails() {
             assert_ok!(ChainGhost::record_journey(
                 RuntimeOrigin::signed(1),
                 0,
-                format!("Step {}", i).as_bytes().to_vec()
+                JourneyStepKind::Note,
+                format!("Step {}", i).as_bytes().to_vec(),
+                None,
+                None
             ));
         }
 
@@ -224,7 +520,14 @@ fn record_journey_max_steps_exceeded_fails() {
 
         // Try to add one more step (should fail)
         assert_noop!(
-            ChainGhost::record_journey(RuntimeOrigin::signed(1), 0, b"Extra step".to_vec()),
+            ChainGhost::record_journey(
+                RuntimeOrigin::signed(1),
+                0,
+                JourneyStepKind::Note,
+                b"Extra step".to_vec(),
+                None,
+                None,
+            ),
             Error::<Test>::MaxJourneyStepsExceeded
         );
     });
@@ -238,7 +541,9 @@ fn update_intent_status_works() {
         // Create intent
         assert_ok!(ChainGhost::execute_intent(
             RuntimeOrigin::signed(1),
-            b"Test intent".to_vec()
+            transfer_intent(),
+            b"Test intent".to_vec(),
+            Vec::new()
         ));
 
         // Verify initial status is Pending
@@ -289,7 +594,9 @@ fn update_intent_status_not_owner_fails() {
         // Account 1 creates intent
         assert_ok!(ChainGhost::execute_intent(
             RuntimeOrigin::signed(1),
-            b"Test intent".to_vec()
+            transfer_intent(),
+            b"Test intent".to_vec(),
+            Vec::new()
         ));
 
         // Account 2 tries to update status (should fail)
@@ -308,7 +615,9 @@ fn update_intent_status_to_failed_works() {
         // Create intent
         assert_ok!(ChainGhost::execute_intent(
             RuntimeOrigin::signed(1),
-            b"Test intent".to_vec()
+            transfer_intent(),
+            b"Test intent".to_vec(),
+            Vec::new()
         ));
 
         // Update status to Failed
@@ -332,7 +641,9 @@ fn complete_workflow_execute_journey_status_works() {
         // Step 1: Execute intent
         assert_ok!(ChainGhost::execute_intent(
             RuntimeOrigin::signed(1),
-            b"Swap 100 USDC to ETH on Arbitrum".to_vec()
+            transfer_intent(),
+            b"Swap 100 USDC to ETH on Arbitrum".to_vec(),
+            Vec::new()
         ));
 
         // Step 2: Record journey steps
@@ -340,21 +651,30 @@ fn complete_workflow_execute_journey_status_works() {
         assert_ok!(ChainGhost::record_journey(
             RuntimeOrigin::signed(1),
             0,
-            b"Analyzing best routes...".to_vec()
+            JourneyStepKind::Note,
+            b"Analyzing best routes...".to_vec(),
+            None,
+            None
         ));
 
         System::set_block_number(3);
         assert_ok!(ChainGhost::record_journey(
             RuntimeOrigin::signed(1),
             0,
-            b"Route selected: Arbitrum via Uniswap V3".to_vec()
+            JourneyStepKind::Note,
+            b"Route selected: Arbitrum via Uniswap V3".to_vec(),
+            None,
+            None
         ));
 
         System::set_block_number(4);
         assert_ok!(ChainGhost::record_journey(
             RuntimeOrigin::signed(1),
             0,
-            b"Transaction submitted to mempool".to_vec()
+            JourneyStepKind::Note,
+            b"Transaction submitted to mempool".to_vec(),
+            None,
+            None
         ));
 
         // Step 3: Update status to Executed
@@ -375,49 +695,72 @@ fn complete_workflow_execute_journey_status_works() {
 }
 
 #[test]
-fn metadata_truncation_works() {
+fn metadata_too_long_is_rejected() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
 
-        // Create metadata larger than 256 bytes
-        let large_metadata = vec![b'A'; 300];
+        // Metadata larger than `MaxMetadataLen` is rejected without allocating an intent ID
+        assert_noop!(
+            ChainGhost::execute_intent(
+                RuntimeOrigin::signed(1),
+                transfer_intent(),
+                vec![b'A'; 257],
+                Vec::new()
+            ),
+            Error::<Test>::MetadataTooLong
+        );
+        assert_noop!(
+            ChainGhost::create_multi_step_intent(
+                RuntimeOrigin::signed(1),
+                vec![b'A'; 257],
+                vec![(swap_action(1), vec![])]
+            ),
+            Error::<Test>::MetadataTooLong
+        );
+        assert_eq!(ChainGhost::next_intent_id(), 0);
 
-        // Execute intent with large metadata
+        // Metadata of exactly `MaxMetadataLen` bytes is stored as is
         assert_ok!(ChainGhost::execute_intent(
             RuntimeOrigin::signed(1),
-            large_metadata.clone()
+            transfer_intent(),
+            vec![b'A'; 256],
+            Vec::new()
         ));
-
-        // Verify metadata was truncated to 256 bytes
-        let intent = ChainGhost::intent_by_id(0).unwrap();
-        assert_eq!(intent.metadata.len(), 256);
+        assert_eq!(ChainGhost::intent_by_id(0).unwrap().metadata.len(), 256);
     });
 }
 
 #[test]
-fn journey_description_truncation_works() {
+fn journey_description_too_long_is_rejected() {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
 
-        // Create intent
-        assert_ok!(ChainGhost::execute_intent(
-            RuntimeOrigin::signed(1),
-            b"Test intent".to_vec()
-        ));
+        let intent_id = create_intent(OWNER);
 
-        // Create description larger than 512 bytes
-        let large_description = vec![b'B'; 600];
+        // Descriptions larger than `MaxDescriptionLen` are rejected
+        assert_noop!(
+            ChainGhost::record_journey(
+                RuntimeOrigin::signed(OWNER),
+                intent_id,
+                JourneyStepKind::Note,
+                vec![b'B'; 513],
+                None,
+                None
+            ),
+            Error::<Test>::DescriptionTooLong
+        );
+        assert!(ChainGhost::journey_by_intent(intent_id).is_empty());
 
-        // Record journey with large description
+        // Descriptions of exactly `MaxDescriptionLen` bytes are stored as is
         assert_ok!(ChainGhost::record_journey(
-            RuntimeOrigin::signed(1),
-            0,
-            large_description.clone()
+            RuntimeOrigin::signed(OWNER),
+            intent_id,
+            JourneyStepKind::Note,
+            vec![b'B'; 512],
+            None,
+            None
         ));
-
-        // Verify description was truncated to 512 bytes
-        let journey_steps = ChainGhost::journey_by_intent(0);
-        assert_eq!(journey_steps[0].description.len(), 512);
+        assert_eq!(ChainGhost::journey_by_intent(intent_id)[0].description.len(), 512);
     });
 }
 
@@ -429,26 +772,32 @@ fn multiple_accounts_independent_intents_works() {
         // Account 1 creates intents
         assert_ok!(ChainGhost::execute_intent(
             RuntimeOrigin::signed(1),
-            b"Account 1 intent 1".to_vec()
+            transfer_intent(),
+            b"Account 1 intent 1".to_vec(),
+            Vec::new()
         ));
         assert_ok!(ChainGhost::execute_intent(
             RuntimeOrigin::signed(1),
-            b"Account 1 intent 2".to_vec()
+            transfer_intent(),
+            b"Account 1 intent 2".to_vec(),
+            Vec::new()
         ));
 
         // Account 2 creates intents
         assert_ok!(ChainGhost::execute_intent(
             RuntimeOrigin::signed(2),
-            b"Account 2 intent 1".to_vec()
+            transfer_intent(),
+            b"Account 2 intent 1".to_vec(),
+            Vec::new()
         ));
 
         // Verify account 1 has 2 intents
-        let account1_intents = ChainGhost::intents_by_account(1);
-        assert_eq!(account1_intents.len(), 2);
+        assert_eq!(ChainGhost::intent_count(1), 2);
+        assert_eq!(ChainGhost::intents_of(&1, None, 10).len(), 2);
 
         // Verify account 2 has 1 intent
-        let account2_intents = ChainGhost::intents_by_account(2);
-        assert_eq!(account2_intents.len(), 1);
+        assert_eq!(ChainGhost::intent_count(2), 1);
+        assert_eq!(ChainGhost::intents_of(&2, None, 10), vec![2]);
 
         // Verify intent ownership
         let intent0 = ChainGhost::intent_by_id(0).unwrap();
@@ -458,3 +807,2040 @@ fn multiple_accounts_independent_intents_works() {
         assert_eq!(intent2.account, 2);
     });
 }
+
+#[test]
+fn execute_intent_stores_each_kind() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let kinds = vec![
+            transfer_intent(),
+            IntentKind::Swap(SwapIntent {
+                asset_in: 0,
+                asset_out: 1,
+                amount_in: 100,
+                min_amount_out: 95,
+            }),
+            IntentKind::Bridge(BridgeIntent {
+                asset: 0,
+                amount: 100,
+                dest_chain: 42161,
+                recipient: vec![0xab; 20].try_into().unwrap(),
+            }),
+            IntentKind::ContractCall(ContractCallIntent {
+                contract: [0x11; 20],
+                value: 0,
+                input: vec![0xa9, 0x05, 0x9c, 0xbb].try_into().unwrap(),
+            }),
+            IntentKind::Batch(
+                vec![
+                    IntentAction::Swap(SwapIntent {
+                        asset_in: 0,
+                        asset_out: 1,
+                        amount_in: 100,
+                        min_amount_out: 95,
+                    }),
+                    IntentAction::Transfer(TransferIntent {
+                        dest: 2,
+                        asset: 1,
+                        amount: 95,
+                    }),
+                ]
+                .try_into()
+                .unwrap(),
+            ),
+        ];
+
+        for (id, kind) in kinds.into_iter().enumerate() {
+            assert_ok!(ChainGhost::execute_intent(
+                RuntimeOrigin::signed(1),
+                kind.clone(),
+                Vec::new(),
+                Vec::new()
+            ));
+            let intent = ChainGhost::intent_by_id(id as u64).expect("Intent should exist");
+            assert_eq!(intent.kind, Some(kind));
+        }
+    });
+}
+
+#[test]
+fn execute_intent_rejects_invalid_kinds() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let cases = vec![
+            (
+                IntentKind::Transfer(TransferIntent {
+                    dest: 2,
+                    asset: 0,
+                    amount: 0,
+                }),
+                Error::<Test>::ZeroAmount,
+            ),
+            (
+                IntentKind::Swap(SwapIntent {
+                    asset_in: 1,
+                    asset_out: 1,
+                    amount_in: 100,
+                    min_amount_out: 0,
+                }),
+                Error::<Test>::SameAssetSwap,
+            ),
+            (
+                IntentKind::Bridge(BridgeIntent {
+                    asset: 0,
+                    amount: 100,
+                    dest_chain: 1,
+                    recipient: Default::default(),
+                }),
+                Error::<Test>::EmptyBridgeRecipient,
+            ),
+            (
+                IntentKind::ContractCall(ContractCallIntent {
+                    contract: [0; 20],
+                    value: 1,
+                    input: Default::default(),
+                }),
+                Error::<Test>::InvalidContractAddress,
+            ),
+            (
+                IntentKind::ContractCall(ContractCallIntent {
+                    contract: [0x11; 20],
+                    value: 0,
+                    input: Default::default(),
+                }),
+                Error::<Test>::EmptyContractCall,
+            ),
+            (IntentKind::Batch(Default::default()), Error::<Test>::EmptyBatch),
+            (
+                IntentKind::Batch(
+                    vec![IntentAction::Swap(SwapIntent {
+                        asset_in: 0,
+                        asset_out: 1,
+                        amount_in: 0,
+                        min_amount_out: 0,
+                    })]
+                    .try_into()
+                    .unwrap(),
+                ),
+                Error::<Test>::ZeroAmount,
+            ),
+        ];
+
+        for (kind, error) in cases {
+            assert_noop!(
+                ChainGhost::execute_intent(
+                    RuntimeOrigin::signed(1),
+                    kind,
+                    Vec::new(),
+                    Vec::new()
+                ),
+                error
+            );
+        }
+
+        // Rejected payloads do not consume intent IDs
+        assert_eq!(ChainGhost::next_intent_id(), 0);
+    });
+}
+
+#[test]
+fn migration_v0_to_v1_adds_empty_kind() {
+    use crate::migrations::v1::{v0, MigrateV0ToV1};
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(0).put::<ChainGhost>();
+
+        v0::IntentById::<Test>::insert(
+            7,
+            v0::OldIntent::<Test> {
+                intent_id: 7,
+                account: 1,
+                status: IntentStatus::Executed,
+                timestamp: 3,
+                metadata: b"legacy".to_vec().try_into().unwrap(),
+            },
+        );
+
+        MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+        let intent = ChainGhost::intent_by_id(7).expect("Intent should survive migration");
+        assert_eq!(intent.account, 1);
+        assert_eq!(intent.status, IntentStatus::Executed);
+        assert_eq!(intent.metadata.to_vec(), b"legacy".to_vec());
+        assert_eq!(intent.kind, None);
+        assert_eq!(ChainGhost::on_chain_storage_version(), 1);
+    });
+}
+
+#[test]
+fn migration_v1_to_v2_adds_step_fields() {
+    use crate::migrations::{
+        v2::{v1, MigrateV1ToV2},
+        v4::v3,
+    };
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(ChainGhost::execute_intent(
+            RuntimeOrigin::signed(1),
+            transfer_intent(),
+            b"legacy".to_vec(),
+            Vec::new()
+        ));
+
+        StorageVersion::new(1).put::<ChainGhost>();
+        v1::JourneyByIntent::<Test>::insert(
+            0,
+            frame_support::BoundedVec::truncate_from(vec![v1::OldJourneyStep::<Test> {
+                step_id: 0,
+                description: b"Bridged to Arbitrum".to_vec().try_into().unwrap(),
+                timestamp: 1,
+            }]),
+        );
+
+        MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+        let steps = v3::JourneyByIntent::<Test>::get(0);
+        assert_eq!(steps.len(), 1);
+        assert_eq!(steps[0].step_kind, JourneyStepKind::Note);
+        assert_eq!(steps[0].description.to_vec(), b"Bridged to Arbitrum".to_vec());
+        assert_eq!(steps[0].author, 1);
+        assert_eq!(steps[0].linked_tx, None);
+        assert_eq!(steps[0].attachment, None);
+        assert_eq!(ChainGhost::on_chain_storage_version(), 2);
+    });
+}
+
+#[test]
+fn migration_v2_to_v3_indexes_intents_by_account() {
+    use crate::migrations::{
+        v3::{v2, MigrateV2ToV3},
+        v6::v5,
+    };
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(2).put::<ChainGhost>();
+
+        v2::IntentsByAccount::<Test>::insert(
+            1,
+            frame_support::BoundedVec::truncate_from(vec![0, 2]),
+        );
+        v2::IntentsByAccount::<Test>::insert(2, frame_support::BoundedVec::truncate_from(vec![1]));
+
+        MigrateV2ToV3::<Test>::on_runtime_upgrade();
+
+        // Version 3 indexes intents under their hashed ID
+        let mut intents: Vec<_> = v5::IntentsByAccount::<Test>::iter_key_prefix(1).collect();
+        intents.sort();
+        assert_eq!(intents, vec![0, 2]);
+        assert_eq!(ChainGhost::intent_count(1), 2);
+        assert_eq!(v5::IntentsByAccount::<Test>::iter_key_prefix(2).collect::<Vec<_>>(), vec![1]);
+        assert_eq!(ChainGhost::intent_count(2), 1);
+        assert_eq!(ChainGhost::on_chain_storage_version(), 3);
+    });
+}
+
+#[test]
+fn migration_v3_to_v4_hashes_journey_steps() {
+    use crate::migrations::v4::{v3, MigrateV3ToV4};
+    use codec::{Decode, Encode};
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let intent_id = create_intent(OWNER);
+        assert_ok!(record_note(OWNER, intent_id));
+        assert_ok!(record_note(OWNER, intent_id));
+        let steps = ChainGhost::journey_by_intent(intent_id);
+        let head = ChainGhost::journey_head(intent_id);
+
+        // Rewind to the version 3 layout, which encodes steps the same way
+        let _ = crate::JourneySteps::<Test>::clear_prefix(intent_id, u32::MAX, None);
+        crate::JourneyHeads::<Test>::remove(intent_id);
+        let old_steps: Vec<_> = steps
+            .iter()
+            .map(|step| v3::OldJourneyStep::<Test>::decode(&mut &step.encode()[..]).unwrap())
+            .collect();
+        v3::JourneyByIntent::<Test>::insert(
+            intent_id,
+            frame_support::BoundedVec::truncate_from(old_steps),
+        );
+        StorageVersion::new(3).put::<ChainGhost>();
+
+        MigrateV3ToV4::<Test>::on_runtime_upgrade();
+
+        assert!(ChainGhost::journey_by_intent(intent_id) == steps);
+        assert_eq!(ChainGhost::journey_head(intent_id), head);
+        assert!(!v3::JourneyByIntent::<Test>::contains_key(intent_id));
+        assert_eq!(ChainGhost::on_chain_storage_version(), 4);
+    });
+}
+
+#[test]
+fn migration_v4_to_v5_applies_configured_bounds() {
+    use crate::{migrations::v5::MigrateV4ToV5, JourneyHead};
+    use frame_support::{
+        sp_runtime::traits::{BlakeTwo256, Hash},
+        traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+    };
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let intent_id = ChainGhost::next_intent_id();
+        assert_ok!(ChainGhost::execute_intent(
+            RuntimeOrigin::signed(OWNER),
+            transfer_intent(),
+            b"legacy".to_vec(),
+            Vec::new()
+        ));
+        assert_ok!(ChainGhost::record_journey(
+            RuntimeOrigin::signed(OWNER),
+            intent_id,
+            JourneyStepKind::Note,
+            vec![b'B'; 40],
+            None,
+            None
+        ));
+        assert_ok!(record_note(OWNER, intent_id));
+        let untouched = create_intent(OWNER);
+        assert_ok!(record_note(OWNER, untouched));
+        let untouched_head = ChainGhost::journey_head(untouched);
+
+        // Lower the bounds below the stored values, as a runtime upgrade could
+        MaxMetadataLen::set(4);
+        MaxDescriptionLen::set(20);
+        StorageVersion::new(4).put::<ChainGhost>();
+
+        MigrateV4ToV5::<Test>::on_runtime_upgrade();
+
+        let intent = ChainGhost::intent_by_id(intent_id).expect("Intent should survive migration");
+        assert_eq!(intent.metadata.to_vec(), b"lega".to_vec());
+        let steps = ChainGhost::journey_by_intent(intent_id);
+        assert_eq!(steps.len(), 2);
+        assert_eq!(steps[0].description.to_vec(), vec![b'B'; 20]);
+        assert_eq!(steps[1].description.to_vec(), b"Recorded by backend".to_vec());
+
+        // The chain of the truncated journey is rebuilt over the stored steps
+        let mut expected = JourneyHead {
+            step_count: 0,
+            hash: Default::default(),
+        };
+        for step in steps {
+            expected.hash = BlakeTwo256::hash_of(&(&expected.hash, &step));
+            expected.step_count += 1;
+        }
+        assert_eq!(ChainGhost::journey_head(intent_id), Some(expected));
+        assert_eq!(ChainGhost::journey_head(untouched), untouched_head);
+        assert_eq!(ChainGhost::on_chain_storage_version(), 5);
+    });
+}
+
+#[test]
+fn migration_v5_to_v6_orders_intents_by_account() {
+    use crate::migrations::v6::{v5, MigrateV5ToV6};
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(5).put::<ChainGhost>();
+
+        for intent_id in [300, 2, 256, 1] {
+            v5::IntentsByAccount::<Test>::insert(1, intent_id, ());
+        }
+        v5::IntentsByAccount::<Test>::insert(2, 7, ());
+
+        MigrateV5ToV6::<Test>::on_runtime_upgrade();
+
+        assert_eq!(ChainGhost::intents_of(&1, None, 10), vec![1, 2, 256, 300]);
+        assert_eq!(ChainGhost::intents_of(&1, Some(2), 10), vec![256, 300]);
+        assert_eq!(ChainGhost::intents_of(&2, None, 10), vec![7]);
+        assert_eq!(v5::IntentsByAccount::<Test>::iter().count(), 0);
+        assert_eq!(ChainGhost::on_chain_storage_version(), 6);
+    });
+}
+
+fn remark_call(remark: &[u8]) -> RuntimeCall {
+    RuntimeCall::System(frame_system::Call::remark_with_event {
+        remark: remark.to_vec(),
+    })
+}
+
+// Requires root, so it fails when dispatched with a signed origin
+fn root_only_call() -> RuntimeCall {
+    RuntimeCall::System(frame_system::Call::set_heap_pages { pages: 1 })
+}
+
+fn has_remark_event() -> bool {
+    System::events().iter().any(|record| {
+        matches!(
+            record.event,
+            RuntimeEvent::System(frame_system::Event::Remarked { .. })
+        )
+    })
+}
+
+#[test]
+fn execute_intent_dispatches_calls() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(ChainGhost::execute_intent(
+            RuntimeOrigin::signed(1),
+            transfer_intent(),
+            Vec::new(),
+            vec![remark_call(b"first"), remark_call(b"second")]
+        ));
+
+        let intent = ChainGhost::intent_by_id(0).expect("Intent should exist");
+        assert_eq!(intent.status, IntentStatus::Executed);
+        assert_eq!(ChainGhost::intent_error(0), None);
+        assert!(has_remark_event());
+
+        let steps = ChainGhost::journey_by_intent(0);
+        assert_eq!(steps.len(), 2);
+        assert_eq!(
+            steps[0].description.to_vec(),
+            b"System.remark_with_event: executed".to_vec()
+        );
+        assert_eq!(steps[1].step_id, 1);
+
+        System::assert_has_event(
+            Event::IntentCallsDispatched {
+                intent_id: 0,
+                call_count: 2,
+                result: Ok(()),
+            }
+            .into(),
+        );
+        // Every step is announced
+        for step_count in 1..=2 {
+            System::assert_has_event(
+                Event::JourneyRecorded {
+                    intent_id: 0,
+                    step_count,
+                }
+                .into(),
+            );
+        }
+        System::assert_last_event(
+            Event::IntentStatusUpdated {
+                intent_id: 0,
+                old_status: 0,
+                new_status: 1,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn execute_intent_failed_call_rolls_back_batch() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(ChainGhost::execute_intent(
+            RuntimeOrigin::signed(1),
+            transfer_intent(),
+            Vec::new(),
+            vec![remark_call(b"first"), root_only_call(), remark_call(b"third")]
+        ));
+
+        // The intent is kept, but none of the calls took effect
+        let intent = ChainGhost::intent_by_id(0).expect("Intent should exist");
+        assert_eq!(intent.status, IntentStatus::Failed);
+        assert!(!has_remark_event());
+        assert_eq!(
+            ChainGhost::intent_error(0),
+            Some(ExecutionError {
+                call_index: 1,
+                error: DispatchError::BadOrigin,
+            })
+        );
+
+        let outcomes: Vec<Vec<u8>> = ChainGhost::journey_by_intent(0)
+            .iter()
+            .map(|step| step.description.to_vec())
+            .collect();
+        assert_eq!(
+            outcomes,
+            vec![
+                b"System.remark_with_event: rolled back".to_vec(),
+                b"System.set_heap_pages: failed".to_vec(),
+                b"System.remark_with_event: skipped".to_vec(),
+            ]
+        );
+
+        System::assert_has_event(
+            Event::IntentCallsDispatched {
+                intent_id: 0,
+                call_count: 3,
+                result: Err(DispatchError::BadOrigin),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn execute_intent_without_calls_stays_pending() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(ChainGhost::execute_intent(
+            RuntimeOrigin::signed(1),
+            transfer_intent(),
+            Vec::new(),
+            Vec::new()
+        ));
+
+        let intent = ChainGhost::intent_by_id(0).expect("Intent should exist");
+        assert_eq!(intent.status, IntentStatus::Pending);
+        assert!(ChainGhost::journey_by_intent(0).is_empty());
+    });
+}
+
+#[test]
+fn execute_intent_rejects_calls_to_the_pallet() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        // The call would cancel the intent being dispatched
+        let cancel = RuntimeCall::ChainGhost(crate::Call::cancel_intent {
+            intent_id: ChainGhost::next_intent_id(),
+            reason: CancelReason::UserRequested,
+        });
+        assert_noop!(
+            ChainGhost::execute_intent(
+                RuntimeOrigin::signed(1),
+                transfer_intent(),
+                Vec::new(),
+                vec![remark_call(b"first"), cancel]
+            ),
+            Error::<Test>::IntentCallNotAllowed
+        );
+        assert!(ChainGhost::intent_by_id(0).is_none());
+    });
+}
+
+#[test]
+fn execute_intent_too_many_calls_fails() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let calls = vec![remark_call(b"x"); MaxCallsPerIntent::get() as usize + 1];
+        assert_noop!(
+            ChainGhost::execute_intent(
+                RuntimeOrigin::signed(1),
+                transfer_intent(),
+                Vec::new(),
+                calls
+            ),
+            Error::<Test>::TooManyCalls
+        );
+    });
+}
+
+const OWNER: u64 = 1;
+const SOLVER: u64 = 3;
+
+fn create_intent(owner: u64) -> u64 {
+    let intent_id = ChainGhost::next_intent_id();
+    assert_ok!(ChainGhost::execute_intent(
+        RuntimeOrigin::signed(owner),
+        transfer_intent(),
+        Vec::new(),
+        Vec::new()
+    ));
+    intent_id
+}
+
+#[test]
+fn register_and_deregister_solver_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(ChainGhost::register_solver(RuntimeOrigin::signed(SOLVER)));
+        assert_eq!(Balances::total_balance_on_hold(&SOLVER), SolverBond::get());
+        assert_eq!(ChainGhost::solver(SOLVER).unwrap().bond, SolverBond::get());
+        System::assert_last_event(
+            Event::SolverRegistered {
+                solver: SOLVER,
+                bond: SolverBond::get(),
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            ChainGhost::register_solver(RuntimeOrigin::signed(SOLVER)),
+            Error::<Test>::AlreadySolver
+        );
+
+        assert_ok!(ChainGhost::deregister_solver(RuntimeOrigin::signed(SOLVER)));
+        assert_eq!(Balances::total_balance_on_hold(&SOLVER), 0);
+        assert!(ChainGhost::solver(SOLVER).is_none());
+
+        assert_noop!(
+            ChainGhost::deregister_solver(RuntimeOrigin::signed(SOLVER)),
+            Error::<Test>::NotSolver
+        );
+    });
+}
+
+#[test]
+fn claim_and_fulfil_pays_reward() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let intent_id = create_intent(OWNER);
+        assert_ok!(ChainGhost::offer_reward(RuntimeOrigin::signed(OWNER), intent_id, 200));
+        assert_eq!(Balances::total_balance_on_hold(&OWNER), 200);
+        assert_noop!(
+            ChainGhost::offer_reward(RuntimeOrigin::signed(OWNER), intent_id, 100),
+            Error::<Test>::RewardAlreadyOffered
+        );
+
+        assert_noop!(
+            ChainGhost::claim_intent(RuntimeOrigin::signed(SOLVER), intent_id),
+            Error::<Test>::NotSolver
+        );
+
+        assert_ok!(ChainGhost::register_solver(RuntimeOrigin::signed(SOLVER)));
+        assert_ok!(ChainGhost::claim_intent(RuntimeOrigin::signed(SOLVER), intent_id));
+        System::assert_last_event(
+            Event::IntentClaimed {
+                intent_id,
+                solver: SOLVER,
+                deadline: 1 + ClaimTimeout::get(),
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            ChainGhost::claim_intent(RuntimeOrigin::signed(4), intent_id),
+            Error::<Test>::IntentAlreadyClaimed
+        );
+        // The owner can no longer self-report once a solver has claimed the intent
+        assert_noop!(
+            ChainGhost::update_intent_status(RuntimeOrigin::signed(OWNER), intent_id, 1),
+            Error::<Test>::IntentAlreadyClaimed
+        );
+        assert_noop!(
+            ChainGhost::fulfil_intent(RuntimeOrigin::signed(4), intent_id, b"0xabc".to_vec()),
+            Error::<Test>::NotClaimant
+        );
+
+        assert_ok!(ChainGhost::fulfil_intent(
+            RuntimeOrigin::signed(SOLVER),
+            intent_id,
+            b"0xabc".to_vec()
+        ));
+
+        let intent = ChainGhost::intent_by_id(intent_id).unwrap();
+        assert_eq!(intent.status, IntentStatus::Executed);
+        assert_eq!(
+            ChainGhost::fulfilment_proof(intent_id).unwrap().to_vec(),
+            b"0xabc".to_vec()
+        );
+        assert!(ChainGhost::intent_claim(intent_id).is_none());
+
+        let info = ChainGhost::solver(SOLVER).unwrap();
+        assert_eq!(info.active_claims, 0);
+        assert_eq!(info.fulfilled, 1);
+
+        let dispute_until = 1 + DisputePeriod::get();
+        System::assert_last_event(
+            Event::IntentFulfilled {
+                intent_id,
+                solver: SOLVER,
+                reward: 200,
+                dispute_until,
+            }
+            .into(),
+        );
+
+        // The reward stays escrowed while the owner may dispute the fulfilment
+        assert_eq!(Balances::total_balance_on_hold(&OWNER), 200);
+        assert_noop!(
+            ChainGhost::collect_reward(RuntimeOrigin::signed(4), intent_id),
+            Error::<Test>::DisputePeriodNotOver
+        );
+        assert_noop!(
+            ChainGhost::update_intent_status(RuntimeOrigin::signed(OWNER), intent_id, 2),
+            Error::<Test>::FulfilmentPending
+        );
+        assert_noop!(
+            ChainGhost::remove_intent(RuntimeOrigin::signed(OWNER), intent_id),
+            Error::<Test>::FulfilmentPending
+        );
+
+        System::set_block_number(dispute_until + 1);
+        assert_noop!(
+            ChainGhost::dispute_fulfilment(RuntimeOrigin::signed(OWNER), intent_id),
+            Error::<Test>::DisputePeriodOver
+        );
+        assert_ok!(ChainGhost::collect_reward(RuntimeOrigin::signed(4), intent_id));
+
+        assert_eq!(Balances::total_balance_on_hold(&OWNER), 0);
+        assert_eq!(Balances::total_balance(&OWNER), ENDOWED_BALANCE - 200);
+        assert_eq!(Balances::balance(&SOLVER), ENDOWED_BALANCE - SolverBond::get() + 200);
+        assert!(ChainGhost::pending_fulfilment(intent_id).is_none());
+        System::assert_last_event(
+            Event::RewardPaid {
+                intent_id,
+                solver: SOLVER,
+                reward: 200,
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            ChainGhost::collect_reward(RuntimeOrigin::signed(4), intent_id),
+            Error::<Test>::FulfilmentNotPending
+        );
+    });
+}
+
+#[test]
+fn disputed_fulfilment_reopens_intent_and_slashes_solver() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let intent_id = create_intent(OWNER);
+        assert_ok!(ChainGhost::offer_reward(RuntimeOrigin::signed(OWNER), intent_id, 200));
+        assert_ok!(ChainGhost::register_solver(RuntimeOrigin::signed(SOLVER)));
+        assert_ok!(ChainGhost::claim_intent(RuntimeOrigin::signed(SOLVER), intent_id));
+        assert_ok!(ChainGhost::fulfil_intent(
+            RuntimeOrigin::signed(SOLVER),
+            intent_id,
+            b"not a real tx".to_vec()
+        ));
+        assert_eq!(ChainGhost::persona(OWNER).intents_executed, 1);
+
+        assert_noop!(
+            ChainGhost::dispute_fulfilment(RuntimeOrigin::signed(4), intent_id),
+            Error::<Test>::NotIntentOwner
+        );
+        assert_ok!(ChainGhost::dispute_fulfilment(RuntimeOrigin::signed(OWNER), intent_id));
+
+        let slashed = ClaimSlash::get().mul_floor(SolverBond::get());
+        System::assert_last_event(
+            Event::FulfilmentDisputed {
+                intent_id,
+                solver: SOLVER,
+                amount: slashed,
+            }
+            .into(),
+        );
+
+        // The intent is pending again, with its reward escrowed and no proof
+        let intent = ChainGhost::intent_by_id(intent_id).unwrap();
+        assert_eq!(intent.status, IntentStatus::Pending);
+        assert_eq!(ChainGhost::persona(OWNER).intents_executed, 0);
+        assert!(ChainGhost::fulfilment_proof(intent_id).is_none());
+        assert!(ChainGhost::pending_fulfilment(intent_id).is_none());
+        assert_eq!(ChainGhost::intent_reward(intent_id), 200);
+        assert_eq!(Balances::total_balance_on_hold(&OWNER), 200);
+        assert_eq!(Balances::balance(&OWNER), ENDOWED_BALANCE - 200 + slashed);
+
+        let info = ChainGhost::solver(SOLVER).unwrap();
+        assert_eq!(info.bond, SolverBond::get() - slashed);
+        assert_eq!(info.fulfilled, 0);
+        assert_eq!(info.slashed, 1);
+
+        assert_noop!(
+            ChainGhost::collect_reward(RuntimeOrigin::signed(SOLVER), intent_id),
+            Error::<Test>::FulfilmentNotPending
+        );
+    });
+}
+
+#[test]
+fn solver_claims_are_capped_and_backed_by_the_bond() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(ChainGhost::register_solver(RuntimeOrigin::signed(SOLVER)));
+        let intents: Vec<u64> = (0..=MaxActiveClaims::get())
+            .map(|_| create_intent(OWNER))
+            .collect();
+
+        for intent_id in &intents[..MaxActiveClaims::get() as usize] {
+            assert_ok!(ChainGhost::claim_intent(RuntimeOrigin::signed(SOLVER), *intent_id));
+        }
+        let last = intents[MaxActiveClaims::get() as usize];
+        assert_noop!(
+            ChainGhost::claim_intent(RuntimeOrigin::signed(SOLVER), last),
+            Error::<Test>::TooManyActiveClaims
+        );
+
+        // Every timed out claim is slashed in full, until the bond runs out
+        System::set_block_number(2 + ClaimTimeout::get());
+        let slash = ClaimSlash::get().mul_floor(SolverBond::get());
+        for intent_id in &intents[..MaxActiveClaims::get() as usize] {
+            assert_ok!(ChainGhost::slash_expired_claim(RuntimeOrigin::signed(4), *intent_id));
+            System::assert_last_event(
+                Event::ClaimSlashed {
+                    intent_id: *intent_id,
+                    solver: SOLVER,
+                    amount: slash,
+                }
+                .into(),
+            );
+        }
+
+        // A solver slashed below the bond can no longer claim
+        assert_eq!(ChainGhost::solver(SOLVER).unwrap().bond, 0);
+        assert_noop!(
+            ChainGhost::claim_intent(RuntimeOrigin::signed(SOLVER), last),
+            Error::<Test>::SolverBondTooLow
+        );
+    });
+}
+
+#[test]
+fn expired_claim_is_slashed_and_reclaimable() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let intent_id = create_intent(OWNER);
+        assert_ok!(ChainGhost::offer_reward(RuntimeOrigin::signed(OWNER), intent_id, 200));
+        assert_ok!(ChainGhost::register_solver(RuntimeOrigin::signed(SOLVER)));
+        assert_ok!(ChainGhost::claim_intent(RuntimeOrigin::signed(SOLVER), intent_id));
+
+        // Deregistering is blocked while the claim is active
+        assert_noop!(
+            ChainGhost::deregister_solver(RuntimeOrigin::signed(SOLVER)),
+            Error::<Test>::SolverHasActiveClaims
+        );
+
+        let deadline = 1 + ClaimTimeout::get();
+        System::set_block_number(deadline);
+        assert_noop!(
+            ChainGhost::slash_expired_claim(RuntimeOrigin::signed(4), intent_id),
+            Error::<Test>::ClaimNotExpired
+        );
+
+        System::set_block_number(deadline + 1);
+        assert_noop!(
+            ChainGhost::fulfil_intent(RuntimeOrigin::signed(SOLVER), intent_id, Vec::new()),
+            Error::<Test>::ClaimExpired
+        );
+
+        assert_ok!(ChainGhost::slash_expired_claim(RuntimeOrigin::signed(4), intent_id));
+
+        let slashed = ClaimSlash::get().mul_floor(SolverBond::get());
+        System::assert_last_event(
+            Event::ClaimSlashed {
+                intent_id,
+                solver: SOLVER,
+                amount: slashed,
+            }
+            .into(),
+        );
+        assert_eq!(Balances::balance(&OWNER), ENDOWED_BALANCE - 200 + slashed);
+        assert_eq!(Balances::total_balance_on_hold(&SOLVER), SolverBond::get() - slashed);
+
+        let info = ChainGhost::solver(SOLVER).unwrap();
+        assert_eq!(info.bond, SolverBond::get() - slashed);
+        assert_eq!(info.active_claims, 0);
+        assert_eq!(info.slashed, 1);
+
+        // The intent is pending again, with its reward still escrowed, but the slashed solver
+        // cannot claim it again
+        assert!(ChainGhost::intent_claim(intent_id).is_none());
+        assert_eq!(ChainGhost::intent_reward(intent_id), 200);
+        assert_noop!(
+            ChainGhost::claim_intent(RuntimeOrigin::signed(SOLVER), intent_id),
+            Error::<Test>::SolverBondTooLow
+        );
+        assert_ok!(ChainGhost::register_solver(RuntimeOrigin::signed(4)));
+        assert_ok!(ChainGhost::claim_intent(RuntimeOrigin::signed(4), intent_id));
+    });
+}
+
+#[test]
+fn owner_resolving_intent_returns_reward() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let intent_id = create_intent(OWNER);
+        assert_noop!(
+            ChainGhost::offer_reward(RuntimeOrigin::signed(2), intent_id, 200),
+            Error::<Test>::NotIntentOwner
+        );
+        assert_noop!(
+            ChainGhost::offer_reward(RuntimeOrigin::signed(OWNER), intent_id, 0),
+            Error::<Test>::ZeroAmount
+        );
+
+        assert_ok!(ChainGhost::offer_reward(RuntimeOrigin::signed(OWNER), intent_id, 200));
+        assert_ok!(ChainGhost::update_intent_status(
+            RuntimeOrigin::signed(OWNER),
+            intent_id,
+            2
+        ));
+
+        assert_eq!(Balances::total_balance_on_hold(&OWNER), 0);
+        assert_eq!(Balances::balance(&OWNER), ENDOWED_BALANCE);
+        assert_eq!(ChainGhost::intent_reward(intent_id), 0);
+
+        // Resolved intents can no longer be rewarded or claimed
+        assert_ok!(ChainGhost::register_solver(RuntimeOrigin::signed(SOLVER)));
+        assert_noop!(
+            ChainGhost::claim_intent(RuntimeOrigin::signed(SOLVER), intent_id),
+            Error::<Test>::InvalidIntentStatus
+        );
+        assert_noop!(
+            ChainGhost::offer_reward(RuntimeOrigin::signed(OWNER), intent_id, 200),
+            Error::<Test>::InvalidIntentStatus
+        );
+    });
+}
+
+#[test]
+fn record_journey_stores_kind_link_and_attachment() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let intent_id = create_intent(OWNER);
+        let cid = b"bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi".to_vec();
+
+        assert_ok!(ChainGhost::record_journey(
+            RuntimeOrigin::signed(OWNER),
+            intent_id,
+            JourneyStepKind::BridgeHop,
+            b"Bridged to Arbitrum".to_vec(),
+            Some(LinkedTx::Ethereum([0xab; 32])),
+            Some(cid.clone())
+        ));
+
+        let step = &ChainGhost::journey_by_intent(intent_id)[0];
+        assert_eq!(step.step_kind, JourneyStepKind::BridgeHop);
+        assert_eq!(step.linked_tx, Some(LinkedTx::Ethereum([0xab; 32])));
+        assert_eq!(step.attachment.as_ref().map(|cid| cid.to_vec()), Some(cid));
+
+        assert_noop!(
+            ChainGhost::record_journey(
+                RuntimeOrigin::signed(OWNER),
+                intent_id,
+                JourneyStepKind::Note,
+                b"Receipt".to_vec(),
+                None,
+                Some(vec![b'x'; 129]),
+            ),
+            Error::<Test>::AttachmentTooLong
+        );
+    });
+}
+
+#[test]
+fn claiming_solver_can_record_journey() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let intent_id = create_intent(OWNER);
+        assert_ok!(ChainGhost::register_solver(RuntimeOrigin::signed(SOLVER)));
+
+        // A registered solver without a claim cannot write to the journey
+        assert_noop!(
+            ChainGhost::record_journey(
+                RuntimeOrigin::signed(SOLVER),
+                intent_id,
+                JourneyStepKind::TxSubmitted,
+                b"Submitted".to_vec(),
+                None,
+                None,
+            ),
+            Error::<Test>::NotIntentOwner
+        );
+
+        assert_ok!(ChainGhost::claim_intent(RuntimeOrigin::signed(SOLVER), intent_id));
+        assert_ok!(ChainGhost::record_journey(
+            RuntimeOrigin::signed(SOLVER),
+            intent_id,
+            JourneyStepKind::TxSubmitted,
+            b"Submitted".to_vec(),
+            Some(LinkedTx::Substrate([0x01; 32])),
+            None
+        ));
+
+        let step = &ChainGhost::journey_by_intent(intent_id)[0];
+        assert_eq!(step.author, SOLVER);
+        assert_eq!(step.step_kind, JourneyStepKind::TxSubmitted);
+
+        // The claim ends with fulfilment, and with it the solver's access
+        assert_ok!(ChainGhost::fulfil_intent(
+            RuntimeOrigin::signed(SOLVER),
+            intent_id,
+            b"0xabc".to_vec()
+        ));
+        assert_noop!(
+            ChainGhost::record_journey(
+                RuntimeOrigin::signed(SOLVER),
+                intent_id,
+                JourneyStepKind::Milestone,
+                b"Done".to_vec(),
+                None,
+                None,
+            ),
+            Error::<Test>::NotIntentOwner
+        );
+    });
+}
+
+const BACKEND: u64 = 2;
+
+fn record_note(who: u64, intent_id: u64) -> frame_support::dispatch::DispatchResult {
+    ChainGhost::record_journey(
+        RuntimeOrigin::signed(who),
+        intent_id,
+        JourneyStepKind::Note,
+        b"Recorded by backend".to_vec(),
+        None,
+        None,
+    )
+}
+
+#[test]
+fn add_and_remove_delegate_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(ChainGhost::add_delegate(
+            RuntimeOrigin::signed(OWNER),
+            BACKEND,
+            DelegateScope::RecordJourney,
+            Some(10)
+        ));
+        assert_eq!(
+            ChainGhost::delegation(OWNER, BACKEND),
+            Some(Delegation {
+                scope: DelegateScope::RecordJourney,
+                expires_at: Some(10),
+            })
+        );
+        System::assert_last_event(
+            Event::DelegateAdded {
+                owner: OWNER,
+                delegate: BACKEND,
+                scope: DelegateScope::RecordJourney,
+                expires_at: Some(10),
+            }
+            .into(),
+        );
+
+        // Updating an existing delegation does not use another slot
+        assert_ok!(ChainGhost::add_delegate(
+            RuntimeOrigin::signed(OWNER),
+            BACKEND,
+            DelegateScope::Full,
+            None
+        ));
+        assert_eq!(ChainGhost::delegate_count(OWNER), 1);
+
+        assert_ok!(ChainGhost::add_delegate(
+            RuntimeOrigin::signed(OWNER),
+            3,
+            DelegateScope::Full,
+            None
+        ));
+        assert_noop!(
+            ChainGhost::add_delegate(RuntimeOrigin::signed(OWNER), 4, DelegateScope::Full, None),
+            Error::<Test>::TooManyDelegates
+        );
+        assert_noop!(
+            ChainGhost::add_delegate(
+                RuntimeOrigin::signed(OWNER),
+                OWNER,
+                DelegateScope::Full,
+                None,
+            ),
+            Error::<Test>::CannotDelegateToSelf
+        );
+
+        assert_ok!(ChainGhost::remove_delegate(RuntimeOrigin::signed(OWNER), BACKEND));
+        assert!(ChainGhost::delegation(OWNER, BACKEND).is_none());
+        assert_eq!(ChainGhost::delegate_count(OWNER), 1);
+        System::assert_last_event(
+            Event::DelegateRemoved {
+                owner: OWNER,
+                delegate: BACKEND,
+            }
+            .into(),
+        );
+        assert_noop!(
+            ChainGhost::remove_delegate(RuntimeOrigin::signed(OWNER), BACKEND),
+            Error::<Test>::DelegateNotFound
+        );
+    });
+}
+
+#[test]
+fn delegate_scopes_are_enforced() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let intent_id = create_intent(OWNER);
+
+        assert_ok!(ChainGhost::add_delegate(
+            RuntimeOrigin::signed(OWNER),
+            BACKEND,
+            DelegateScope::RecordJourney,
+            None
+        ));
+        assert_ok!(record_note(BACKEND, intent_id));
+        assert_eq!(ChainGhost::journey_by_intent(intent_id)[0].author, BACKEND);
+        assert_noop!(
+            ChainGhost::update_intent_status(RuntimeOrigin::signed(BACKEND), intent_id, 1),
+            Error::<Test>::NotIntentOwner
+        );
+
+        assert_ok!(ChainGhost::add_delegate(
+            RuntimeOrigin::signed(OWNER),
+            BACKEND,
+            DelegateScope::UpdateStatus,
+            None
+        ));
+        assert_noop!(record_note(BACKEND, intent_id), Error::<Test>::NotIntentOwner);
+
+        // Resolving through a delegate returns the reward to the owner, not the delegate
+        assert_ok!(ChainGhost::offer_reward(RuntimeOrigin::signed(OWNER), intent_id, 200));
+        assert_ok!(ChainGhost::update_intent_status(
+            RuntimeOrigin::signed(BACKEND),
+            intent_id,
+            1
+        ));
+        assert_eq!(ChainGhost::intent_by_id(intent_id).unwrap().status, IntentStatus::Executed);
+        assert_eq!(Balances::total_balance_on_hold(&OWNER), 0);
+        assert_eq!(Balances::balance(&OWNER), ENDOWED_BALANCE);
+        assert_eq!(Balances::balance(&BACKEND), ENDOWED_BALANCE);
+
+        // Delegations only cover the delegating account's intents
+        let other_intent = create_intent(4);
+        assert_noop!(record_note(BACKEND, other_intent), Error::<Test>::NotIntentOwner);
+    });
+}
+
+#[test]
+fn expired_delegation_is_rejected() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(5);
+
+        let intent_id = create_intent(OWNER);
+
+        assert_noop!(
+            ChainGhost::add_delegate(
+                RuntimeOrigin::signed(OWNER),
+                BACKEND,
+                DelegateScope::Full,
+                Some(4),
+            ),
+            Error::<Test>::DelegationExpired
+        );
+
+        assert_ok!(ChainGhost::add_delegate(
+            RuntimeOrigin::signed(OWNER),
+            BACKEND,
+            DelegateScope::Full,
+            Some(6)
+        ));
+
+        System::set_block_number(6);
+        assert_ok!(record_note(BACKEND, intent_id));
+
+        System::set_block_number(7);
+        assert_noop!(record_note(BACKEND, intent_id), Error::<Test>::NotIntentOwner);
+        assert_noop!(
+            ChainGhost::cancel_intent(
+                RuntimeOrigin::signed(BACKEND),
+                intent_id,
+                CancelReason::Expired
+            ),
+            Error::<Test>::NotIntentOwner
+        );
+        assert_eq!(ChainGhost::intent_by_id(intent_id).unwrap().status, IntentStatus::Pending);
+
+        // The expired delegation still holds a slot until removed
+        assert_eq!(ChainGhost::delegate_count(OWNER), 1);
+        assert_ok!(ChainGhost::remove_delegate(RuntimeOrigin::signed(OWNER), BACKEND));
+        assert_eq!(ChainGhost::delegate_count(OWNER), 0);
+    });
+}
+
+fn swap_action(asset_out: u32) -> IntentAction<u64> {
+    IntentAction::Swap(SwapIntent {
+        asset_in: 0,
+        asset_out,
+        amount_in: 100,
+        min_amount_out: 95,
+    })
+}
+
+// 0 and 1 are independent, 2 depends on both
+fn create_diamond_intent() -> u64 {
+    let intent_id = ChainGhost::next_intent_id();
+    assert_ok!(ChainGhost::create_multi_step_intent(
+        RuntimeOrigin::signed(OWNER),
+        b"Stake, bridge and deposit".to_vec(),
+        vec![
+            (swap_action(1), vec![]),
+            (swap_action(2), vec![]),
+            (swap_action(3), vec![1, 0]),
+        ]
+    ));
+    intent_id
+}
+
+#[test]
+fn create_multi_step_intent_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let intent_id = create_diamond_intent();
+
+        let intent = ChainGhost::intent_by_id(intent_id).unwrap();
+        assert_eq!(intent.status, IntentStatus::Pending);
+        assert!(matches!(intent.kind, Some(IntentKind::Batch(ref actions)) if actions.len() == 3));
+
+        let sub_intents = ChainGhost::sub_intents(intent_id).unwrap();
+        assert_eq!(sub_intents.len(), 3);
+        assert_eq!(sub_intents[2].depends_on.to_vec(), vec![0, 1]);
+        assert!(sub_intents.iter().all(|sub| sub.status == IntentStatus::Pending));
+
+        let tree = ChainGhost::sub_intent_tree(intent_id);
+        assert_eq!(tree[1].0, swap_action(2));
+        assert_eq!(ChainGhost::ready_sub_intents(intent_id), vec![0, 1]);
+    });
+}
+
+#[test]
+fn create_multi_step_intent_rejects_invalid_graphs() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let create = |sub_intents| {
+            ChainGhost::create_multi_step_intent(
+                RuntimeOrigin::signed(OWNER),
+                Vec::new(),
+                sub_intents,
+            )
+        };
+
+        assert_noop!(create(vec![]), Error::<Test>::EmptyBatch);
+        // Self-dependency
+        assert_noop!(
+            create(vec![(swap_action(1), vec![0])]),
+            Error::<Test>::InvalidDependency
+        );
+        // Forward dependency, which could close a cycle
+        assert_noop!(
+            create(vec![(swap_action(1), vec![1]), (swap_action(2), vec![0])]),
+            Error::<Test>::InvalidDependency
+        );
+        // Repeated dependency
+        assert_noop!(
+            create(vec![(swap_action(1), vec![]), (swap_action(2), vec![0, 0])]),
+            Error::<Test>::InvalidDependency
+        );
+        assert_noop!(
+            create(vec![(swap_action(1), vec![]); 17]),
+            Error::<Test>::TooManySubIntents
+        );
+        assert_noop!(
+            create(vec![(swap_action(0), vec![])]),
+            Error::<Test>::SameAssetSwap
+        );
+    });
+}
+
+#[test]
+fn sub_intents_roll_up_to_executed() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let intent_id = create_diamond_intent();
+        assert_ok!(ChainGhost::offer_reward(RuntimeOrigin::signed(OWNER), intent_id, 200));
+
+        // The parent status is derived from the sub-intents
+        assert_noop!(
+            ChainGhost::update_intent_status(RuntimeOrigin::signed(OWNER), intent_id, 1),
+            Error::<Test>::StatusDerivedFromSubIntents
+        );
+
+        // Nor can a solver claim and fulfil the parent as a whole
+        assert_ok!(ChainGhost::register_solver(RuntimeOrigin::signed(SOLVER)));
+        assert_noop!(
+            ChainGhost::claim_intent(RuntimeOrigin::signed(SOLVER), intent_id),
+            Error::<Test>::StatusDerivedFromSubIntents
+        );
+
+        assert_ok!(ChainGhost::update_sub_intent_status(
+            RuntimeOrigin::signed(OWNER),
+            intent_id,
+            0,
+            1
+        ));
+        assert_noop!(
+            ChainGhost::update_sub_intent_status(RuntimeOrigin::signed(OWNER), intent_id, 2, 1),
+            Error::<Test>::DependenciesNotMet
+        );
+        assert_eq!(ChainGhost::ready_sub_intents(intent_id), vec![1]);
+
+        assert_ok!(ChainGhost::update_sub_intent_status(
+            RuntimeOrigin::signed(OWNER),
+            intent_id,
+            1,
+            1
+        ));
+        assert_eq!(ChainGhost::intent_by_id(intent_id).unwrap().status, IntentStatus::Pending);
+
+        assert_ok!(ChainGhost::update_sub_intent_status(
+            RuntimeOrigin::signed(OWNER),
+            intent_id,
+            2,
+            1
+        ));
+        assert_eq!(ChainGhost::intent_by_id(intent_id).unwrap().status, IntentStatus::Executed);
+        assert_eq!(Balances::total_balance_on_hold(&OWNER), 0);
+        System::assert_has_event(
+            Event::SubIntentStatusUpdated {
+                intent_id,
+                index: 2,
+                old_status: 0,
+                new_status: 1,
+            }
+            .into(),
+        );
+        System::assert_last_event(
+            Event::IntentStatusUpdated {
+                intent_id,
+                old_status: 0,
+                new_status: 1,
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            ChainGhost::update_sub_intent_status(RuntimeOrigin::signed(OWNER), intent_id, 0, 2),
+            Error::<Test>::InvalidIntentStatus
+        );
+    });
+}
+
+#[test]
+fn failed_sub_intent_fails_parent() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let intent_id = create_diamond_intent();
+
+        assert_noop!(
+            ChainGhost::update_sub_intent_status(RuntimeOrigin::signed(BACKEND), intent_id, 1, 2),
+            Error::<Test>::NotIntentOwner
+        );
+        assert_noop!(
+            ChainGhost::update_sub_intent_status(RuntimeOrigin::signed(OWNER), intent_id, 3, 2),
+            Error::<Test>::SubIntentNotFound
+        );
+
+        // Delegates with the status scope may resolve sub-intents
+        assert_ok!(ChainGhost::add_delegate(
+            RuntimeOrigin::signed(OWNER),
+            BACKEND,
+            DelegateScope::UpdateStatus,
+            None
+        ));
+        assert_ok!(ChainGhost::update_sub_intent_status(
+            RuntimeOrigin::signed(BACKEND),
+            intent_id,
+            1,
+            2
+        ));
+
+        assert_eq!(ChainGhost::intent_by_id(intent_id).unwrap().status, IntentStatus::Failed);
+        assert_eq!(ChainGhost::sub_intents(intent_id).unwrap()[0].status, IntentStatus::Pending);
+
+        // Once the parent has failed, the remaining sub-intents are frozen
+        assert!(ChainGhost::ready_sub_intents(intent_id).is_empty());
+        assert_noop!(
+            ChainGhost::update_sub_intent_status(RuntimeOrigin::signed(OWNER), intent_id, 0, 1),
+            Error::<Test>::InvalidIntentStatus
+        );
+
+        // Single-step intents have no sub-intents
+        let single = create_intent(OWNER);
+        assert_noop!(
+            ChainGhost::update_sub_intent_status(RuntimeOrigin::signed(OWNER), single, 0, 1),
+            Error::<Test>::SubIntentNotFound
+        );
+        assert!(ChainGhost::sub_intent_tree(single).is_empty());
+    });
+}
+
+const RELAYER: u64 = 4;
+
+#[test]
+fn add_and_remove_relayer_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_noop!(
+            ChainGhost::add_relayer(RuntimeOrigin::signed(OWNER), RELAYER),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(ChainGhost::add_relayer(RuntimeOrigin::root(), RELAYER));
+        assert!(ChainGhost::is_relayer(RELAYER).is_some());
+        System::assert_last_event(Event::RelayerAdded { relayer: RELAYER }.into());
+        assert_noop!(
+            ChainGhost::add_relayer(RuntimeOrigin::root(), RELAYER),
+            Error::<Test>::AlreadyRelayer
+        );
+
+        assert_ok!(ChainGhost::remove_relayer(RuntimeOrigin::root(), RELAYER));
+        assert!(ChainGhost::is_relayer(RELAYER).is_none());
+        System::assert_last_event(Event::RelayerRemoved { relayer: RELAYER }.into());
+        assert_noop!(
+            ChainGhost::remove_relayer(RuntimeOrigin::root(), RELAYER),
+            Error::<Test>::NotRelayer
+        );
+    });
+}
+
+#[test]
+fn attach_external_tx_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let intent_id = create_intent(OWNER);
+
+        assert_noop!(
+            ChainGhost::attach_external_tx(
+                RuntimeOrigin::signed(BACKEND),
+                intent_id,
+                1,
+                vec![1; 32],
+                7
+            ),
+            Error::<Test>::NotIntentOwner
+        );
+        assert_noop!(
+            ChainGhost::attach_external_tx(
+                RuntimeOrigin::signed(OWNER),
+                intent_id,
+                1,
+                Vec::new(),
+                7
+            ),
+            Error::<Test>::InvalidTxHash
+        );
+        assert_noop!(
+            ChainGhost::attach_external_tx(
+                RuntimeOrigin::signed(OWNER),
+                intent_id,
+                1,
+                vec![1; 65],
+                7
+            ),
+            Error::<Test>::InvalidTxHash
+        );
+        assert_noop!(
+            ChainGhost::attach_external_tx(RuntimeOrigin::signed(OWNER), 99, 1, vec![1; 32], 7),
+            Error::<Test>::IntentNotFound
+        );
+
+        assert_ok!(ChainGhost::attach_external_tx(
+            RuntimeOrigin::signed(OWNER),
+            intent_id,
+            1,
+            vec![1; 32],
+            7
+        ));
+        System::assert_last_event(
+            Event::ExternalTxAttached {
+                intent_id,
+                index: 0,
+                chain_id: 1,
+            }
+            .into(),
+        );
+
+        // Delegates recording the journey may attach transactions too, e.g. a 64-byte signature
+        assert_ok!(ChainGhost::add_delegate(
+            RuntimeOrigin::signed(OWNER),
+            BACKEND,
+            DelegateScope::RecordJourney,
+            None
+        ));
+        assert_ok!(ChainGhost::attach_external_tx(
+            RuntimeOrigin::signed(BACKEND),
+            intent_id,
+            101,
+            vec![2; 64],
+            250_000_000
+        ));
+
+        let refs = ChainGhost::chain_refs(intent_id);
+        assert_eq!(refs.len(), 2);
+        assert_eq!(refs[0].chain_id, 1);
+        assert_eq!(refs[0].tx_hash.to_vec(), vec![1; 32]);
+        assert_eq!(refs[0].block_number, 7);
+        assert!(!refs[0].attested);
+        assert_eq!(refs[1].chain_id, 101);
+        assert_eq!(refs[1].block_number, 250_000_000);
+
+        assert_noop!(
+            ChainGhost::attach_external_tx(
+                RuntimeOrigin::signed(OWNER),
+                intent_id,
+                1,
+                vec![3; 32],
+                8
+            ),
+            Error::<Test>::TooManyChainRefs
+        );
+    });
+}
+
+#[test]
+fn relayer_attestation_executes_intent() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let intent_id = create_intent(OWNER);
+        assert_ok!(ChainGhost::offer_reward(RuntimeOrigin::signed(OWNER), intent_id, 200));
+        assert_ok!(ChainGhost::attach_external_tx(
+            RuntimeOrigin::signed(OWNER),
+            intent_id,
+            1,
+            vec![1; 32],
+            7
+        ));
+
+        assert_noop!(
+            ChainGhost::attest_external_execution(RuntimeOrigin::signed(RELAYER), intent_id, 0),
+            Error::<Test>::NotRelayer
+        );
+
+        assert_ok!(ChainGhost::add_relayer(RuntimeOrigin::root(), RELAYER));
+        assert_noop!(
+            ChainGhost::attest_external_execution(RuntimeOrigin::signed(RELAYER), intent_id, 1),
+            Error::<Test>::ChainRefNotFound
+        );
+
+        assert_ok!(ChainGhost::attest_external_execution(
+            RuntimeOrigin::signed(RELAYER),
+            intent_id,
+            0
+        ));
+        assert_eq!(ChainGhost::intent_by_id(intent_id).unwrap().status, IntentStatus::Executed);
+        assert!(ChainGhost::chain_refs(intent_id)[0].attested);
+        assert_eq!(Balances::total_balance_on_hold(&OWNER), 0);
+        assert!(System::events().iter().any(|record| {
+            record.event
+                == Event::ExternalExecutionAttested {
+                    intent_id,
+                    index: 0,
+                    relayer: RELAYER,
+                }
+                .into()
+        }));
+        System::assert_last_event(
+            Event::IntentStatusUpdated {
+                intent_id,
+                old_status: 0,
+                new_status: 1,
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            ChainGhost::attest_external_execution(RuntimeOrigin::signed(RELAYER), intent_id, 0),
+            Error::<Test>::InvalidIntentStatus
+        );
+
+        // Claimed intents are resolved by their solver, not by relayers
+        let claimed = create_intent(OWNER);
+        assert_ok!(ChainGhost::attach_external_tx(
+            RuntimeOrigin::signed(OWNER),
+            claimed,
+            1,
+            vec![1; 32],
+            7
+        ));
+        assert_ok!(ChainGhost::register_solver(RuntimeOrigin::signed(SOLVER)));
+        assert_ok!(ChainGhost::claim_intent(RuntimeOrigin::signed(SOLVER), claimed));
+        assert_noop!(
+            ChainGhost::attest_external_execution(RuntimeOrigin::signed(RELAYER), claimed, 0),
+            Error::<Test>::IntentAlreadyClaimed
+        );
+    });
+}
+
+fn achievement(kind: AchievementKind, threshold: u32) -> Achievement {
+    Achievement { kind, threshold }
+}
+
+fn unlocked_events() -> Vec<Achievement> {
+    System::events()
+        .into_iter()
+        .filter_map(|record| match record.event {
+            RuntimeEvent::ChainGhost(Event::AchievementUnlocked { account, achievement })
+                if account == OWNER =>
+            {
+                Some(achievement)
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+/// Attach an external transaction to `intent_id` and have it attested by a relayer
+fn attest_execution(intent_id: u64) {
+    if ChainGhost::is_relayer(RELAYER).is_none() {
+        assert_ok!(ChainGhost::add_relayer(RuntimeOrigin::root(), RELAYER));
+    }
+    let index = ChainGhost::chain_refs(intent_id).len() as u32;
+    assert_ok!(ChainGhost::attach_external_tx(
+        RuntimeOrigin::signed(OWNER),
+        intent_id,
+        1,
+        vec![1; 32],
+        7
+    ));
+    assert_ok!(ChainGhost::attest_external_execution(
+        RuntimeOrigin::signed(RELAYER),
+        intent_id,
+        index
+    ));
+}
+
+#[test]
+fn persona_counters_unlock_achievements() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let transfer = create_intent(OWNER);
+        for _ in 0..3 {
+            assert_ok!(record_note(OWNER, transfer));
+        }
+        assert_eq!(unlocked_events(), vec![achievement(AchievementKind::JourneySteps, 3)]);
+
+        // Owners reporting their own executions earn nothing
+        assert_ok!(ChainGhost::update_intent_status(RuntimeOrigin::signed(OWNER), transfer, 1));
+        assert_eq!(ChainGhost::persona(OWNER).intents_executed, 0);
+        assert!(ChainGhost::is_execution_attested(transfer).is_none());
+        assert_eq!(unlocked_events(), vec![achievement(AchievementKind::JourneySteps, 3)]);
+
+        assert_ok!(ChainGhost::update_intent_status(RuntimeOrigin::signed(OWNER), transfer, 0));
+        attest_execution(transfer);
+        assert!(ChainGhost::is_execution_attested(transfer).is_some());
+        // A single resolved intent does not earn the success ratio badge
+        assert_eq!(
+            unlocked_events(),
+            vec![
+                achievement(AchievementKind::JourneySteps, 3),
+                achievement(AchievementKind::IntentsExecuted, 1),
+            ]
+        );
+
+        System::reset_events();
+        System::set_block_number(2);
+        let swap = ChainGhost::next_intent_id();
+        assert_ok!(ChainGhost::execute_intent(
+            RuntimeOrigin::signed(OWNER),
+            IntentKind::Swap(SwapIntent {
+                asset_in: 0,
+                asset_out: 1,
+                amount_in: 100,
+                min_amount_out: 95,
+            }),
+            Vec::new(),
+            Vec::new()
+        ));
+        assert_ok!(ChainGhost::update_intent_status(RuntimeOrigin::signed(OWNER), swap, 2));
+        // Failed intents do not count towards distinct kinds
+        assert_eq!(unlocked_events(), vec![achievement(AchievementKind::SuccessRatio, 50)]);
+
+        System::reset_events();
+        assert_ok!(ChainGhost::update_intent_status(RuntimeOrigin::signed(OWNER), swap, 1));
+        assert!(unlocked_events().is_empty());
+        assert_eq!(ChainGhost::persona(OWNER).distinct_kinds(), 1);
+
+        assert_ok!(ChainGhost::update_intent_status(RuntimeOrigin::signed(OWNER), swap, 0));
+        attest_execution(swap);
+        assert_eq!(unlocked_events(), vec![achievement(AchievementKind::DistinctKinds, 2)]);
+
+        let persona = ChainGhost::persona(OWNER);
+        assert_eq!(persona.intents_created, 2);
+        assert_eq!(persona.intents_executed, 2);
+        assert_eq!(persona.intents_failed, 0);
+        assert_eq!(persona.distinct_kinds(), 2);
+        assert_eq!(persona.journey_steps, 3);
+        assert_eq!(persona.success_ratio(), Some(100));
+        assert_eq!(
+            ChainGhost::achievement_unlocked_at(
+                OWNER,
+                achievement(AchievementKind::DistinctKinds, 2)
+            ),
+            Some(2)
+        );
+
+        // Badges are soulbound: they survive the counters dropping again
+        assert_ok!(ChainGhost::update_intent_status(RuntimeOrigin::signed(OWNER), swap, 0));
+        assert_eq!(ChainGhost::persona(OWNER).intents_executed, 1);
+        assert!(ChainGhost::is_execution_attested(swap).is_none());
+        assert!(ChainGhost::achievement_unlocked_at(
+            OWNER,
+            achievement(AchievementKind::DistinctKinds, 2)
+        )
+        .is_some());
+    });
+}
+
+#[test]
+fn dispatched_intents_update_persona() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(ChainGhost::execute_intent(
+            RuntimeOrigin::signed(OWNER),
+            transfer_intent(),
+            Vec::new(),
+            vec![remark_call(b"a"), remark_call(b"b")]
+        ));
+        assert_ok!(ChainGhost::execute_intent(
+            RuntimeOrigin::signed(OWNER),
+            transfer_intent(),
+            Vec::new(),
+            vec![root_only_call()]
+        ));
+
+        let persona = ChainGhost::persona(OWNER);
+        assert_eq!(persona.intents_executed, 1);
+        assert_eq!(persona.intents_failed, 1);
+        assert_eq!(persona.journey_steps, 3);
+        assert!(unlocked_events().contains(&achievement(AchievementKind::IntentsExecuted, 1)));
+
+        // A multi-step intent rolled up by its owner is not attested
+        let intent_id = create_diamond_intent();
+        for index in 0..3 {
+            assert_ok!(ChainGhost::update_sub_intent_status(
+                RuntimeOrigin::signed(OWNER),
+                intent_id,
+                index,
+                1
+            ));
+        }
+        assert_eq!(ChainGhost::intent_by_id(intent_id).unwrap().status, IntentStatus::Executed);
+
+        let persona = ChainGhost::persona(OWNER);
+        assert_eq!(persona.intents_created, 3);
+        assert_eq!(persona.intents_executed, 1);
+        assert_eq!(persona.distinct_kinds(), 1);
+        assert_eq!(persona.success_ratio(), Some(50));
+    });
+}
+
+fn create_template(owner: u64) -> u64 {
+    let template_id = ChainGhost::next_template_id();
+    assert_ok!(ChainGhost::create_template(
+        RuntimeOrigin::signed(owner),
+        transfer_intent(),
+        b"Weekly rent".to_vec()
+    ));
+    template_id
+}
+
+fn schedule(owner: u64, template_id: u64, period: u64, count: u32) -> u64 {
+    let schedule_id = ChainGhost::next_schedule_id();
+    assert_ok!(ChainGhost::schedule_recurring_intent(
+        RuntimeOrigin::signed(owner),
+        template_id,
+        period,
+        count
+    ));
+    schedule_id
+}
+
+fn instantiated_events() -> Vec<(u64, u64)> {
+    System::events()
+        .into_iter()
+        .filter_map(|record| match record.event {
+            RuntimeEvent::ChainGhost(Event::RecurringIntentInstantiated {
+                schedule_id,
+                intent_id,
+                ..
+            }) => Some((schedule_id, intent_id)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn templates_are_validated_and_bounded_per_account() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let template_id = create_template(OWNER);
+        let template = ChainGhost::template(OWNER, template_id).expect("Template should exist");
+        assert_eq!(template.kind, transfer_intent());
+        assert_eq!(template.metadata.to_vec(), b"Weekly rent".to_vec());
+        System::assert_last_event(
+            Event::TemplateCreated {
+                account: OWNER,
+                template_id,
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            ChainGhost::create_template(
+                RuntimeOrigin::signed(OWNER),
+                IntentKind::Swap(SwapIntent {
+                    asset_in: 0,
+                    asset_out: 0,
+                    amount_in: 100,
+                    min_amount_out: 95,
+                }),
+                Vec::new()
+            ),
+            Error::<Test>::SameAssetSwap
+        );
+        assert_noop!(
+            ChainGhost::create_template(
+                RuntimeOrigin::signed(OWNER),
+                transfer_intent(),
+                vec![0u8; MaxMetadataLen::get() as usize + 1]
+            ),
+            Error::<Test>::MetadataTooLong
+        );
+
+        create_template(OWNER);
+        assert_noop!(
+            ChainGhost::create_template(
+                RuntimeOrigin::signed(OWNER),
+                transfer_intent(),
+                Vec::new()
+            ),
+            Error::<Test>::TooManyTemplates
+        );
+
+        // Templates are private to their owner
+        assert_noop!(
+            ChainGhost::remove_template(RuntimeOrigin::signed(SOLVER), template_id),
+            Error::<Test>::TemplateNotFound
+        );
+        assert_ok!(ChainGhost::remove_template(RuntimeOrigin::signed(OWNER), template_id));
+        assert!(ChainGhost::template(OWNER, template_id).is_none());
+        assert_eq!(ChainGhost::template_count(OWNER), 1);
+        create_template(OWNER);
+    });
+}
+
+#[test]
+fn recurring_intent_is_instantiated_every_period() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let template_id = create_template(OWNER);
+        assert_noop!(
+            ChainGhost::schedule_recurring_intent(RuntimeOrigin::signed(OWNER), template_id, 0, 2),
+            Error::<Test>::InvalidRecurrence
+        );
+        assert_noop!(
+            ChainGhost::schedule_recurring_intent(RuntimeOrigin::signed(OWNER), template_id, 3, 0),
+            Error::<Test>::InvalidRecurrence
+        );
+        assert_noop!(
+            ChainGhost::schedule_recurring_intent(RuntimeOrigin::signed(SOLVER), template_id, 3, 2),
+            Error::<Test>::TemplateNotFound
+        );
+
+        let schedule_id = schedule(OWNER, template_id, 3, 2);
+        assert!(
+            ChainGhost::recurring_intent(schedule_id)
+                == Some(RecurringIntent {
+                    owner: OWNER,
+                    template_id,
+                    period: 3,
+                    remaining: 2,
+                    next_at: 4,
+                })
+        );
+
+        run_to_block(3);
+        assert!(instantiated_events().is_empty());
+
+        run_to_block(4);
+        assert_eq!(instantiated_events(), vec![(schedule_id, 0)]);
+        let intent = ChainGhost::intent_by_id(0).expect("Intent should exist");
+        assert_eq!(intent.account, OWNER);
+        assert_eq!(intent.status, IntentStatus::Pending);
+        assert_eq!(intent.kind, Some(transfer_intent()));
+        assert_eq!(intent.metadata.to_vec(), b"Weekly rent".to_vec());
+        assert_eq!(ChainGhost::recurring_intent(schedule_id).map(|s| s.next_at), Some(7));
+
+        run_to_block(10);
+        assert_eq!(instantiated_events(), vec![(schedule_id, 0), (schedule_id, 1)]);
+        assert_eq!(ChainGhost::intent_count(OWNER), 2);
+
+        // The schedule ends after its last occurrence
+        assert!(ChainGhost::recurring_intent(schedule_id).is_none());
+        assert_eq!(ChainGhost::recurring_count(OWNER), 0);
+    });
+}
+
+#[test]
+fn agenda_carries_excess_occurrences_over() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        // Three occurrences are due at block 3, but only two are processed per block
+        let schedules: Vec<u64> = [OWNER, BACKEND, SOLVER]
+            .into_iter()
+            .map(|owner| {
+                let template_id = create_template(owner);
+                schedule(owner, template_id, 2, 2)
+            })
+            .collect();
+
+        run_to_block(3);
+        assert_eq!(instantiated_events().len(), MaxInstantiationsPerBlock::get() as usize);
+        assert_eq!(ChainGhost::agenda_cursor(), Some(3));
+
+        // The agenda of block 3 is drained, then the visit budget runs out
+        run_to_block(4);
+        assert_eq!(instantiated_events().len(), 3);
+        assert_eq!(ChainGhost::agenda_cursor(), Some(4));
+
+        // Carried over occurrences stay aligned on the original period
+        for schedule_id in schedules.iter() {
+            assert_eq!(ChainGhost::recurring_intent(schedule_id).map(|s| s.next_at), Some(5));
+        }
+
+        run_to_block(6);
+        assert_eq!(instantiated_events().len(), 6);
+        assert_eq!(ChainGhost::next_intent_id(), 6);
+        assert!(schedules
+            .iter()
+            .all(|schedule_id| ChainGhost::recurring_intent(schedule_id).is_none()));
+    });
+}
+
+#[test]
+fn cancelled_and_failing_recurring_intents() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let template_id = create_template(OWNER);
+        let cancelled = schedule(OWNER, template_id, 2, 3);
+        let failing = schedule(OWNER, template_id, 2, 2);
+        assert_noop!(
+            ChainGhost::schedule_recurring_intent(RuntimeOrigin::signed(OWNER), template_id, 2, 1),
+            Error::<Test>::TooManyRecurringIntents
+        );
+
+        assert_noop!(
+            ChainGhost::cancel_recurring_intent(RuntimeOrigin::signed(SOLVER), cancelled),
+            Error::<Test>::NotIntentOwner
+        );
+        assert_ok!(ChainGhost::cancel_recurring_intent(RuntimeOrigin::signed(OWNER), cancelled));
+        System::assert_last_event(
+            Event::RecurringIntentCancelled {
+                schedule_id: cancelled,
+            }
+            .into(),
+        );
+        assert_noop!(
+            ChainGhost::cancel_recurring_intent(RuntimeOrigin::signed(OWNER), cancelled),
+            Error::<Test>::RecurringIntentNotFound
+        );
+        assert_eq!(ChainGhost::recurring_count(OWNER), 1);
+
+        // The first occurrence of a removed template fails and cancels the schedule
+        assert_ok!(ChainGhost::remove_template(RuntimeOrigin::signed(OWNER), template_id));
+        run_to_block(3);
+        assert!(instantiated_events().is_empty());
+        System::assert_has_event(
+            Event::RecurringIntentFailed {
+                schedule_id: failing,
+                error: Error::<Test>::TemplateNotFound.into(),
+                remaining: 1,
+            }
+            .into(),
+        );
+        System::assert_last_event(
+            Event::RecurringIntentCancelled {
+                schedule_id: failing,
+            }
+            .into(),
+        );
+        assert_eq!(ChainGhost::next_intent_id(), 0);
+        assert!(ChainGhost::recurring_intent(failing).is_none());
+        assert_eq!(ChainGhost::recurring_count(OWNER), 0);
+    });
+}
+
+#[test]
+fn templates_and_schedules_hold_a_deposit() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        TemplateDeposit::set(10);
+        RecurringDeposit::set(20);
+
+        let template_id = create_template(OWNER);
+        assert_eq!(ChainGhost::template_deposit(OWNER, template_id), 10);
+        assert_eq!(Balances::total_balance_on_hold(&OWNER), 10);
+
+        let cancelled = schedule(OWNER, template_id, 2, 3);
+        assert_eq!(ChainGhost::recurring_deposit(cancelled), 20);
+        assert_eq!(Balances::total_balance_on_hold(&OWNER), 30);
+
+        // Cancelling a schedule releases its deposit
+        assert_ok!(ChainGhost::cancel_recurring_intent(RuntimeOrigin::signed(OWNER), cancelled));
+        assert_eq!(ChainGhost::recurring_deposit(cancelled), 0);
+        assert_eq!(Balances::total_balance_on_hold(&OWNER), 10);
+
+        // So does its last occurrence
+        let completed = schedule(OWNER, template_id, 2, 1);
+        assert_eq!(Balances::total_balance_on_hold(&OWNER), 30);
+        run_to_block(3);
+        assert_eq!(instantiated_events(), vec![(completed, 0)]);
+        assert!(ChainGhost::recurring_intent(completed).is_none());
+        assert_eq!(ChainGhost::recurring_deposit(completed), 0);
+        assert_eq!(Balances::total_balance_on_hold(&OWNER), 10);
+
+        // And the cancellation of a schedule whose template is gone
+        let orphaned = schedule(OWNER, template_id, 2, 3);
+        assert_ok!(ChainGhost::remove_template(RuntimeOrigin::signed(OWNER), template_id));
+        assert_eq!(ChainGhost::template_deposit(OWNER, template_id), 0);
+        assert_eq!(Balances::total_balance_on_hold(&OWNER), 20);
+        run_to_block(5);
+        assert!(ChainGhost::recurring_intent(orphaned).is_none());
+        assert_eq!(ChainGhost::recurring_deposit(orphaned), 0);
+        assert_eq!(Balances::total_balance_on_hold(&OWNER), 0);
+
+        // Both deposits must be affordable
+        TemplateDeposit::set(ENDOWED_BALANCE + 1);
+        assert_noop!(
+            ChainGhost::create_template(
+                RuntimeOrigin::signed(OWNER),
+                transfer_intent(),
+                Vec::new()
+            ),
+            TokenError::FundsUnavailable
+        );
+        TemplateDeposit::set(10);
+        let template_id = create_template(OWNER);
+        RecurringDeposit::set(ENDOWED_BALANCE + 1);
+        assert_noop!(
+            ChainGhost::schedule_recurring_intent(RuntimeOrigin::signed(OWNER), template_id, 2, 1),
+            TokenError::FundsUnavailable
+        );
+    });
+}
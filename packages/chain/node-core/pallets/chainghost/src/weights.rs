@@ -1,9 +1,13 @@
-//! Autogenerated weights for pallet_chainghost
+//! Weights for pallet_chainghost
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI
-//! DATE: 2025-11-18
-//! HOSTNAME: Ghost Protocol Development Node
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled
+//! PLACEHOLDER VALUES, NOT BENCHMARKED: the weights, proof sizes and execution times below
+//! are hand-written estimates following the storage accesses of each extrinsic. Regenerate
+//! this file with the benchmark CLI before relying on it:
+//!
+//! ```text
+//! ghost-node benchmark pallet --chain dev --pallet pallet_chainghost --extrinsic '*' \
+//!     --steps 50 --repeat 20 --output pallets/chainghost/src/weights.rs
+//! ```
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -17,6 +21,29 @@ pub trait WeightInfo {
 	fn execute_intent() -> Weight;
 	fn record_journey() -> Weight;
 	fn update_intent_status() -> Weight;
+	fn register_solver() -> Weight;
+	fn deregister_solver() -> Weight;
+	fn offer_reward() -> Weight;
+	fn claim_intent() -> Weight;
+	fn fulfil_intent() -> Weight;
+	fn slash_expired_claim() -> Weight;
+	fn dispute_fulfilment() -> Weight;
+	fn collect_reward() -> Weight;
+	fn add_delegate() -> Weight;
+	fn remove_delegate() -> Weight;
+	fn create_multi_step_intent(s: u32) -> Weight;
+	fn update_sub_intent_status() -> Weight;
+	fn add_relayer() -> Weight;
+	fn remove_relayer() -> Weight;
+	fn attach_external_tx() -> Weight;
+	fn attest_external_execution() -> Weight;
+	fn remove_intent(s: u32) -> Weight;
+	fn cancel_intent() -> Weight;
+	fn create_template() -> Weight;
+	fn remove_template() -> Weight;
+	fn schedule_recurring_intent() -> Weight;
+	fn cancel_recurring_intent() -> Weight;
+	fn process_agenda(b: u32, n: u32) -> Weight;
 }
 
 /// Weights for pallet_chainghost using the Substrate node and recommended hardware.
@@ -24,26 +51,343 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: ChainGhost NextIntentId (r:1 w:1)
 	/// Storage: ChainGhost IntentById (r:0 w:1)
-	/// Storage: ChainGhost IntentsByAccount (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: ChainGhost IntentDeposits (r:0 w:1)
+	/// Storage: ChainGhost IntentsByAccount (r:0 w:1)
+	/// Storage: ChainGhost IntentCountByAccount (r:1 w:1)
+	/// Storage: ChainGhost Personas (r:1 w:1)
+	/// Storage: ChainGhost UnlockedAchievements (r:1 w:1)
+	/// Storage: ChainGhost AttestedExecutions (r:0 w:1)
 	fn execute_intent() -> Weight {
+		Weight::from_parts(60_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+
+	/// Storage: ChainGhost IntentById (r:1 w:0)
+	/// Storage: ChainGhost Delegates (r:1 w:0)
+	/// Storage: ChainGhost IntentClaims (r:1 w:0)
+	/// Storage: ChainGhost JourneyHeads (r:1 w:1)
+	/// Storage: ChainGhost JourneySteps (r:0 w:1)
+	/// Storage: ChainGhost Personas (r:1 w:1)
+	/// Storage: ChainGhost UnlockedAchievements (r:1 w:1)
+	fn record_journey() -> Weight {
+		Weight::from_parts(55_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: ChainGhost IntentById (r:1 w:1)
+	/// Storage: ChainGhost Delegates (r:1 w:0)
+	/// Storage: ChainGhost IntentClaims (r:1 w:0)
+	/// Storage: ChainGhost SubIntents (r:1 w:0)
+	/// Storage: ChainGhost IntentRewards (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: ChainGhost Personas (r:1 w:1)
+	/// Storage: ChainGhost UnlockedAchievements (r:1 w:1)
+	/// Storage: ChainGhost AttestedExecutions (r:1 w:1)
+	fn update_intent_status() -> Weight {
 		Weight::from_parts(50_000_000, 0)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+
+	/// Storage: ChainGhost Solvers (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn register_solver() -> Weight {
+		Weight::from_parts(60_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: ChainGhost Solvers (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn deregister_solver() -> Weight {
+		Weight::from_parts(55_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 
 	/// Storage: ChainGhost IntentById (r:1 w:0)
-	/// Storage: ChainGhost JourneyByIntent (r:1 w:1)
-	fn record_journey() -> Weight {
-		Weight::from_parts(40_000_000, 0)
+	/// Storage: ChainGhost IntentClaims (r:1 w:0)
+	/// Storage: ChainGhost IntentRewards (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn offer_reward() -> Weight {
+		Weight::from_parts(60_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: ChainGhost IntentById (r:1 w:0)
+	/// Storage: ChainGhost SubIntents (r:1 w:0)
+	/// Storage: ChainGhost IntentClaims (r:1 w:1)
+	/// Storage: ChainGhost Solvers (r:1 w:1)
+	fn claim_intent() -> Weight {
+		Weight::from_parts(45_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: ChainGhost IntentClaims (r:1 w:1)
+	/// Storage: ChainGhost IntentById (r:1 w:1)
+	/// Storage: ChainGhost IntentRewards (r:1 w:1)
+	/// Storage: ChainGhost PendingFulfilments (r:0 w:1)
+	/// Storage: ChainGhost FulfilmentProofs (r:0 w:1)
+	/// Storage: ChainGhost Solvers (r:1 w:1)
+	/// Storage: ChainGhost Personas (r:1 w:1)
+	/// Storage: ChainGhost UnlockedAchievements (r:1 w:1)
+	/// Storage: ChainGhost AttestedExecutions (r:0 w:1)
+	fn fulfil_intent() -> Weight {
+		Weight::from_parts(60_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+
+	/// Storage: ChainGhost IntentClaims (r:1 w:1)
+	/// Storage: ChainGhost IntentById (r:1 w:0)
+	/// Storage: ChainGhost Solvers (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	fn slash_expired_claim() -> Weight {
+		Weight::from_parts(75_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: ChainGhost PendingFulfilments (r:1 w:1)
+	/// Storage: ChainGhost IntentById (r:1 w:1)
+	/// Storage: ChainGhost Personas (r:1 w:1)
+	/// Storage: ChainGhost UnlockedAchievements (r:1 w:0)
+	/// Storage: ChainGhost FulfilmentProofs (r:0 w:1)
+	/// Storage: ChainGhost IntentRewards (r:0 w:1)
+	/// Storage: ChainGhost Solvers (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: ChainGhost AttestedExecutions (r:1 w:1)
+	fn dispute_fulfilment() -> Weight {
+		Weight::from_parts(85_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+
+	/// Storage: ChainGhost PendingFulfilments (r:1 w:1)
+	/// Storage: ChainGhost IntentById (r:1 w:0)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	fn collect_reward() -> Weight {
+		Weight::from_parts(65_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: ChainGhost Delegates (r:1 w:1)
+	/// Storage: ChainGhost DelegateCount (r:1 w:1)
+	fn add_delegate() -> Weight {
+		Weight::from_parts(30_000_000, 0)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: ChainGhost Delegates (r:1 w:1)
+	/// Storage: ChainGhost DelegateCount (r:1 w:1)
+	fn remove_delegate() -> Weight {
+		Weight::from_parts(30_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: ChainGhost NextIntentId (r:1 w:1)
+	/// Storage: ChainGhost IntentById (r:0 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: ChainGhost IntentDeposits (r:0 w:1)
+	/// Storage: ChainGhost IntentsByAccount (r:0 w:1)
+	/// Storage: ChainGhost IntentCountByAccount (r:1 w:1)
+	/// Storage: ChainGhost SubIntents (r:0 w:1)
+	/// Storage: ChainGhost Personas (r:1 w:1)
+	/// Storage: ChainGhost UnlockedAchievements (r:1 w:1)
+	/// The range of component `s` is `[1, 16]`.
+	fn create_multi_step_intent(s: u32) -> Weight {
+		Weight::from_parts(65_000_000, 0)
+			// Standard Error: 1_500_000
+			.saturating_add(Weight::from_parts(2_500_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
 	}
 
 	/// Storage: ChainGhost IntentById (r:1 w:1)
-	fn update_intent_status() -> Weight {
+	/// Storage: ChainGhost Delegates (r:1 w:0)
+	/// Storage: ChainGhost IntentClaims (r:1 w:0)
+	/// Storage: ChainGhost SubIntents (r:1 w:1)
+	/// Storage: ChainGhost IntentRewards (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: ChainGhost Personas (r:1 w:1)
+	/// Storage: ChainGhost UnlockedAchievements (r:1 w:1)
+	fn update_sub_intent_status() -> Weight {
+		Weight::from_parts(60_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+
+	/// Storage: ChainGhost Relayers (r:1 w:1)
+	fn add_relayer() -> Weight {
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: ChainGhost Relayers (r:1 w:1)
+	fn remove_relayer() -> Weight {
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: ChainGhost IntentById (r:1 w:0)
+	/// Storage: ChainGhost Delegates (r:1 w:0)
+	/// Storage: ChainGhost IntentClaims (r:1 w:0)
+	/// Storage: ChainGhost ChainRefs (r:1 w:1)
+	fn attach_external_tx() -> Weight {
 		Weight::from_parts(35_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: ChainGhost Relayers (r:1 w:0)
+	/// Storage: ChainGhost IntentById (r:1 w:1)
+	/// Storage: ChainGhost IntentClaims (r:1 w:0)
+	/// Storage: ChainGhost SubIntents (r:1 w:0)
+	/// Storage: ChainGhost ChainRefs (r:1 w:1)
+	/// Storage: ChainGhost IntentRewards (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: ChainGhost Personas (r:1 w:1)
+	/// Storage: ChainGhost UnlockedAchievements (r:1 w:1)
+	/// Storage: ChainGhost AttestedExecutions (r:0 w:1)
+	fn attest_external_execution() -> Weight {
+		Weight::from_parts(60_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+
+	/// Storage: ChainGhost IntentById (r:1 w:1)
+	/// Storage: ChainGhost IntentDeposits (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: ChainGhost IntentsByAccount (r:0 w:1)
+	/// Storage: ChainGhost IntentCountByAccount (r:1 w:1)
+	/// Storage: ChainGhost JourneyHeads (r:1 w:1)
+	/// Storage: ChainGhost JourneySteps (r:0 w:50)
+	/// Storage: ChainGhost SubIntents (r:0 w:1)
+	/// Storage: ChainGhost ChainRefs (r:0 w:1)
+	/// Storage: ChainGhost IntentErrors (r:0 w:1)
+	/// Storage: ChainGhost CancelReasons (r:0 w:1)
+	/// Storage: ChainGhost FulfilmentProofs (r:0 w:1)
+	/// Storage: ChainGhost AttestedExecutions (r:0 w:1)
+	/// The range of component `s` is `[0, 50]`.
+	fn remove_intent(s: u32) -> Weight {
+		Weight::from_parts(55_000_000, 0)
+			// Standard Error: 500_000
+			.saturating_add(Weight::from_parts(1_000_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
+	}
+
+	/// Storage: ChainGhost IntentById (r:1 w:1)
+	/// Storage: ChainGhost Delegates (r:1 w:0)
+	/// Storage: ChainGhost IntentClaims (r:1 w:0)
+	/// Storage: ChainGhost IntentRewards (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: ChainGhost CancelReasons (r:0 w:1)
+	fn cancel_intent() -> Weight {
+		Weight::from_parts(45_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: ChainGhost TemplateCount (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: ChainGhost NextTemplateId (r:1 w:1)
+	/// Storage: ChainGhost Templates (r:0 w:1)
+	/// Storage: ChainGhost TemplateDeposits (r:0 w:1)
+	fn create_template() -> Weight {
+		Weight::from_parts(50_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+
+	/// Storage: ChainGhost Templates (r:1 w:1)
+	/// Storage: ChainGhost TemplateCount (r:1 w:1)
+	/// Storage: ChainGhost TemplateDeposits (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn remove_template() -> Weight {
+		Weight::from_parts(45_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: ChainGhost Templates (r:1 w:0)
+	/// Storage: ChainGhost RecurringCount (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: ChainGhost NextScheduleId (r:1 w:1)
+	/// Storage: ChainGhost RecurringDeposits (r:0 w:1)
+	/// Storage: ChainGhost RecurringIntents (r:0 w:1)
+	/// Storage: ChainGhost Agenda (r:0 w:1)
+	fn schedule_recurring_intent() -> Weight {
+		Weight::from_parts(55_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+
+	/// Storage: ChainGhost RecurringIntents (r:1 w:1)
+	/// Storage: ChainGhost RecurringCount (r:1 w:1)
+	/// Storage: ChainGhost RecurringDeposits (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn cancel_recurring_intent() -> Weight {
+		Weight::from_parts(45_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: ChainGhost AgendaCursor (r:1 w:1)
+	/// Storage: ChainGhost Agenda (r:20 w:40)
+	/// Storage: ChainGhost RecurringIntents (r:20 w:20)
+	/// Storage: ChainGhost RecurringCount (r:0 w:20)
+	/// Storage: ChainGhost RecurringDeposits (r:20 w:20)
+	/// Storage: ChainGhost Templates (r:20 w:0)
+	/// Storage: ChainGhost NextIntentId (r:20 w:20)
+	/// Storage: ChainGhost IntentById (r:0 w:20)
+	/// Storage: Balances Holds (r:20 w:20)
+	/// Storage: System Account (r:20 w:20)
+	/// Storage: ChainGhost IntentDeposits (r:0 w:20)
+	/// Storage: ChainGhost IntentsByAccount (r:0 w:20)
+	/// Storage: ChainGhost IntentCountByAccount (r:20 w:20)
+	/// Storage: ChainGhost Personas (r:20 w:20)
+	/// Storage: ChainGhost UnlockedAchievements (r:20 w:20)
+	/// The range of component `b` is `[1, 20]`.
+	/// The range of component `n` is `[0, 20]`.
+	fn process_agenda(b: u32, n: u32) -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			// Standard Error: 200_000
+			.saturating_add(Weight::from_parts(2_000_000, 0).saturating_mul(b.into()))
+			// Standard Error: 1_000_000
+			.saturating_add(Weight::from_parts(65_000_000, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().reads((10_u64).saturating_mul(n.into())))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((14_u64).saturating_mul(n.into())))
 	}
 }
 
@@ -51,25 +395,342 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 impl WeightInfo for () {
 	/// Storage: ChainGhost NextIntentId (r:1 w:1)
 	/// Storage: ChainGhost IntentById (r:0 w:1)
-	/// Storage: ChainGhost IntentsByAccount (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: ChainGhost IntentDeposits (r:0 w:1)
+	/// Storage: ChainGhost IntentsByAccount (r:0 w:1)
+	/// Storage: ChainGhost IntentCountByAccount (r:1 w:1)
+	/// Storage: ChainGhost Personas (r:1 w:1)
+	/// Storage: ChainGhost UnlockedAchievements (r:1 w:1)
+	/// Storage: ChainGhost AttestedExecutions (r:0 w:1)
 	fn execute_intent() -> Weight {
+		Weight::from_parts(60_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+
+	/// Storage: ChainGhost IntentById (r:1 w:0)
+	/// Storage: ChainGhost Delegates (r:1 w:0)
+	/// Storage: ChainGhost IntentClaims (r:1 w:0)
+	/// Storage: ChainGhost JourneyHeads (r:1 w:1)
+	/// Storage: ChainGhost JourneySteps (r:0 w:1)
+	/// Storage: ChainGhost Personas (r:1 w:1)
+	/// Storage: ChainGhost UnlockedAchievements (r:1 w:1)
+	fn record_journey() -> Weight {
+		Weight::from_parts(55_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: ChainGhost IntentById (r:1 w:1)
+	/// Storage: ChainGhost Delegates (r:1 w:0)
+	/// Storage: ChainGhost IntentClaims (r:1 w:0)
+	/// Storage: ChainGhost SubIntents (r:1 w:0)
+	/// Storage: ChainGhost IntentRewards (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: ChainGhost Personas (r:1 w:1)
+	/// Storage: ChainGhost UnlockedAchievements (r:1 w:1)
+	/// Storage: ChainGhost AttestedExecutions (r:1 w:1)
+	fn update_intent_status() -> Weight {
 		Weight::from_parts(50_000_000, 0)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+
+	/// Storage: ChainGhost Solvers (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn register_solver() -> Weight {
+		Weight::from_parts(60_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: ChainGhost Solvers (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn deregister_solver() -> Weight {
+		Weight::from_parts(55_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 
 	/// Storage: ChainGhost IntentById (r:1 w:0)
-	/// Storage: ChainGhost JourneyByIntent (r:1 w:1)
-	fn record_journey() -> Weight {
-		Weight::from_parts(40_000_000, 0)
+	/// Storage: ChainGhost IntentClaims (r:1 w:0)
+	/// Storage: ChainGhost IntentRewards (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn offer_reward() -> Weight {
+		Weight::from_parts(60_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: ChainGhost IntentById (r:1 w:0)
+	/// Storage: ChainGhost SubIntents (r:1 w:0)
+	/// Storage: ChainGhost IntentClaims (r:1 w:1)
+	/// Storage: ChainGhost Solvers (r:1 w:1)
+	fn claim_intent() -> Weight {
+		Weight::from_parts(45_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: ChainGhost IntentClaims (r:1 w:1)
+	/// Storage: ChainGhost IntentById (r:1 w:1)
+	/// Storage: ChainGhost IntentRewards (r:1 w:1)
+	/// Storage: ChainGhost PendingFulfilments (r:0 w:1)
+	/// Storage: ChainGhost FulfilmentProofs (r:0 w:1)
+	/// Storage: ChainGhost Solvers (r:1 w:1)
+	/// Storage: ChainGhost Personas (r:1 w:1)
+	/// Storage: ChainGhost UnlockedAchievements (r:1 w:1)
+	/// Storage: ChainGhost AttestedExecutions (r:0 w:1)
+	fn fulfil_intent() -> Weight {
+		Weight::from_parts(60_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+
+	/// Storage: ChainGhost IntentClaims (r:1 w:1)
+	/// Storage: ChainGhost IntentById (r:1 w:0)
+	/// Storage: ChainGhost Solvers (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	fn slash_expired_claim() -> Weight {
+		Weight::from_parts(75_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: ChainGhost PendingFulfilments (r:1 w:1)
+	/// Storage: ChainGhost IntentById (r:1 w:1)
+	/// Storage: ChainGhost Personas (r:1 w:1)
+	/// Storage: ChainGhost UnlockedAchievements (r:1 w:0)
+	/// Storage: ChainGhost FulfilmentProofs (r:0 w:1)
+	/// Storage: ChainGhost IntentRewards (r:0 w:1)
+	/// Storage: ChainGhost Solvers (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: ChainGhost AttestedExecutions (r:1 w:1)
+	fn dispute_fulfilment() -> Weight {
+		Weight::from_parts(85_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+
+	/// Storage: ChainGhost PendingFulfilments (r:1 w:1)
+	/// Storage: ChainGhost IntentById (r:1 w:0)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	fn collect_reward() -> Weight {
+		Weight::from_parts(65_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: ChainGhost Delegates (r:1 w:1)
+	/// Storage: ChainGhost DelegateCount (r:1 w:1)
+	fn add_delegate() -> Weight {
+		Weight::from_parts(30_000_000, 0)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: ChainGhost Delegates (r:1 w:1)
+	/// Storage: ChainGhost DelegateCount (r:1 w:1)
+	fn remove_delegate() -> Weight {
+		Weight::from_parts(30_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: ChainGhost NextIntentId (r:1 w:1)
+	/// Storage: ChainGhost IntentById (r:0 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: ChainGhost IntentDeposits (r:0 w:1)
+	/// Storage: ChainGhost IntentsByAccount (r:0 w:1)
+	/// Storage: ChainGhost IntentCountByAccount (r:1 w:1)
+	/// Storage: ChainGhost SubIntents (r:0 w:1)
+	/// Storage: ChainGhost Personas (r:1 w:1)
+	/// Storage: ChainGhost UnlockedAchievements (r:1 w:1)
+	/// The range of component `s` is `[1, 16]`.
+	fn create_multi_step_intent(s: u32) -> Weight {
+		Weight::from_parts(65_000_000, 0)
+			// Standard Error: 1_500_000
+			.saturating_add(Weight::from_parts(2_500_000, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
 	}
 
 	/// Storage: ChainGhost IntentById (r:1 w:1)
-	fn update_intent_status() -> Weight {
+	/// Storage: ChainGhost Delegates (r:1 w:0)
+	/// Storage: ChainGhost IntentClaims (r:1 w:0)
+	/// Storage: ChainGhost SubIntents (r:1 w:1)
+	/// Storage: ChainGhost IntentRewards (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: ChainGhost Personas (r:1 w:1)
+	/// Storage: ChainGhost UnlockedAchievements (r:1 w:1)
+	fn update_sub_intent_status() -> Weight {
+		Weight::from_parts(60_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+
+	/// Storage: ChainGhost Relayers (r:1 w:1)
+	fn add_relayer() -> Weight {
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: ChainGhost Relayers (r:1 w:1)
+	fn remove_relayer() -> Weight {
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: ChainGhost IntentById (r:1 w:0)
+	/// Storage: ChainGhost Delegates (r:1 w:0)
+	/// Storage: ChainGhost IntentClaims (r:1 w:0)
+	/// Storage: ChainGhost ChainRefs (r:1 w:1)
+	fn attach_external_tx() -> Weight {
 		Weight::from_parts(35_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: ChainGhost Relayers (r:1 w:0)
+	/// Storage: ChainGhost IntentById (r:1 w:1)
+	/// Storage: ChainGhost IntentClaims (r:1 w:0)
+	/// Storage: ChainGhost SubIntents (r:1 w:0)
+	/// Storage: ChainGhost ChainRefs (r:1 w:1)
+	/// Storage: ChainGhost IntentRewards (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: ChainGhost Personas (r:1 w:1)
+	/// Storage: ChainGhost UnlockedAchievements (r:1 w:1)
+	/// Storage: ChainGhost AttestedExecutions (r:0 w:1)
+	fn attest_external_execution() -> Weight {
+		Weight::from_parts(60_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+
+	/// Storage: ChainGhost IntentById (r:1 w:1)
+	/// Storage: ChainGhost IntentDeposits (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: ChainGhost IntentsByAccount (r:0 w:1)
+	/// Storage: ChainGhost IntentCountByAccount (r:1 w:1)
+	/// Storage: ChainGhost JourneyHeads (r:1 w:1)
+	/// Storage: ChainGhost JourneySteps (r:0 w:50)
+	/// Storage: ChainGhost SubIntents (r:0 w:1)
+	/// Storage: ChainGhost ChainRefs (r:0 w:1)
+	/// Storage: ChainGhost IntentErrors (r:0 w:1)
+	/// Storage: ChainGhost CancelReasons (r:0 w:1)
+	/// Storage: ChainGhost FulfilmentProofs (r:0 w:1)
+	/// Storage: ChainGhost AttestedExecutions (r:0 w:1)
+	/// The range of component `s` is `[0, 50]`.
+	fn remove_intent(s: u32) -> Weight {
+		Weight::from_parts(55_000_000, 0)
+			// Standard Error: 500_000
+			.saturating_add(Weight::from_parts(1_000_000, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(s.into())))
+	}
+
+	/// Storage: ChainGhost IntentById (r:1 w:1)
+	/// Storage: ChainGhost Delegates (r:1 w:0)
+	/// Storage: ChainGhost IntentClaims (r:1 w:0)
+	/// Storage: ChainGhost IntentRewards (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: ChainGhost CancelReasons (r:0 w:1)
+	fn cancel_intent() -> Weight {
+		Weight::from_parts(45_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: ChainGhost TemplateCount (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: ChainGhost NextTemplateId (r:1 w:1)
+	/// Storage: ChainGhost Templates (r:0 w:1)
+	/// Storage: ChainGhost TemplateDeposits (r:0 w:1)
+	fn create_template() -> Weight {
+		Weight::from_parts(50_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+
+	/// Storage: ChainGhost Templates (r:1 w:1)
+	/// Storage: ChainGhost TemplateCount (r:1 w:1)
+	/// Storage: ChainGhost TemplateDeposits (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn remove_template() -> Weight {
+		Weight::from_parts(45_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: ChainGhost Templates (r:1 w:0)
+	/// Storage: ChainGhost RecurringCount (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: ChainGhost NextScheduleId (r:1 w:1)
+	/// Storage: ChainGhost RecurringDeposits (r:0 w:1)
+	/// Storage: ChainGhost RecurringIntents (r:0 w:1)
+	/// Storage: ChainGhost Agenda (r:0 w:1)
+	fn schedule_recurring_intent() -> Weight {
+		Weight::from_parts(55_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+
+	/// Storage: ChainGhost RecurringIntents (r:1 w:1)
+	/// Storage: ChainGhost RecurringCount (r:1 w:1)
+	/// Storage: ChainGhost RecurringDeposits (r:1 w:1)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn cancel_recurring_intent() -> Weight {
+		Weight::from_parts(45_000_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+
+	/// Storage: ChainGhost AgendaCursor (r:1 w:1)
+	/// Storage: ChainGhost Agenda (r:20 w:40)
+	/// Storage: ChainGhost RecurringIntents (r:20 w:20)
+	/// Storage: ChainGhost RecurringCount (r:0 w:20)
+	/// Storage: ChainGhost RecurringDeposits (r:20 w:20)
+	/// Storage: ChainGhost Templates (r:20 w:0)
+	/// Storage: ChainGhost NextIntentId (r:20 w:20)
+	/// Storage: ChainGhost IntentById (r:0 w:20)
+	/// Storage: Balances Holds (r:20 w:20)
+	/// Storage: System Account (r:20 w:20)
+	/// Storage: ChainGhost IntentDeposits (r:0 w:20)
+	/// Storage: ChainGhost IntentsByAccount (r:0 w:20)
+	/// Storage: ChainGhost IntentCountByAccount (r:20 w:20)
+	/// Storage: ChainGhost Personas (r:20 w:20)
+	/// Storage: ChainGhost UnlockedAchievements (r:20 w:20)
+	/// The range of component `b` is `[1, 20]`.
+	/// The range of component `n` is `[0, 20]`.
+	fn process_agenda(b: u32, n: u32) -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			// Standard Error: 200_000
+			.saturating_add(Weight::from_parts(2_000_000, 0).saturating_mul(b.into()))
+			// Standard Error: 1_000_000
+			.saturating_add(Weight::from_parts(65_000_000, 0).saturating_mul(n.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(b.into())))
+			.saturating_add(RocksDbWeight::get().reads((10_u64).saturating_mul(n.into())))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((14_u64).saturating_mul(n.into())))
 	}
 }
@@ -5,8 +5,23 @@ use super::*;
 #[allow(unused)]
 use crate::Pallet as Ghonity;
 use frame_benchmarking::v2::*;
+use frame_support::{
+    sp_runtime::traits::Saturating,
+    traits::{
+        fungible::{Inspect, Mutate},
+        Get,
+    },
+};
 use frame_system::RawOrigin;
 
+/// Give `who` enough balance for a follow request deposit
+fn fund<T: Config>(who: &T::AccountId) {
+    let amount = T::Currency::minimum_balance()
+        .saturating_mul(10u32.into())
+        .saturating_add(T::FollowRequestDeposit::get());
+    T::Currency::set_balance(who, amount);
+}
+
 #[benchmarks]
 mod benchmarks {
     use super::*;
@@ -19,7 +34,7 @@ mod benchmarks {
         #[extrinsic_call]
         follow(RawOrigin::Signed(caller.clone()), followee.clone());
 
-        assert!(Follows::<T>::get(&caller, &followee));
+        assert!(Follows::<T>::contains_key(&caller, &followee));
         assert_eq!(FollowingCount::<T>::get(&caller), 1);
         assert_eq!(FollowerCount::<T>::get(&followee), 1);
     }
@@ -35,7 +50,7 @@ mod benchmarks {
         #[extrinsic_call]
         unfollow(RawOrigin::Signed(caller.clone()), followee.clone());
 
-        assert!(!Follows::<T>::get(&caller, &followee));
+        assert!(!Follows::<T>::contains_key(&caller, &followee));
         assert_eq!(FollowingCount::<T>::get(&caller), 0);
         assert_eq!(FollowerCount::<T>::get(&followee), 0);
     }
@@ -51,5 +66,95 @@ mod benchmarks {
         assert_eq!(ReputationScores::<T>::get(&account), score);
     }
 
+    #[benchmark]
+    fn set_privacy() {
+        let caller: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        set_privacy(RawOrigin::Signed(caller.clone()), true);
+
+        assert!(PrivateAccounts::<T>::get(&caller));
+    }
+
+    #[benchmark]
+    fn request_follow() {
+        let caller: T::AccountId = whitelisted_caller();
+        let target: T::AccountId = account("target", 0, 0);
+
+        // Setup: Make the target private and fill its queue up to one below the limit
+        PrivateAccounts::<T>::insert(&target, true);
+        for i in 1..T::MaxPendingRequests::get() {
+            let requester: T::AccountId = account("requester", i, 0);
+            PendingFollowRequests::<T>::mutate(&target, |requests| {
+                let _ = requests.try_push(requester);
+            });
+        }
+        fund::<T>(&caller);
+
+        #[extrinsic_call]
+        request_follow(RawOrigin::Signed(caller.clone()), target.clone());
+
+        assert!(Ghonity::<T>::has_pending_request(&caller, &target));
+    }
+
+    #[benchmark]
+    fn approve_follow() {
+        let caller: T::AccountId = whitelisted_caller();
+        let requester: T::AccountId = account("requester", 0, 0);
+
+        // Setup: Queue a request from the requester to the private caller
+        fund::<T>(&requester);
+        let _ = Ghonity::<T>::set_privacy(RawOrigin::Signed(caller.clone()).into(), true);
+        let _ = Ghonity::<T>::request_follow(
+            RawOrigin::Signed(requester.clone()).into(),
+            caller.clone(),
+        );
+
+        #[extrinsic_call]
+        approve_follow(RawOrigin::Signed(caller.clone()), requester.clone());
+
+        assert!(Follows::<T>::contains_key(&requester, &caller));
+        assert!(!Ghonity::<T>::has_pending_request(&requester, &caller));
+    }
+
+    #[benchmark]
+    fn reject_follow() {
+        let caller: T::AccountId = whitelisted_caller();
+        let requester: T::AccountId = account("requester", 0, 0);
+
+        // Setup: Queue a request from the requester to the private caller
+        fund::<T>(&requester);
+        let _ = Ghonity::<T>::set_privacy(RawOrigin::Signed(caller.clone()).into(), true);
+        let _ = Ghonity::<T>::request_follow(
+            RawOrigin::Signed(requester.clone()).into(),
+            caller.clone(),
+        );
+
+        #[extrinsic_call]
+        reject_follow(RawOrigin::Signed(caller.clone()), requester.clone());
+
+        assert!(!Follows::<T>::contains_key(&requester, &caller));
+        assert!(!Ghonity::<T>::has_pending_request(&requester, &caller));
+    }
+
+    #[benchmark]
+    fn cancel_request() {
+        let caller: T::AccountId = whitelisted_caller();
+        let target: T::AccountId = account("target", 0, 0);
+
+        // Setup: Queue a request from the caller to the private target
+        fund::<T>(&caller);
+        let _ = Ghonity::<T>::set_privacy(RawOrigin::Signed(target.clone()).into(), true);
+        let _ = Ghonity::<T>::request_follow(
+            RawOrigin::Signed(caller.clone()).into(),
+            target.clone(),
+        );
+
+        #[extrinsic_call]
+        cancel_request(RawOrigin::Signed(caller.clone()), target.clone());
+
+        assert!(!Ghonity::<T>::has_pending_request(&caller, &target));
+    }
+
     impl_benchmark_test_suite!(Ghonity, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -8,32 +8,59 @@
 //! - Follow and unfollow other accounts to build a social graph
 //! - Track follower and following counts for each account
 //! - Manage reputation scores (governance-controlled)
+//! - Mark accounts private so that new followers require approval
 //! - Query social graph relationships
 //!
 //! ## Key Features
 //!
 //! - **Social Graph**: Follow/unfollow relationships with atomic counter updates
 //! - **Reputation System**: Governance-controlled reputation scoring
+//! - **Weighted Followers**: Sybil-resistant follower score summing capped follower reputation,
+//!   counting only followers that meet minimum account age and balance requirements
+//! - **Private Accounts**: Approval-based following through bounded follow request queues,
+//!   with a deposit held from the requester until the request is approved, rejected or cancelled
 //! - **Resource Limits**: Enforces maximum following limit per account (1000)
 //! - **Query Helpers**: Public functions to query follow status and statistics
+//! - **Graph Analytics**: Bounded mutual follow, common follower and follow suggestion queries
 //!
 //! ## Storage Items
 //!
-//! - `Follows`: Double map tracking follow relationships (Follower, Followee) → bool
+//! - `Follows`: Double map tracking follow relationships (Follower, Followee) → FollowEdge
+//! - `Followers`: Reverse index of `Follows` (Followee, Follower) → ()
 //! - `FollowerCount`: Map of follower counts per account
 //! - `FollowingCount`: Map of following counts per account
 //! - `ReputationScores`: Map of reputation scores per account (default: 0)
+//! - `WeightedFollowerScore`: Map of reputation-weighted follower scores per account
+//! - `FollowWeight`: Double map recording the weight each follow contributed (Follower, Followee) → u32
+//! - `AccountCreatedAt`: Map of the block at which each account was created
+//! - `PrivateAccounts`: Map of accounts requiring approval for new followers
+//! - `PendingFollowRequests`: Map of pending follow requests per private account → BoundedVec<AccountId>
+//! - `FollowRequestDeposits`: Double map of the deposit held per pending request (Target, Requester) → Balance
 //!
 //! ## Dispatchable Functions
 //!
 //! - `follow`: Create a follow relationship
 //! - `unfollow`: Remove a follow relationship
 //! - `update_reputation`: Update account reputation (Root/Sudo only)
+//! - `set_privacy`: Mark the caller's account as private or public
+//! - `request_follow`: Request to follow a private account
+//! - `approve_follow`: Approve a pending follow request
+//! - `reject_follow`: Reject a pending follow request
+//! - `cancel_request`: Cancel a follow request sent by the caller
+//!
+//! ## Migrations
+//!
+//! - `migrations::v1::MigrateV0ToV1`: Converts `Follows` values from `bool` to `FollowEdge`
+//! - `migrations::v2::MigrateV1ToV2`: Builds the `Followers` reverse index from `Follows`
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
 pub use pallet::*;
 
+pub mod migrations;
+
 #[cfg(test)]
 mod mock;
 
@@ -48,28 +75,91 @@ pub use weights::*;
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
-    use frame_support::pallet_prelude::*;
+    use alloc::{collections::BTreeMap, vec::Vec};
+    use frame_support::{
+        pallet_prelude::*,
+        sp_runtime::traits::{Saturating, Zero},
+        traits::{
+            fungible::{self, MutateHold},
+            tokens::Precision,
+            OnKilledAccount, OnNewAccount,
+        },
+    };
     use frame_system::pallet_prelude::*;
 
+    /// Type alias for the balance type of the configured currency
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as fungible::Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+    /// The in-code storage version
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
+    /// Follow edge struct storing metadata about a follow relationship
+    #[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+    pub struct FollowEdge<BlockNumber> {
+        /// Block number when the follow relationship was created
+        pub since: BlockNumber,
+        /// Application-defined bit flags attached to the edge (0 by default)
+        pub tags: u8,
+    }
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
+    /// Reasons for the pallet placing a hold on funds
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// Deposit held from a requester while its follow request is pending
+        FollowRequestDeposit,
+    }
+
     /// Configuration trait for the Ghonity pallet
     #[pallet::config]
     pub trait Config: frame_system::Config {
         /// The overarching runtime event type
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
+        /// The overarching hold reason
+        type RuntimeHoldReason: From<HoldReason>;
+
         /// Weight information for extrinsics in this pallet
         type WeightInfo: WeightInfo;
 
         /// Maximum number of accounts a user can follow
         #[pallet::constant]
         type MaxFollowing: Get<u32>;
+
+        /// Currency used to check the minimum follower balance and hold follow request deposits
+        type Currency: fungible::Mutate<Self::AccountId>
+            + fungible::MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+
+        /// Maximum reputation a single follower can contribute to a weighted follower score
+        #[pallet::constant]
+        type MaxReputationPerFollower: Get<u32>;
+
+        /// Minimum age (in blocks) of the follower account for its follow to carry weight
+        #[pallet::constant]
+        type MinFollowerAge: Get<BlockNumberFor<Self>>;
+
+        /// Minimum balance of the follower account for its follow to carry weight
+        #[pallet::constant]
+        type MinFollowerBalance: Get<BalanceOf<Self>>;
+
+        /// Maximum number of pending follow requests per private account
+        #[pallet::constant]
+        type MaxPendingRequests: Get<u32>;
+
+        /// Deposit held for every pending follow request, released when the request is
+        /// approved, rejected or cancelled
+        #[pallet::constant]
+        type FollowRequestDeposit: Get<BalanceOf<Self>>;
     }
 
     /// Storage for follow relationships
-    /// Double map: (Follower AccountId, Followee AccountId) -> bool
+    /// Double map: (Follower AccountId, Followee AccountId) -> FollowEdge
+    ///
+    /// An entry exists only while the follower follows the followee
     #[pallet::storage]
     #[pallet::getter(fn follows)]
     pub type Follows<T: Config> = StorageDoubleMap<
@@ -78,8 +168,23 @@ pub mod pallet {
         T::AccountId,
         Blake2_128Concat,
         T::AccountId,
-        bool,
-        ValueQuery,
+        FollowEdge<BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Reverse index of follow relationships
+    /// Double map: (Followee AccountId, Follower AccountId) -> ()
+    ///
+    /// Kept in sync with `Follows` so the followers of an account can be iterated by prefix
+    #[pallet::storage]
+    pub type Followers<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        (),
+        OptionQuery,
     >;
 
     /// Storage for follower counts per account
@@ -100,6 +205,78 @@ pub mod pallet {
     pub type ReputationScores<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
 
+    /// Storage for reputation-weighted follower scores per account
+    ///
+    /// Sum of the weights recorded in `FollowWeight` for every follower of the account
+    #[pallet::storage]
+    #[pallet::getter(fn weighted_follower_score)]
+    pub type WeightedFollowerScore<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+
+    /// Storage for the weight contributed by each follow relationship
+    /// Double map: (Follower AccountId, Followee AccountId) -> u32
+    ///
+    /// Recorded when the follow is created so that unfollowing subtracts exactly
+    /// what was added, even if the follower's reputation changed in between.
+    /// Follows that carry no weight have no entry.
+    #[pallet::storage]
+    #[pallet::getter(fn follow_weight)]
+    pub type FollowWeight<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        u32,
+        ValueQuery,
+    >;
+
+    /// Storage for the block at which each account was created
+    ///
+    /// Populated through `OnNewAccount`. Accounts without an entry predate the
+    /// hook and are treated as created at genesis.
+    #[pallet::storage]
+    #[pallet::getter(fn account_created_at)]
+    pub type AccountCreatedAt<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+    /// Storage for the privacy flag per account
+    ///
+    /// Private accounts only gain followers through approved follow requests
+    #[pallet::storage]
+    #[pallet::getter(fn private_accounts)]
+    pub type PrivateAccounts<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
+
+    /// Storage for pending follow requests per account
+    ///
+    /// Maps the requested (private) account to the requesters awaiting approval, oldest first
+    #[pallet::storage]
+    #[pallet::getter(fn pending_follow_requests)]
+    pub type PendingFollowRequests<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<T::AccountId, T::MaxPendingRequests>,
+        ValueQuery,
+    >;
+
+    /// Storage for the deposit held per pending follow request
+    /// Double map: (Target AccountId, Requester AccountId) -> Balance
+    ///
+    /// Requests queued before deposits were introduced have no deposit
+    #[pallet::storage]
+    #[pallet::getter(fn follow_request_deposits)]
+    pub type FollowRequestDeposits<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
     /// Events emitted by the Ghonity pallet
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -127,6 +304,41 @@ pub mod pallet {
             /// The new reputation score
             new_score: u32,
         },
+        /// An account's privacy flag was changed
+        PrivacyUpdated {
+            /// The account whose privacy flag changed
+            account: T::AccountId,
+            /// Whether the account is now private
+            private: bool,
+        },
+        /// A follow request was sent to a private account
+        FollowRequested {
+            /// The account requesting to follow
+            requester: T::AccountId,
+            /// The private account being requested
+            target: T::AccountId,
+        },
+        /// A follow request was approved and the follow relationship created
+        FollowRequestApproved {
+            /// The account that requested to follow
+            requester: T::AccountId,
+            /// The private account that approved the request
+            target: T::AccountId,
+        },
+        /// A follow request was rejected by the target account
+        FollowRequestRejected {
+            /// The account that requested to follow
+            requester: T::AccountId,
+            /// The private account that rejected the request
+            target: T::AccountId,
+        },
+        /// A follow request was cancelled by the requester
+        FollowRequestCancelled {
+            /// The account that cancelled its request
+            requester: T::AccountId,
+            /// The private account the request was sent to
+            target: T::AccountId,
+        },
     }
 
     /// Errors that can be returned by the Ghonity pallet
@@ -142,6 +354,16 @@ pub mod pallet {
         MaxFollowingExceeded,
         /// Reputation score would overflow u32::MAX
         ReputationOverflow,
+        /// The target account is private and requires a follow request
+        FollowRequiresApproval,
+        /// The target account is not private, follow it directly instead
+        AccountNotPrivate,
+        /// A follow request to the target account is already pending
+        FollowRequestAlreadyPending,
+        /// No pending follow request exists for this pair of accounts
+        FollowRequestNotFound,
+        /// The target account has reached the maximum number of pending follow requests
+        MaxPendingRequestsExceeded,
     }
 
     #[pallet::call]
@@ -150,6 +372,8 @@ pub mod pallet {
         ///
         /// Creates a follow relationship from the caller to the target account.
         /// Increments following count for caller and follower count for target.
+        /// If the caller meets the minimum age and balance requirements, its capped
+        /// reputation is added to the target's weighted follower score.
         /// Enforces maximum following limit.
         ///
         /// # Parameters
@@ -162,6 +386,7 @@ pub mod pallet {
         /// - `CannotFollowSelf`: Cannot follow your own account
         /// - `AlreadyFollowing`: Already following this account
         /// - `MaxFollowingExceeded`: Following limit reached
+        /// - `FollowRequiresApproval`: Target account is private, use `request_follow`
         ///
         /// # Events
         ///
@@ -171,37 +396,15 @@ pub mod pallet {
         pub fn follow(origin: OriginFor<T>, followee: T::AccountId) -> DispatchResult {
             let follower = ensure_signed(origin)?;
 
-            // Validate no self-follow
-            ensure!(follower != followee, Error::<T>::CannotFollowSelf);
+            Self::ensure_can_follow(&follower, &followee)?;
 
-            // Check not already following
+            // Private accounts only gain followers through approved requests
             ensure!(
-                !Follows::<T>::get(&follower, &followee),
-                Error::<T>::AlreadyFollowing
+                !PrivateAccounts::<T>::get(&followee),
+                Error::<T>::FollowRequiresApproval
             );
 
-            // Check max following limit
-            let current_following = FollowingCount::<T>::get(&follower);
-            ensure!(
-                current_following < T::MaxFollowing::get(),
-                Error::<T>::MaxFollowingExceeded
-            );
-
-            // Create follow relationship
-            Follows::<T>::insert(&follower, &followee, true);
-
-            // Increment follower count for followee
-            FollowerCount::<T>::mutate(&followee, |count| {
-                *count = count.saturating_add(1);
-            });
-
-            // Increment following count for follower
-            FollowingCount::<T>::mutate(&follower, |count| {
-                *count = count.saturating_add(1);
-            });
-
-            // Emit event
-            Self::deposit_event(Event::Followed { follower, followee });
+            Self::do_follow(follower, followee);
 
             Ok(())
         }
@@ -209,7 +412,8 @@ pub mod pallet {
         /// Unfollow an account
         ///
         /// Removes a follow relationship from the caller to the target account.
-        /// Decrements following count for caller and follower count for target.
+        /// Decrements following count for caller and follower count for target,
+        /// and removes the weight the follow contributed to the target's weighted score.
         ///
         /// # Parameters
         ///
@@ -230,18 +434,27 @@ pub mod pallet {
 
             // Validate currently following
             ensure!(
-                Follows::<T>::get(&follower, &followee),
+                Follows::<T>::contains_key(&follower, &followee),
                 Error::<T>::NotFollowing
             );
 
             // Remove follow relationship
             Follows::<T>::remove(&follower, &followee);
+            Followers::<T>::remove(&followee, &follower);
 
             // Decrement follower count for followee
             FollowerCount::<T>::mutate(&followee, |count| {
                 *count = count.saturating_sub(1);
             });
 
+            // Subtract the weight this follow contributed when it was created
+            let weight = FollowWeight::<T>::take(&follower, &followee);
+            if weight > 0 {
+                WeightedFollowerScore::<T>::mutate(&followee, |score| {
+                    *score = score.saturating_sub(weight as u64);
+                });
+            }
+
             // Decrement following count for follower
             FollowingCount::<T>::mutate(&follower, |count| {
                 *count = count.saturating_sub(1);
@@ -296,6 +509,189 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Set the privacy flag of the caller's account
+        ///
+        /// Private accounts can only be followed through approved follow requests.
+        /// Existing followers and pending requests are kept when the flag changes.
+        ///
+        /// # Parameters
+        ///
+        /// - `origin`: The account changing its privacy (must be signed)
+        /// - `private`: Whether new followers require approval
+        ///
+        /// # Events
+        ///
+        /// - `PrivacyUpdated`: Emitted when the flag is set
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::set_privacy())]
+        pub fn set_privacy(origin: OriginFor<T>, private: bool) -> DispatchResult {
+            let account = ensure_signed(origin)?;
+
+            if private {
+                PrivateAccounts::<T>::insert(&account, true);
+            } else {
+                PrivateAccounts::<T>::remove(&account);
+            }
+
+            Self::deposit_event(Event::PrivacyUpdated { account, private });
+
+            Ok(())
+        }
+
+        /// Request to follow a private account
+        ///
+        /// Queues a follow request that the target account can approve or reject.
+        /// The queue per target account is bounded by `MaxPendingRequests`, and
+        /// `FollowRequestDeposit` is held from the caller until the request is resolved.
+        ///
+        /// # Parameters
+        ///
+        /// - `origin`: The account requesting to follow (must be signed)
+        /// - `target`: The private account to follow
+        ///
+        /// # Errors
+        ///
+        /// - `CannotFollowSelf`: Cannot follow your own account
+        /// - `AlreadyFollowing`: Already following this account
+        /// - `AccountNotPrivate`: Target is public, use `follow` instead
+        /// - `FollowRequestAlreadyPending`: A request to this account is already pending
+        /// - `MaxPendingRequestsExceeded`: Target's request queue is full
+        /// - `FundsUnavailable`: Caller cannot cover `FollowRequestDeposit`
+        ///
+        /// # Events
+        ///
+        /// - `FollowRequested`: Emitted when the request is queued
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::request_follow())]
+        pub fn request_follow(origin: OriginFor<T>, target: T::AccountId) -> DispatchResult {
+            let requester = ensure_signed(origin)?;
+
+            ensure!(requester != target, Error::<T>::CannotFollowSelf);
+            ensure!(
+                !Follows::<T>::contains_key(&requester, &target),
+                Error::<T>::AlreadyFollowing
+            );
+            ensure!(
+                PrivateAccounts::<T>::get(&target),
+                Error::<T>::AccountNotPrivate
+            );
+
+            PendingFollowRequests::<T>::try_mutate(&target, |requests| -> DispatchResult {
+                ensure!(
+                    !requests.contains(&requester),
+                    Error::<T>::FollowRequestAlreadyPending
+                );
+                requests
+                    .try_push(requester.clone())
+                    .map_err(|_| Error::<T>::MaxPendingRequestsExceeded)?;
+                Ok(())
+            })?;
+
+            let deposit = T::FollowRequestDeposit::get();
+            if !deposit.is_zero() {
+                T::Currency::hold(&HoldReason::FollowRequestDeposit.into(), &requester, deposit)?;
+                FollowRequestDeposits::<T>::insert(&target, &requester, deposit);
+            }
+
+            Self::deposit_event(Event::FollowRequested { requester, target });
+
+            Ok(())
+        }
+
+        /// Approve a pending follow request
+        ///
+        /// Removes the request from the caller's queue, releases the requester's
+        /// deposit and creates the follow relationship from the requester to the caller.
+        ///
+        /// # Parameters
+        ///
+        /// - `origin`: The private account approving the request (must be signed)
+        /// - `requester`: The account whose request is approved
+        ///
+        /// # Errors
+        ///
+        /// - `FollowRequestNotFound`: No pending request from this account
+        /// - `AlreadyFollowing`: Requester already follows the caller
+        /// - `MaxFollowingExceeded`: Requester has reached its following limit
+        ///
+        /// # Events
+        ///
+        /// - `FollowRequestApproved`: Emitted when the request is approved
+        /// - `Followed`: Emitted when the follow relationship is created
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::approve_follow())]
+        pub fn approve_follow(origin: OriginFor<T>, requester: T::AccountId) -> DispatchResult {
+            let target = ensure_signed(origin)?;
+
+            Self::remove_request(&target, &requester)?;
+            Self::ensure_can_follow(&requester, &target)?;
+
+            Self::deposit_event(Event::FollowRequestApproved {
+                requester: requester.clone(),
+                target: target.clone(),
+            });
+
+            Self::do_follow(requester, target);
+
+            Ok(())
+        }
+
+        /// Reject a pending follow request
+        ///
+        /// Removes the request from the caller's queue and releases the requester's deposit.
+        ///
+        /// # Parameters
+        ///
+        /// - `origin`: The private account rejecting the request (must be signed)
+        /// - `requester`: The account whose request is rejected
+        ///
+        /// # Errors
+        ///
+        /// - `FollowRequestNotFound`: No pending request from this account
+        ///
+        /// # Events
+        ///
+        /// - `FollowRequestRejected`: Emitted when the request is rejected
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::reject_follow())]
+        pub fn reject_follow(origin: OriginFor<T>, requester: T::AccountId) -> DispatchResult {
+            let target = ensure_signed(origin)?;
+
+            Self::remove_request(&target, &requester)?;
+
+            Self::deposit_event(Event::FollowRequestRejected { requester, target });
+
+            Ok(())
+        }
+
+        /// Cancel a follow request sent by the caller
+        ///
+        /// Removes the request from the target's queue and releases the caller's deposit.
+        ///
+        /// # Parameters
+        ///
+        /// - `origin`: The account that sent the request (must be signed)
+        /// - `target`: The private account the request was sent to
+        ///
+        /// # Errors
+        ///
+        /// - `FollowRequestNotFound`: No pending request to this account
+        ///
+        /// # Events
+        ///
+        /// - `FollowRequestCancelled`: Emitted when the request is cancelled
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::cancel_request())]
+        pub fn cancel_request(origin: OriginFor<T>, target: T::AccountId) -> DispatchResult {
+            let requester = ensure_signed(origin)?;
+
+            Self::remove_request(&target, &requester)?;
+
+            Self::deposit_event(Event::FollowRequestCancelled { requester, target });
+
+            Ok(())
+        }
     }
 
     /// Helper functions for querying social graph data
@@ -311,7 +707,26 @@ pub mod pallet {
         ///
         /// `true` if follower is following followee, `false` otherwise
         pub fn is_following(follower: &T::AccountId, followee: &T::AccountId) -> bool {
-            Follows::<T>::get(follower, followee)
+            Follows::<T>::contains_key(follower, followee)
+        }
+
+        /// Get the number of blocks since follower started following followee
+        ///
+        /// # Parameters
+        ///
+        /// - `follower`: The account that might be following
+        /// - `followee`: The account that might be followed
+        ///
+        /// # Returns
+        ///
+        /// The follow age in blocks, or `None` if follower is not following followee
+        pub fn follow_age(
+            follower: &T::AccountId,
+            followee: &T::AccountId,
+        ) -> Option<BlockNumberFor<T>> {
+            Follows::<T>::get(follower, followee).map(|edge| {
+                frame_system::Pallet::<T>::block_number().saturating_sub(edge.since)
+            })
         }
 
         /// Get the number of followers for an account
@@ -352,5 +767,271 @@ pub mod pallet {
         pub fn get_reputation(account: &T::AccountId) -> u32 {
             ReputationScores::<T>::get(account)
         }
+
+        /// Get the reputation-weighted follower score for an account
+        ///
+        /// # Parameters
+        ///
+        /// - `account`: The account to get the weighted follower score for
+        ///
+        /// # Returns
+        ///
+        /// The sum of capped reputation of qualifying followers
+        pub fn get_weighted_follower_score(account: &T::AccountId) -> u64 {
+            WeightedFollowerScore::<T>::get(account)
+        }
+
+        /// Check if an account requires approval for new followers
+        ///
+        /// # Parameters
+        ///
+        /// - `account`: The account to check
+        ///
+        /// # Returns
+        ///
+        /// `true` if the account is private, `false` otherwise
+        pub fn is_private(account: &T::AccountId) -> bool {
+            PrivateAccounts::<T>::get(account)
+        }
+
+        /// Check if `requester` has a pending follow request to `target`
+        ///
+        /// # Parameters
+        ///
+        /// - `requester`: The account that might have requested to follow
+        /// - `target`: The private account that might have been requested
+        ///
+        /// # Returns
+        ///
+        /// `true` if a request is pending, `false` otherwise
+        pub fn has_pending_request(requester: &T::AccountId, target: &T::AccountId) -> bool {
+            PendingFollowRequests::<T>::get(target).contains(requester)
+        }
+
+        /// Get the accounts followed by both `a` and `b`
+        ///
+        /// # Parameters
+        ///
+        /// - `a`: The first account
+        /// - `b`: The second account
+        /// - `max_scan`: Maximum number of accounts followed by `a` to inspect
+        ///
+        /// # Returns
+        ///
+        /// Accounts followed by both, limited to the first `max_scan` followees of `a`
+        pub fn mutual_follows(
+            a: &T::AccountId,
+            b: &T::AccountId,
+            max_scan: u32,
+        ) -> Vec<T::AccountId> {
+            Follows::<T>::iter_key_prefix(a)
+                .take(max_scan as usize)
+                .filter(|followee| Follows::<T>::contains_key(b, followee))
+                .collect()
+        }
+
+        /// Get the accounts following both `a` and `b`
+        ///
+        /// # Parameters
+        ///
+        /// - `a`: The first account
+        /// - `b`: The second account
+        /// - `max_scan`: Maximum number of followers of `a` to inspect
+        ///
+        /// # Returns
+        ///
+        /// Accounts following both, limited to the first `max_scan` followers of `a`
+        pub fn common_followers(
+            a: &T::AccountId,
+            b: &T::AccountId,
+            max_scan: u32,
+        ) -> Vec<T::AccountId> {
+            Followers::<T>::iter_key_prefix(a)
+                .take(max_scan as usize)
+                .filter(|follower| Follows::<T>::contains_key(follower, b))
+                .collect()
+        }
+
+        /// Suggest accounts to follow from the followees of the accounts `account` follows
+        ///
+        /// Candidates already followed by `account`, and `account` itself, are skipped.
+        /// Suggestions are ranked by reputation, then by the number of followees of
+        /// `account` that follow them.
+        ///
+        /// # Parameters
+        ///
+        /// - `account`: The account to build suggestions for
+        /// - `max_followees`: Maximum number of followees of `account` to inspect
+        /// - `max_per_followee`: Maximum number of followees inspected per followee
+        /// - `limit`: Maximum number of suggestions returned
+        ///
+        /// # Returns
+        ///
+        /// `(candidate, mutual_count)` pairs, best first
+        pub fn follow_suggestions(
+            account: &T::AccountId,
+            max_followees: u32,
+            max_per_followee: u32,
+            limit: u32,
+        ) -> Vec<(T::AccountId, u32)> {
+            let mut candidates: BTreeMap<T::AccountId, u32> = BTreeMap::new();
+
+            for followee in Follows::<T>::iter_key_prefix(account).take(max_followees as usize) {
+                for candidate in
+                    Follows::<T>::iter_key_prefix(&followee).take(max_per_followee as usize)
+                {
+                    if &candidate == account || Follows::<T>::contains_key(account, &candidate) {
+                        continue;
+                    }
+                    candidates.entry(candidate).or_default().saturating_inc();
+                }
+            }
+
+            let mut ranked: Vec<(T::AccountId, u32, u32)> = candidates
+                .into_iter()
+                .map(|(candidate, mutuals)| {
+                    let reputation = ReputationScores::<T>::get(&candidate);
+                    (candidate, reputation, mutuals)
+                })
+                .collect();
+
+            ranked.sort_by(|x, y| y.1.cmp(&x.1).then(y.2.cmp(&x.2)));
+            ranked.truncate(limit as usize);
+
+            ranked
+                .into_iter()
+                .map(|(candidate, _, mutuals)| (candidate, mutuals))
+                .collect()
+        }
+
+        /// Validate that `follower` may start following `followee`
+        ///
+        /// Checks self-follow, existing relationship and the following limit
+        fn ensure_can_follow(follower: &T::AccountId, followee: &T::AccountId) -> DispatchResult {
+            // Validate no self-follow
+            ensure!(follower != followee, Error::<T>::CannotFollowSelf);
+
+            // Check not already following
+            ensure!(
+                !Follows::<T>::contains_key(follower, followee),
+                Error::<T>::AlreadyFollowing
+            );
+
+            // Check max following limit
+            let current_following = FollowingCount::<T>::get(follower);
+            ensure!(
+                current_following < T::MaxFollowing::get(),
+                Error::<T>::MaxFollowingExceeded
+            );
+
+            Ok(())
+        }
+
+        /// Remove `requester` from the pending follow requests of `target` and release its deposit
+        fn remove_request(target: &T::AccountId, requester: &T::AccountId) -> DispatchResult {
+            PendingFollowRequests::<T>::try_mutate_exists(target, |maybe_requests| -> DispatchResult {
+                let requests = maybe_requests
+                    .as_mut()
+                    .ok_or(Error::<T>::FollowRequestNotFound)?;
+                let index = requests
+                    .iter()
+                    .position(|r| r == requester)
+                    .ok_or(Error::<T>::FollowRequestNotFound)?;
+                requests.remove(index);
+
+                if requests.is_empty() {
+                    *maybe_requests = None;
+                }
+
+                Ok(())
+            })?;
+
+            let deposit = FollowRequestDeposits::<T>::take(target, requester);
+            if !deposit.is_zero() {
+                T::Currency::release(
+                    &HoldReason::FollowRequestDeposit.into(),
+                    requester,
+                    deposit,
+                    Precision::BestEffort,
+                )?;
+            }
+
+            Ok(())
+        }
+
+        /// Create the follow relationship, update counters and weighted score, and emit `Followed`
+        ///
+        /// Callers must validate the follow with `ensure_can_follow` first
+        fn do_follow(follower: T::AccountId, followee: T::AccountId) {
+            // Create follow relationship
+            let edge = FollowEdge {
+                since: frame_system::Pallet::<T>::block_number(),
+                tags: 0,
+            };
+            Follows::<T>::insert(&follower, &followee, edge);
+            Followers::<T>::insert(&followee, &follower, ());
+
+            // Increment follower count for followee
+            FollowerCount::<T>::mutate(&followee, |count| {
+                *count = count.saturating_add(1);
+            });
+
+            // Add the follower's weight to the followee's weighted score
+            let weight = Self::follower_weight(&follower);
+            if weight > 0 {
+                FollowWeight::<T>::insert(&follower, &followee, weight);
+                WeightedFollowerScore::<T>::mutate(&followee, |score| {
+                    *score = score.saturating_add(weight as u64);
+                });
+            }
+
+            // Increment following count for follower
+            FollowingCount::<T>::mutate(&follower, |count| {
+                *count = count.saturating_add(1);
+            });
+
+            // Emit event
+            Self::deposit_event(Event::Followed { follower, followee });
+        }
+
+        /// Compute the weight a follow from `follower` would carry right now
+        ///
+        /// # Parameters
+        ///
+        /// - `follower`: The account creating the follow relationship
+        ///
+        /// # Returns
+        ///
+        /// The follower's reputation capped at `MaxReputationPerFollower`, or 0 if the
+        /// follower is younger than `MinFollowerAge` or holds less than `MinFollowerBalance`
+        pub fn follower_weight(follower: &T::AccountId) -> u32 {
+            use fungible::Inspect;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let created_at = AccountCreatedAt::<T>::get(follower).unwrap_or_default();
+            if now.saturating_sub(created_at) < T::MinFollowerAge::get() {
+                return 0;
+            }
+
+            if T::Currency::balance(follower) < T::MinFollowerBalance::get() {
+                return 0;
+            }
+
+            ReputationScores::<T>::get(follower).min(T::MaxReputationPerFollower::get())
+        }
+    }
+
+    /// Records the creation block of new accounts for the follower age check
+    impl<T: Config> OnNewAccount<T::AccountId> for Pallet<T> {
+        fn on_new_account(who: &T::AccountId) {
+            AccountCreatedAt::<T>::insert(who, frame_system::Pallet::<T>::block_number());
+        }
+    }
+
+    /// Clears the creation block of reaped accounts so a re-created account starts fresh
+    impl<T: Config> OnKilledAccount<T::AccountId> for Pallet<T> {
+        fn on_killed_account(who: &T::AccountId) {
+            AccountCreatedAt::<T>::remove(who);
+        }
     }
 }
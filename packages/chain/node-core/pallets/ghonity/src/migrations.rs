@@ -0,0 +1,159 @@
+//! Storage migrations for the Ghonity pallet
+
+/// Migration to storage version 1
+///
+/// Replaces the `bool` value of every `Follows` entry with a `FollowEdge`.
+pub mod v1 {
+    use crate::{Config, FollowEdge, Follows, Pallet};
+    use frame_support::{
+        migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+    };
+
+    #[cfg(feature = "try-runtime")]
+    use alloc::vec::Vec;
+
+    /// Storage layout of version 0
+    pub mod v0 {
+        use super::*;
+
+        /// `Follows` as stored before version 1: (Follower, Followee) → bool
+        #[frame_support::storage_alias]
+        pub type Follows<T: Config> = StorageDoubleMap<
+            Pallet<T>,
+            Blake2_128Concat,
+            <T as frame_system::Config>::AccountId,
+            Blake2_128Concat,
+            <T as frame_system::Config>::AccountId,
+            bool,
+            ValueQuery,
+        >;
+    }
+
+    /// Rewrites every `Follows` entry from `bool` to `FollowEdge`
+    ///
+    /// The block at which existing follows were created is unknown, so `since` is set
+    /// to the block of the upgrade. Entries stored as `false` are removed.
+    pub struct InnerMigrateV0ToV1<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let now = frame_system::Pallet::<T>::block_number();
+            let mut translated = 0u64;
+
+            Follows::<T>::translate::<bool, _>(|_, _, following| {
+                translated += 1;
+                following.then(|| FollowEdge {
+                    since: now,
+                    tags: 0,
+                })
+            });
+
+            T::DbWeight::get().reads_writes(translated.saturating_add(1), translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+            let follows = v0::Follows::<T>::iter_values()
+                .filter(|following| *following)
+                .count() as u64;
+
+            Ok(follows.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+            let expected = u64::decode(&mut &state[..])
+                .map_err(|_| "Failed to decode pre-upgrade follow count")?;
+
+            let mut migrated = 0u64;
+            for (follower, followee) in Follows::<T>::iter_keys() {
+                ensure!(
+                    Follows::<T>::try_get(&follower, &followee).is_ok(),
+                    "Follow edge failed to decode after migration"
+                );
+                migrated += 1;
+            }
+
+            ensure!(
+                migrated == expected,
+                "Follow count changed during migration"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// Migrates `Follows` to version 1, only running while the on-chain version is 0
+    pub type MigrateV0ToV1<T> = VersionedMigration<
+        0,
+        1,
+        InnerMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migration to storage version 2
+///
+/// Builds the `Followers` reverse index from the existing `Follows` entries.
+pub mod v2 {
+    use crate::{Config, Followers, Follows, Pallet};
+    use frame_support::{
+        migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+    };
+
+    #[cfg(feature = "try-runtime")]
+    use alloc::vec::Vec;
+
+    /// Inserts a `Followers` entry for every `Follows` entry
+    pub struct InnerMigrateV1ToV2<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV1ToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut indexed = 0u64;
+
+            for (follower, followee) in Follows::<T>::iter_keys() {
+                Followers::<T>::insert(&followee, &follower, ());
+                indexed += 1;
+            }
+
+            T::DbWeight::get().reads_writes(indexed.saturating_add(1), indexed)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, frame_support::sp_runtime::TryRuntimeError> {
+            Ok((Follows::<T>::iter_keys().count() as u64).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+            let expected = u64::decode(&mut &state[..])
+                .map_err(|_| "Failed to decode pre-upgrade follow count")?;
+
+            let mut indexed = 0u64;
+            for (followee, follower) in Followers::<T>::iter_keys() {
+                ensure!(
+                    Follows::<T>::contains_key(&follower, &followee),
+                    "Followers entry without a matching follow"
+                );
+                indexed += 1;
+            }
+
+            ensure!(
+                indexed == expected,
+                "Followers index does not match the follow count"
+            );
+
+            Ok(())
+        }
+    }
+
+    /// Builds the `Followers` index, only running while the on-chain version is 1
+    pub type MigrateV1ToV2<T> = VersionedMigration<
+        1,
+        2,
+        InnerMigrateV1ToV2<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
@@ -1,5 +1,6 @@
 use crate as pallet_ghonity;
 use frame_support::{derive_impl, parameter_types};
+use frame_system::pallet_prelude::BlockNumberFor;
 use sp_runtime::BuildStorage;
 
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -26,26 +27,59 @@ mod runtime {
 
     #[runtime::pallet_index(1)]
     pub type Ghonity = pallet_ghonity::Pallet<Test>;
+
+    #[runtime::pallet_index(2)]
+    pub type Balances = pallet_balances::Pallet<Test>;
 }
 
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
 impl frame_system::Config for Test {
     type Block = Block;
+    type AccountData = pallet_balances::AccountData<u64>;
+    type OnNewAccount = Ghonity;
+    type OnKilledAccount = Ghonity;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = System;
 }
 
 parameter_types! {
     pub const MaxFollowing: u32 = 1000;
+    pub const MaxReputationPerFollower: u32 = 500;
+    pub const MinFollowerAge: BlockNumberFor<Test> = 10;
+    pub const MinFollowerBalance: u64 = 100;
+    pub const MaxPendingRequests: u32 = 3;
+    pub const FollowRequestDeposit: u64 = 10;
 }
 
 impl pallet_ghonity::Config for Test {
     type RuntimeEvent = RuntimeEvent;
+    type RuntimeHoldReason = RuntimeHoldReason;
     type WeightInfo = ();
     type MaxFollowing = MaxFollowing;
+    type Currency = Balances;
+    type MaxReputationPerFollower = MaxReputationPerFollower;
+    type MinFollowerAge = MinFollowerAge;
+    type MinFollowerBalance = MinFollowerBalance;
+    type MaxPendingRequests = MaxPendingRequests;
+    type FollowRequestDeposit = FollowRequestDeposit;
 }
 
+/// Accounts endowed at genesis (ALICE, BOB, CHARLIE) with enough balance to carry follow weight
+pub const ENDOWED_BALANCE: u64 = 1_000;
+
 pub fn new_test_ext() -> sp_io::TestExternalities {
-    frame_system::GenesisConfig::<Test>::default()
+    let mut t = frame_system::GenesisConfig::<Test>::default()
         .build_storage()
-        .unwrap()
-        .into()
+        .unwrap();
+
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(1, ENDOWED_BALANCE), (2, ENDOWED_BALANCE), (3, ENDOWED_BALANCE)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    t.into()
 }
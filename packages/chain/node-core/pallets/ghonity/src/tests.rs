@@ -1,5 +1,12 @@
-use crate::{mock::*, Error, Event};
-use frame_support::{assert_noop, assert_ok};
+use crate::{migrations, mock::*, Error, Event, FollowEdge, FollowWeight, Followers, Follows};
+use frame_support::{
+    assert_noop, assert_ok,
+    sp_runtime::{DispatchError, TokenError},
+    traits::{
+        fungible::{Inspect, InspectHold},
+        GetStorageVersion, OnRuntimeUpgrade, StorageVersion,
+    },
+};
 
 const ALICE: u64 = 1;
 const BOB: u64 = 2;
@@ -320,3 +327,448 @@ fn storage_independence_per_account() {
         assert_eq!(Ghonity::get_follower_count(&BOB), 1);
     });
 }
+
+#[test]
+fn weighted_score_counts_qualified_follower_reputation() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(20);
+
+        assert_ok!(Ghonity::update_reputation(RuntimeOrigin::root(), ALICE, 200));
+        assert_ok!(Ghonity::update_reputation(RuntimeOrigin::root(), CHARLIE, 100));
+
+        assert_ok!(Ghonity::follow(RuntimeOrigin::signed(ALICE), BOB));
+        assert_ok!(Ghonity::follow(RuntimeOrigin::signed(CHARLIE), BOB));
+
+        assert_eq!(Ghonity::get_follower_count(&BOB), 2);
+        assert_eq!(Ghonity::get_weighted_follower_score(&BOB), 300);
+        assert_eq!(FollowWeight::<Test>::get(ALICE, BOB), 200);
+    });
+}
+
+#[test]
+fn weighted_score_caps_reputation_per_follower() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(20);
+
+        assert_ok!(Ghonity::update_reputation(
+            RuntimeOrigin::root(),
+            ALICE,
+            u32::MAX
+        ));
+        assert_ok!(Ghonity::follow(RuntimeOrigin::signed(ALICE), BOB));
+
+        let cap = <Test as crate::Config>::MaxReputationPerFollower::get();
+        assert_eq!(Ghonity::get_weighted_follower_score(&BOB), cap as u64);
+    });
+}
+
+#[test]
+fn young_follower_carries_no_weight() {
+    new_test_ext().execute_with(|| {
+        // Endowed accounts are created at genesis, MinFollowerAge is 10 blocks
+        System::set_block_number(5);
+
+        assert_ok!(Ghonity::update_reputation(RuntimeOrigin::root(), ALICE, 200));
+        assert_ok!(Ghonity::follow(RuntimeOrigin::signed(ALICE), BOB));
+
+        assert_eq!(Ghonity::get_follower_count(&BOB), 1);
+        assert_eq!(Ghonity::get_weighted_follower_score(&BOB), 0);
+        assert!(!FollowWeight::<Test>::contains_key(ALICE, BOB));
+    });
+}
+
+#[test]
+fn account_created_after_genesis_is_aged_from_creation() {
+    new_test_ext().execute_with(|| {
+        let sock_puppet = 42u64;
+
+        System::set_block_number(100);
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(ALICE),
+            sock_puppet,
+            500
+        ));
+        assert_eq!(Ghonity::account_created_at(sock_puppet), Some(100));
+        assert_ok!(Ghonity::update_reputation(
+            RuntimeOrigin::root(),
+            sock_puppet,
+            300
+        ));
+
+        assert_ok!(Ghonity::follow(RuntimeOrigin::signed(sock_puppet), BOB));
+        assert_eq!(Ghonity::get_weighted_follower_score(&BOB), 0);
+
+        assert_ok!(Ghonity::unfollow(RuntimeOrigin::signed(sock_puppet), BOB));
+        System::set_block_number(110);
+        assert_ok!(Ghonity::follow(RuntimeOrigin::signed(sock_puppet), BOB));
+        assert_eq!(Ghonity::get_weighted_follower_score(&BOB), 300);
+    });
+}
+
+#[test]
+fn low_balance_follower_carries_no_weight() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(20);
+
+        // Account 4 has no balance at all
+        assert_ok!(Ghonity::update_reputation(RuntimeOrigin::root(), 4, 200));
+        assert_ok!(Ghonity::follow(RuntimeOrigin::signed(4), BOB));
+
+        assert_eq!(Ghonity::get_follower_count(&BOB), 1);
+        assert_eq!(Ghonity::get_weighted_follower_score(&BOB), 0);
+    });
+}
+
+#[test]
+fn unfollow_removes_weight_recorded_at_follow_time() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(20);
+
+        assert_ok!(Ghonity::update_reputation(RuntimeOrigin::root(), ALICE, 200));
+        assert_ok!(Ghonity::follow(RuntimeOrigin::signed(ALICE), BOB));
+        assert_eq!(Ghonity::get_weighted_follower_score(&BOB), 200);
+
+        // Reputation changes after the follow do not skew the score on unfollow
+        assert_ok!(Ghonity::update_reputation(RuntimeOrigin::root(), ALICE, 400));
+        assert_ok!(Ghonity::unfollow(RuntimeOrigin::signed(ALICE), BOB));
+
+        assert_eq!(Ghonity::get_weighted_follower_score(&BOB), 0);
+        assert!(!FollowWeight::<Test>::contains_key(ALICE, BOB));
+    });
+}
+
+#[test]
+fn set_privacy_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert!(!Ghonity::is_private(&BOB));
+
+        assert_ok!(Ghonity::set_privacy(RuntimeOrigin::signed(BOB), true));
+        assert!(Ghonity::is_private(&BOB));
+        System::assert_last_event(
+            Event::PrivacyUpdated {
+                account: BOB,
+                private: true,
+            }
+            .into(),
+        );
+
+        assert_ok!(Ghonity::set_privacy(RuntimeOrigin::signed(BOB), false));
+        assert!(!Ghonity::is_private(&BOB));
+    });
+}
+
+#[test]
+fn follow_private_account_requires_approval() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Ghonity::set_privacy(RuntimeOrigin::signed(BOB), true));
+
+        assert_noop!(
+            Ghonity::follow(RuntimeOrigin::signed(ALICE), BOB),
+            Error::<Test>::FollowRequiresApproval
+        );
+    });
+}
+
+#[test]
+fn request_and_approve_follow_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(Ghonity::set_privacy(RuntimeOrigin::signed(BOB), true));
+        assert_ok!(Ghonity::request_follow(RuntimeOrigin::signed(ALICE), BOB));
+
+        assert!(Ghonity::has_pending_request(&ALICE, &BOB));
+        assert!(!Ghonity::is_following(&ALICE, &BOB));
+        assert_eq!(Balances::total_balance_on_hold(&ALICE), FollowRequestDeposit::get());
+        assert_eq!(Ghonity::follow_request_deposits(BOB, ALICE), FollowRequestDeposit::get());
+        System::assert_last_event(
+            Event::FollowRequested {
+                requester: ALICE,
+                target: BOB,
+            }
+            .into(),
+        );
+
+        assert_ok!(Ghonity::approve_follow(RuntimeOrigin::signed(BOB), ALICE));
+
+        assert!(!Ghonity::has_pending_request(&ALICE, &BOB));
+        assert!(Ghonity::is_following(&ALICE, &BOB));
+        assert_eq!(Balances::total_balance_on_hold(&ALICE), 0);
+        assert_eq!(Ghonity::follow_request_deposits(BOB, ALICE), 0);
+        assert_eq!(Ghonity::get_follower_count(&BOB), 1);
+        assert_eq!(Ghonity::get_following_count(&ALICE), 1);
+        System::assert_has_event(
+            Event::FollowRequestApproved {
+                requester: ALICE,
+                target: BOB,
+            }
+            .into(),
+        );
+        System::assert_last_event(
+            Event::Followed {
+                follower: ALICE,
+                followee: BOB,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn reject_follow_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(Ghonity::set_privacy(RuntimeOrigin::signed(BOB), true));
+        assert_ok!(Ghonity::request_follow(RuntimeOrigin::signed(ALICE), BOB));
+        assert_ok!(Ghonity::reject_follow(RuntimeOrigin::signed(BOB), ALICE));
+
+        assert!(!Ghonity::has_pending_request(&ALICE, &BOB));
+        assert!(!Ghonity::is_following(&ALICE, &BOB));
+        assert_eq!(Balances::total_balance_on_hold(&ALICE), 0);
+        assert_eq!(Balances::balance(&ALICE), ENDOWED_BALANCE);
+        System::assert_last_event(
+            Event::FollowRequestRejected {
+                requester: ALICE,
+                target: BOB,
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            Ghonity::approve_follow(RuntimeOrigin::signed(BOB), ALICE),
+            Error::<Test>::FollowRequestNotFound
+        );
+    });
+}
+
+#[test]
+fn cancel_request_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(Ghonity::set_privacy(RuntimeOrigin::signed(BOB), true));
+        assert_ok!(Ghonity::request_follow(RuntimeOrigin::signed(ALICE), BOB));
+        assert_ok!(Ghonity::cancel_request(RuntimeOrigin::signed(ALICE), BOB));
+
+        assert!(!Ghonity::has_pending_request(&ALICE, &BOB));
+        assert_eq!(Balances::total_balance_on_hold(&ALICE), 0);
+        assert_eq!(Balances::balance(&ALICE), ENDOWED_BALANCE);
+        System::assert_last_event(
+            Event::FollowRequestCancelled {
+                requester: ALICE,
+                target: BOB,
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            Ghonity::cancel_request(RuntimeOrigin::signed(ALICE), BOB),
+            Error::<Test>::FollowRequestNotFound
+        );
+    });
+}
+
+#[test]
+fn request_follow_validations() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Ghonity::request_follow(RuntimeOrigin::signed(ALICE), BOB),
+            Error::<Test>::AccountNotPrivate
+        );
+
+        assert_ok!(Ghonity::set_privacy(RuntimeOrigin::signed(BOB), true));
+        assert_noop!(
+            Ghonity::request_follow(RuntimeOrigin::signed(BOB), BOB),
+            Error::<Test>::CannotFollowSelf
+        );
+
+        assert_ok!(Ghonity::request_follow(RuntimeOrigin::signed(ALICE), BOB));
+        assert_noop!(
+            Ghonity::request_follow(RuntimeOrigin::signed(ALICE), BOB),
+            Error::<Test>::FollowRequestAlreadyPending
+        );
+
+        assert_ok!(Ghonity::approve_follow(RuntimeOrigin::signed(BOB), ALICE));
+        assert_noop!(
+            Ghonity::request_follow(RuntimeOrigin::signed(ALICE), BOB),
+            Error::<Test>::AlreadyFollowing
+        );
+    });
+}
+
+#[test]
+fn pending_requests_are_bounded() {
+    new_test_ext().execute_with(|| {
+        let max_pending = <Test as crate::Config>::MaxPendingRequests::get();
+
+        assert_ok!(Ghonity::set_privacy(RuntimeOrigin::signed(BOB), true));
+        for i in 0..max_pending {
+            let requester = 100 + i as u64;
+            assert_ok!(Balances::transfer_allow_death(
+                RuntimeOrigin::signed(CHARLIE),
+                requester,
+                100
+            ));
+            assert_ok!(Ghonity::request_follow(
+                RuntimeOrigin::signed(requester),
+                BOB
+            ));
+        }
+
+        assert_noop!(
+            Ghonity::request_follow(RuntimeOrigin::signed(ALICE), BOB),
+            Error::<Test>::MaxPendingRequestsExceeded
+        );
+
+        // Resolving a request frees a slot
+        assert_ok!(Ghonity::reject_follow(RuntimeOrigin::signed(BOB), 100));
+        assert_ok!(Ghonity::request_follow(RuntimeOrigin::signed(ALICE), BOB));
+    });
+}
+
+#[test]
+fn request_follow_requires_deposit() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Ghonity::set_privacy(RuntimeOrigin::signed(BOB), true));
+
+        // Account 4 has no balance to hold the deposit from
+        assert_noop!(
+            Ghonity::request_follow(RuntimeOrigin::signed(4), BOB),
+            DispatchError::Token(TokenError::FundsUnavailable)
+        );
+        assert!(!Ghonity::has_pending_request(&4, &BOB));
+    });
+}
+
+#[test]
+fn follow_records_edge_metadata() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(5);
+        assert_ok!(Ghonity::follow(RuntimeOrigin::signed(ALICE), BOB));
+
+        assert_eq!(
+            Follows::<Test>::get(ALICE, BOB),
+            Some(FollowEdge { since: 5, tags: 0 })
+        );
+        assert!(Followers::<Test>::contains_key(BOB, ALICE));
+
+        System::set_block_number(12);
+        assert_eq!(Ghonity::follow_age(&ALICE, &BOB), Some(7));
+        assert_eq!(Ghonity::follow_age(&BOB, &ALICE), None);
+
+        assert_ok!(Ghonity::unfollow(RuntimeOrigin::signed(ALICE), BOB));
+        assert_eq!(Follows::<Test>::get(ALICE, BOB), None);
+        assert!(!Followers::<Test>::contains_key(BOB, ALICE));
+    });
+}
+
+#[test]
+fn migration_v0_to_v1_converts_follows() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(42);
+        StorageVersion::new(0).put::<Ghonity>();
+
+        migrations::v1::v0::Follows::<Test>::insert(ALICE, BOB, true);
+        migrations::v1::v0::Follows::<Test>::insert(BOB, CHARLIE, true);
+        migrations::v1::v0::Follows::<Test>::insert(CHARLIE, ALICE, false);
+
+        migrations::v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(
+            Follows::<Test>::get(ALICE, BOB),
+            Some(FollowEdge { since: 42, tags: 0 })
+        );
+        assert_eq!(
+            Follows::<Test>::get(BOB, CHARLIE),
+            Some(FollowEdge { since: 42, tags: 0 })
+        );
+        assert!(!Follows::<Test>::contains_key(CHARLIE, ALICE));
+        assert_eq!(Ghonity::on_chain_storage_version(), 1);
+
+        // Running again is a no-op once the version has been bumped
+        System::set_block_number(50);
+        migrations::v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+        assert_eq!(
+            Follows::<Test>::get(ALICE, BOB),
+            Some(FollowEdge { since: 42, tags: 0 })
+        );
+    });
+}
+
+#[test]
+fn migration_v1_to_v2_indexes_followers() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(1).put::<Ghonity>();
+
+        let edge = FollowEdge { since: 1, tags: 0 };
+        Follows::<Test>::insert(ALICE, BOB, edge.clone());
+        Follows::<Test>::insert(CHARLIE, BOB, edge);
+
+        migrations::v2::MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+        assert!(Followers::<Test>::contains_key(BOB, ALICE));
+        assert!(Followers::<Test>::contains_key(BOB, CHARLIE));
+        assert!(!Followers::<Test>::contains_key(ALICE, BOB));
+        assert_eq!(Followers::<Test>::iter_keys().count(), 2);
+        assert_eq!(Ghonity::on_chain_storage_version(), 2);
+    });
+}
+
+#[test]
+fn mutual_follows_and_common_followers_work() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let dave = 4;
+
+        // ALICE and BOB both follow CHARLIE; only ALICE follows dave
+        assert_ok!(Ghonity::follow(RuntimeOrigin::signed(ALICE), CHARLIE));
+        assert_ok!(Ghonity::follow(RuntimeOrigin::signed(ALICE), dave));
+        assert_ok!(Ghonity::follow(RuntimeOrigin::signed(BOB), CHARLIE));
+
+        assert_eq!(Ghonity::mutual_follows(&ALICE, &BOB, 100), vec![CHARLIE]);
+        assert_eq!(Ghonity::mutual_follows(&ALICE, &dave, 100), Vec::<u64>::new());
+        assert!(Ghonity::mutual_follows(&ALICE, &BOB, 0).is_empty());
+
+        // ALICE follows both CHARLIE and dave; BOB only follows CHARLIE
+        assert_eq!(Ghonity::common_followers(&CHARLIE, &dave, 100), vec![ALICE]);
+        let mut followers = Ghonity::common_followers(&CHARLIE, &CHARLIE, 100);
+        followers.sort();
+        assert_eq!(followers, vec![ALICE, BOB]);
+        assert!(Ghonity::common_followers(&CHARLIE, &dave, 0).is_empty());
+    });
+}
+
+#[test]
+fn follow_suggestions_rank_friends_of_friends() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let (dave, eve, ferdie) = (4, 5, 6);
+
+        assert_ok!(Ghonity::follow(RuntimeOrigin::signed(ALICE), BOB));
+        assert_ok!(Ghonity::follow(RuntimeOrigin::signed(ALICE), CHARLIE));
+
+        // BOB and CHARLIE both follow dave; CHARLIE also follows eve and ALICE
+        assert_ok!(Ghonity::follow(RuntimeOrigin::signed(BOB), dave));
+        assert_ok!(Ghonity::follow(RuntimeOrigin::signed(BOB), CHARLIE));
+        assert_ok!(Ghonity::follow(RuntimeOrigin::signed(CHARLIE), dave));
+        assert_ok!(Ghonity::follow(RuntimeOrigin::signed(CHARLIE), eve));
+        assert_ok!(Ghonity::follow(RuntimeOrigin::signed(CHARLIE), ALICE));
+        assert_ok!(Ghonity::follow(RuntimeOrigin::signed(CHARLIE), ferdie));
+
+        assert_ok!(Ghonity::update_reputation(RuntimeOrigin::root(), eve, 50));
+
+        // Already followed accounts and ALICE are excluded;
+        // eve ranks first on reputation, then dave on mutual count
+        let suggestions = Ghonity::follow_suggestions(&ALICE, 100, 100, 10);
+        assert_eq!(suggestions[0], (eve, 1));
+        assert_eq!(suggestions[1], (dave, 2));
+        assert_eq!(suggestions[2], (ferdie, 1));
+        assert_eq!(suggestions.len(), 3);
+
+        assert_eq!(Ghonity::follow_suggestions(&ALICE, 100, 100, 1), vec![(eve, 1)]);
+        assert!(Ghonity::follow_suggestions(&ALICE, 0, 100, 10).is_empty());
+    });
+}
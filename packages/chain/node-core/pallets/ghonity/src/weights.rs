@@ -1,10 +1,13 @@
-//! Autogenerated weights for pallet_ghonity
+//! Weights for pallet_ghonity
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2025-11-18, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `Ghost-Protocol-Node`, CPU: `<UNKNOWN>`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+//! PLACEHOLDER VALUES, NOT BENCHMARKED: the weights, proof sizes and execution times below
+//! are hand-written estimates following the storage accesses of each extrinsic. Regenerate
+//! this file with the benchmark CLI before relying on it:
+//!
+//! ```text
+//! ghost-node benchmark pallet --chain dev --pallet pallet_ghonity --extrinsic '*' \
+//!     --steps 50 --repeat 20 --output pallets/ghonity/src/weights.rs
+//! ```
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -18,41 +21,66 @@ pub trait WeightInfo {
 	fn follow() -> Weight;
 	fn unfollow() -> Weight;
 	fn update_reputation() -> Weight;
+	fn set_privacy() -> Weight;
+	fn request_follow() -> Weight;
+	fn approve_follow() -> Weight;
+	fn reject_follow() -> Weight;
+	fn cancel_request() -> Weight;
 }
 
 /// Weights for pallet_ghonity using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: Ghonity Follows (r:1 w:1)
-	/// Proof: Ghonity Follows (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Proof: Ghonity Follows (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: Ghonity Followers (r:0 w:1)
+	/// Proof: Ghonity Followers (max_values: None, max_size: Some(96), added: 2591, mode: MaxEncodedLen)
 	/// Storage: Ghonity FollowingCount (r:1 w:1)
 	/// Proof: Ghonity FollowingCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
 	/// Storage: Ghonity FollowerCount (r:0 w:1)
 	/// Proof: Ghonity FollowerCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Ghonity AccountCreatedAt (r:1 w:0)
+	/// Proof: Ghonity AccountCreatedAt (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Ghonity ReputationScores (r:1 w:0)
+	/// Proof: Ghonity ReputationScores (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Ghonity FollowWeight (r:0 w:1)
+	/// Proof: Ghonity FollowWeight (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Ghonity WeightedFollowerScore (r:1 w:1)
+	/// Proof: Ghonity WeightedFollowerScore (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: Ghonity PrivateAccounts (r:1 w:0)
+	/// Proof: Ghonity PrivateAccounts (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
 	fn follow() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `76`
-		//  Estimated: `5054`
-		// Minimum execution time: 15_000_000 picoseconds.
-		Weight::from_parts(16_000_000, 5054)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		//  Measured:  `240`
+		//  Estimated: `7593`
+		// Minimum execution time: 25_000_000 picoseconds.
+		Weight::from_parts(27_000_000, 7593)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 
 	/// Storage: Ghonity Follows (r:1 w:1)
-	/// Proof: Ghonity Follows (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Proof: Ghonity Follows (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: Ghonity Followers (r:0 w:1)
+	/// Proof: Ghonity Followers (max_values: None, max_size: Some(96), added: 2591, mode: MaxEncodedLen)
 	/// Storage: Ghonity FollowingCount (r:0 w:1)
 	/// Proof: Ghonity FollowingCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
 	/// Storage: Ghonity FollowerCount (r:0 w:1)
 	/// Proof: Ghonity FollowerCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Ghonity FollowWeight (r:1 w:1)
+	/// Proof: Ghonity FollowWeight (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Ghonity WeightedFollowerScore (r:1 w:1)
+	/// Proof: Ghonity WeightedFollowerScore (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
 	fn unfollow() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `108`
-		//  Estimated: `2543`
-		// Minimum execution time: 14_000_000 picoseconds.
-		Weight::from_parts(15_000_000, 2543)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		//  Measured:  `172`
+		//  Estimated: `5058`
+		// Minimum execution time: 18_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 5058)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 
 	/// Storage: Ghonity ReputationScores (r:1 w:1)
@@ -66,40 +94,164 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+
+	/// Storage: Ghonity PrivateAccounts (r:0 w:1)
+	/// Proof: Ghonity PrivateAccounts (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	fn set_privacy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: Ghonity Follows (r:1 w:0)
+	/// Proof: Ghonity Follows (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: Ghonity PrivateAccounts (r:1 w:0)
+	/// Proof: Ghonity PrivateAccounts (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: Ghonity PendingFollowRequests (r:1 w:1)
+	/// Proof: Ghonity PendingFollowRequests (max_values: None, max_size: Some(3238), added: 5713, mode: MaxEncodedLen)
+	/// Storage: Ghonity FollowRequestDeposits (r:0 w:1)
+	/// Proof: Ghonity FollowRequestDeposits (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn request_follow() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3415`
+		//  Estimated: `6703`
+		// Minimum execution time: 38_000_000 picoseconds.
+		Weight::from_parts(41_000_000, 6703)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: Ghonity PendingFollowRequests (r:1 w:1)
+	/// Proof: Ghonity PendingFollowRequests (max_values: None, max_size: Some(3238), added: 5713, mode: MaxEncodedLen)
+ (r:1 w:1)
+	/// Storage: Ghonity FollowRequestDeposits (r:1 w:1)
+	/// Proof: Ghonity FollowRequestDeposits (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Ghonity Follows (r:1 w:1)
+	/// Proof: Ghonity Follows (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: Ghonity Followers (r:0 w:1)
+	/// Proof: Ghonity Followers (max_values: None, max_size: Some(96), added: 2591, mode: MaxEncodedLen)
+	/// Storage: Ghonity FollowingCount (r:1 w:1)
+	/// Proof: Ghonity FollowingCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Ghonity FollowerCount (r:0 w:1)
+	/// Proof: Ghonity FollowerCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Ghonity AccountCreatedAt (r:1 w:0)
+	/// Proof: Ghonity AccountCreatedAt (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Ghonity ReputationScores (r:1 w:0)
+	/// Proof: Ghonity ReputationScores (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Ghonity FollowWeight (r:0 w:1)
+	/// Proof: Ghonity FollowWeight (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Ghonity WeightedFollowerScore (r:1 w:1)
+	/// Proof: Ghonity WeightedFollowerScore (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	fn approve_follow() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3655`
+		//  Estimated: `10303`
+		// Minimum execution time: 54_000_000 picoseconds.
+		Weight::from_parts(58_000_000, 10303)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+
+	/// Storage: Ghonity PendingFollowRequests (r:1 w:1)
+	/// Proof: Ghonity PendingFollowRequests (max_values: None, max_size: Some(3238), added: 5713, mode: MaxEncodedLen)
+	/// Storage: Ghonity FollowRequestDeposits (r:1 w:1)
+	/// Proof: Ghonity FollowRequestDeposits (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn reject_follow() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3415`
+		//  Estimated: `6703`
+		// Minimum execution time: 32_000_000 picoseconds.
+		Weight::from_parts(35_000_000, 6703)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: Ghonity PendingFollowRequests (r:1 w:1)
+	/// Proof: Ghonity PendingFollowRequests (max_values: None, max_size: Some(3238), added: 5713, mode: MaxEncodedLen)
+	/// Storage: Ghonity FollowRequestDeposits (r:1 w:1)
+	/// Proof: Ghonity FollowRequestDeposits (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn cancel_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3415`
+		//  Estimated: `6703`
+		// Minimum execution time: 32_000_000 picoseconds.
+		Weight::from_parts(35_000_000, 6703)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	/// Storage: Ghonity Follows (r:1 w:1)
-	/// Proof: Ghonity Follows (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Proof: Ghonity Follows (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: Ghonity Followers (r:0 w:1)
+	/// Proof: Ghonity Followers (max_values: None, max_size: Some(96), added: 2591, mode: MaxEncodedLen)
 	/// Storage: Ghonity FollowingCount (r:1 w:1)
 	/// Proof: Ghonity FollowingCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
 	/// Storage: Ghonity FollowerCount (r:0 w:1)
 	/// Proof: Ghonity FollowerCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Ghonity AccountCreatedAt (r:1 w:0)
+	/// Proof: Ghonity AccountCreatedAt (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Ghonity ReputationScores (r:1 w:0)
+	/// Proof: Ghonity ReputationScores (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Ghonity FollowWeight (r:0 w:1)
+	/// Proof: Ghonity FollowWeight (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Ghonity WeightedFollowerScore (r:1 w:1)
+	/// Proof: Ghonity WeightedFollowerScore (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	/// Storage: Ghonity PrivateAccounts (r:1 w:0)
+	/// Proof: Ghonity PrivateAccounts (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
 	fn follow() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `76`
-		//  Estimated: `5054`
-		// Minimum execution time: 15_000_000 picoseconds.
-		Weight::from_parts(16_000_000, 5054)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		//  Measured:  `240`
+		//  Estimated: `7593`
+		// Minimum execution time: 25_000_000 picoseconds.
+		Weight::from_parts(27_000_000, 7593)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 
 	/// Storage: Ghonity Follows (r:1 w:1)
-	/// Proof: Ghonity Follows (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Proof: Ghonity Follows (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: Ghonity Followers (r:0 w:1)
+	/// Proof: Ghonity Followers (max_values: None, max_size: Some(96), added: 2591, mode: MaxEncodedLen)
 	/// Storage: Ghonity FollowingCount (r:0 w:1)
 	/// Proof: Ghonity FollowingCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
 	/// Storage: Ghonity FollowerCount (r:0 w:1)
 	/// Proof: Ghonity FollowerCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Ghonity FollowWeight (r:1 w:1)
+	/// Proof: Ghonity FollowWeight (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Ghonity WeightedFollowerScore (r:1 w:1)
+	/// Proof: Ghonity WeightedFollowerScore (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
 	fn unfollow() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `108`
-		//  Estimated: `2543`
-		// Minimum execution time: 14_000_000 picoseconds.
-		Weight::from_parts(15_000_000, 2543)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		//  Measured:  `172`
+		//  Estimated: `5058`
+		// Minimum execution time: 18_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 5058)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 
 	/// Storage: Ghonity ReputationScores (r:1 w:1)
@@ -113,4 +265,108 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+
+	/// Storage: Ghonity PrivateAccounts (r:0 w:1)
+	/// Proof: Ghonity PrivateAccounts (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	fn set_privacy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	/// Storage: Ghonity Follows (r:1 w:0)
+	/// Proof: Ghonity Follows (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: Ghonity PrivateAccounts (r:1 w:0)
+	/// Proof: Ghonity PrivateAccounts (max_values: None, max_size: Some(33), added: 2508, mode: MaxEncodedLen)
+	/// Storage: Ghonity PendingFollowRequests (r:1 w:1)
+	/// Proof: Ghonity PendingFollowRequests (max_values: None, max_size: Some(3238), added: 5713, mode: MaxEncodedLen)
+	/// Storage: Ghonity FollowRequestDeposits (r:0 w:1)
+	/// Proof: Ghonity FollowRequestDeposits (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn request_follow() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3415`
+		//  Estimated: `6703`
+		// Minimum execution time: 38_000_000 picoseconds.
+		Weight::from_parts(41_000_000, 6703)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: Ghonity PendingFollowRequests (r:1 w:1)
+	/// Proof: Ghonity PendingFollowRequests (max_values: None, max_size: Some(3238), added: 5713, mode: MaxEncodedLen)
+ (r:1 w:1)
+	/// Storage: Ghonity FollowRequestDeposits (r:1 w:1)
+	/// Proof: Ghonity FollowRequestDeposits (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Ghonity Follows (r:1 w:1)
+	/// Proof: Ghonity Follows (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: Ghonity Followers (r:0 w:1)
+	/// Proof: Ghonity Followers (max_values: None, max_size: Some(96), added: 2591, mode: MaxEncodedLen)
+	/// Storage: Ghonity FollowingCount (r:1 w:1)
+	/// Proof: Ghonity FollowingCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Ghonity FollowerCount (r:0 w:1)
+	/// Proof: Ghonity FollowerCount (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Ghonity AccountCreatedAt (r:1 w:0)
+	/// Proof: Ghonity AccountCreatedAt (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Ghonity ReputationScores (r:1 w:0)
+	/// Proof: Ghonity ReputationScores (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Ghonity FollowWeight (r:0 w:1)
+	/// Proof: Ghonity FollowWeight (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Ghonity WeightedFollowerScore (r:1 w:1)
+	/// Proof: Ghonity WeightedFollowerScore (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	fn approve_follow() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3655`
+		//  Estimated: `10303`
+		// Minimum execution time: 54_000_000 picoseconds.
+		Weight::from_parts(58_000_000, 10303)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+
+	/// Storage: Ghonity PendingFollowRequests (r:1 w:1)
+	/// Proof: Ghonity PendingFollowRequests (max_values: None, max_size: Some(3238), added: 5713, mode: MaxEncodedLen)
+	/// Storage: Ghonity FollowRequestDeposits (r:1 w:1)
+	/// Proof: Ghonity FollowRequestDeposits (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn reject_follow() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3415`
+		//  Estimated: `6703`
+		// Minimum execution time: 32_000_000 picoseconds.
+		Weight::from_parts(35_000_000, 6703)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+
+	/// Storage: Ghonity PendingFollowRequests (r:1 w:1)
+	/// Proof: Ghonity PendingFollowRequests (max_values: None, max_size: Some(3238), added: 5713, mode: MaxEncodedLen)
+	/// Storage: Ghonity FollowRequestDeposits (r:1 w:1)
+	/// Proof: Ghonity FollowRequestDeposits (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn cancel_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3415`
+		//  Estimated: `6703`
+		// Minimum execution time: 32_000_000 picoseconds.
+		Weight::from_parts(35_000_000, 6703)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
 }
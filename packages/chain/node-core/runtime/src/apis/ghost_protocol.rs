@@ -1,11 +1,48 @@
 use codec::{Decode, Encode};
 use scale_info::TypeInfo;
 use sp_api::decl_runtime_apis;
+use sp_core::H256;
 use sp_std::vec::Vec;
 
-pub use pallet_chainghost::{IntentId, IntentStatus};
+pub use pallet_chainghost::{
+    Achievement, AchievementKind, CancelReason, ChainId, ExecutionError, IntentAction, IntentId, IntentKind, IntentStatus, JourneyStepKind,
+    LinkedTx,
+};
 pub use pallet_g3mail::MessageId;
 
+/// Maximum number of followees walked when building an activity feed
+pub const MAX_FEED_FOLLOWEES: u32 = 100;
+
+/// Maximum number of most recent intents inspected per followee
+pub const MAX_FEED_INTENTS_PER_ACCOUNT: u32 = 20;
+
+/// Maximum number of intent IDs returned by a single page of an account's intents
+pub const MAX_INTENTS_PAGE: u32 = 100;
+
+/// Maximum number of items returned by a single feed query
+pub const MAX_FEED_ITEMS: u32 = 100;
+
+/// Maximum number of followees inspected by a mutual follows query
+pub const MAX_MUTUAL_FOLLOWS_SCAN: u32 = 1_000;
+
+/// Maximum number of followers of the first account inspected by a common followers query
+pub const MAX_COMMON_FOLLOWERS_SCAN: u32 = 1_000;
+
+/// Maximum number of followees whose own followees are inspected for suggestions
+pub const MAX_SUGGESTION_FOLLOWEES: u32 = 50;
+
+/// Maximum number of followees inspected per followee when building suggestions
+pub const MAX_SUGGESTION_CANDIDATES_PER_FOLLOWEE: u32 = 50;
+
+/// Maximum number of suggestions returned by a single query
+pub const MAX_SUGGESTIONS: u32 = 50;
+
+/// Maximum number of intents read by a single batch query
+pub const MAX_BATCH_INTENTS: u32 = 100;
+
+/// Maximum number of accounts read by a single batch query
+pub const MAX_BATCH_ACCOUNTS: u32 = 100;
+
 #[derive(Clone, Encode, Decode, Eq, PartialEq, TypeInfo)]
 #[cfg_attr(feature = "std", derive(Debug))]
 pub struct IntentData<AccountId, BlockNumber> {
@@ -14,14 +51,88 @@ pub struct IntentData<AccountId, BlockNumber> {
     pub status: IntentStatus,
     pub timestamp: BlockNumber,
     pub metadata: Vec<u8>,
+    /// Typed payload, `None` for intents created before typed payloads
+    pub kind: Option<IntentKind<AccountId>>,
+    /// Failure of the intent's dispatched calls, if any
+    pub error: Option<ExecutionError>,
+    /// Transactions on other chains carrying out the intent
+    pub chain_refs: Vec<ChainRefData>,
+    /// Why the intent was cancelled, if it was
+    pub cancel_reason: Option<CancelReason>,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct ChainRefData {
+    pub chain_id: ChainId,
+    pub tx_hash: Vec<u8>,
+    pub block_number: u64,
+    /// Whether a relayer has attested the transaction
+    pub attested: bool,
+}
+
+/// Head of an intent's journey hash chain
+///
+/// `hash` chains `blake2_256(previous hash ++ SCALE(step))` over the first `step_count` steps,
+/// starting from the all-zero hash.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct JourneyHeadData {
+    pub step_count: u32,
+    pub hash: H256,
 }
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, TypeInfo)]
 #[cfg_attr(feature = "std", derive(Debug))]
-pub struct JourneyStepData<BlockNumber> {
+pub struct JourneyStepData<AccountId, BlockNumber> {
     pub step_id: u32,
+    pub step_kind: JourneyStepKind,
     pub description: Vec<u8>,
     pub timestamp: BlockNumber,
+    /// Account that recorded the step: the intent owner, one of its delegates or its claiming
+    /// solver
+    pub author: AccountId,
+    pub linked_tx: Option<LinkedTx>,
+    /// CID of an off-chain attachment
+    pub attachment: Option<Vec<u8>>,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct SubIntentData<AccountId> {
+    pub index: u32,
+    pub action: IntentAction<AccountId>,
+    /// Indices of the sub-intents that must be executed first
+    pub depends_on: Vec<u32>,
+    pub status: IntentStatus,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct IntentTreeData<AccountId> {
+    pub intent_id: IntentId,
+    /// Status of the intent, rolled up from its sub-intents
+    pub status: IntentStatus,
+    /// Sub-intents in order, empty for single-step intents
+    pub sub_intents: Vec<SubIntentData<AccountId>>,
+    /// Pending sub-intents whose dependencies are all executed
+    pub ready: Vec<u32>,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct PersonaData<BlockNumber> {
+    pub intents_created: u32,
+    pub intents_executed: u32,
+    pub intents_failed: u32,
+    /// Percentage of resolved intents that were executed, `None` if none is resolved
+    pub success_ratio: Option<u32>,
+    /// Number of distinct intent kinds executed
+    pub distinct_kinds: u32,
+    /// Number of journey steps recorded on the account's intents
+    pub journey_steps: u32,
+    /// Unlocked achievements and the block each was unlocked at
+    pub achievements: Vec<(Achievement, BlockNumber)>,
 }
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, TypeInfo)]
@@ -35,7 +146,69 @@ pub struct MessagePointerData<AccountId, BlockNumber> {
     pub read: bool,
 }
 
+#[derive(Clone, Encode, Decode, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct FollowerStatsData {
+    /// Raw number of followers
+    pub follower_count: u32,
+    /// Sum of capped reputation of followers meeting the age and balance requirements
+    pub weighted_score: u64,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct AccountStatsData {
+    pub follower_count: u32,
+    pub following_count: u32,
+    pub reputation_score: u32,
+    /// Sum of capped reputation of followers meeting the age and balance requirements
+    pub weighted_score: u64,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct FollowEdgeData<BlockNumber> {
+    /// Block at which the follow was created
+    pub since: BlockNumber,
+    /// Number of blocks elapsed since the follow was created
+    pub age: BlockNumber,
+    /// Relationship tag bitmask
+    pub tags: u8,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct FollowSuggestionData<AccountId> {
+    pub account: AccountId,
+    pub reputation: u32,
+    /// Number of accounts followed by the requester that follow this account
+    pub mutual_count: u32,
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub enum FeedItemKind {
+    /// A new intent created by a followed account
+    Intent,
+    /// A journey step recorded on an intent of a followed account
+    JourneyStep { step_id: u32 },
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct FeedItemData<AccountId, BlockNumber> {
+    pub account: AccountId,
+    pub intent_id: IntentId,
+    pub kind: FeedItemKind,
+    pub status: IntentStatus,
+    pub block_number: BlockNumber,
+    /// Intent metadata for `Intent` items, step description for `JourneyStep` items
+    pub content: Vec<u8>,
+}
+
 decl_runtime_apis! {
+    /// Version 2: typed intents, journey step kinds, paging and batch queries
+    #[api_version(2)]
     pub trait ChainGhostRuntimeApi<AccountId, BlockNumber>
     where
         AccountId: Encode + Decode,
@@ -43,13 +216,32 @@ decl_runtime_apis! {
     {
         fn get_intent(intent_id: IntentId) -> Option<IntentData<AccountId, BlockNumber>>;
 
-        fn get_intents_by_account(account: AccountId) -> Vec<IntentId>;
+        /// Up to `limit` intents of `account` (capped at `MAX_INTENTS_PAGE`), oldest first,
+        /// starting after `cursor`, the last intent of the previous page
+        fn get_intents_by_account(account: AccountId, cursor: Option<IntentId>, limit: u32) -> Vec<IntentId>;
 
-        fn get_journey_steps(intent_id: IntentId) -> Vec<JourneyStepData<BlockNumber>>;
+        fn get_journey_steps(intent_id: IntentId) -> Vec<JourneyStepData<AccountId, BlockNumber>>;
+
+        /// Head of the journey hash chain of an intent, `None` if no step was recorded
+        fn get_journey_head(intent_id: IntentId) -> Option<JourneyHeadData>;
 
         fn get_intent_status(intent_id: IntentId) -> Option<IntentStatus>;
+
+        /// Sub-intents and dependencies of an intent, `None` if the intent does not exist
+        fn get_intent_tree(intent_id: IntentId) -> Option<IntentTreeData<AccountId>>;
+
+        /// Pending sub-intents of an intent whose dependencies are all executed
+        fn get_ready_sub_intents(intent_id: IntentId) -> Vec<u32>;
+
+        /// Activity counters and soulbound achievements of an account
+        fn get_persona(account: AccountId) -> PersonaData<BlockNumber>;
+
+        /// `get_intent` for each of the first `MAX_BATCH_INTENTS` IDs, in order
+        fn get_intents_batch(intent_ids: Vec<IntentId>) -> Vec<Option<IntentData<AccountId, BlockNumber>>>;
     }
 
+    /// Version 2: batch queries
+    #[api_version(2)]
     pub trait G3MailRuntimeApi<AccountId, BlockNumber>
     where
         AccountId: Encode + Decode,
@@ -62,11 +254,17 @@ decl_runtime_apis! {
         fn get_message(recipient: AccountId, message_id: MessageId) -> Option<MessagePointerData<AccountId, BlockNumber>>;
 
         fn get_inbox_count(account: AccountId) -> u32;
+
+        /// `get_public_key` for each of the first `MAX_BATCH_ACCOUNTS` accounts, in order
+        fn get_public_keys_batch(accounts: Vec<AccountId>) -> Vec<Option<Vec<u8>>>;
     }
 
-    pub trait GhonityRuntimeApi<AccountId>
+    /// Version 2: feed, follow graph queries and batch queries
+    #[api_version(2)]
+    pub trait GhonityRuntimeApi<AccountId, BlockNumber>
     where
         AccountId: Encode + Decode,
+        BlockNumber: Encode + Decode,
     {
         fn is_following(follower: AccountId, followee: AccountId) -> bool;
 
@@ -75,5 +273,32 @@ decl_runtime_apis! {
         fn get_following_count(account: AccountId) -> u32;
 
         fn get_reputation_score(account: AccountId) -> u32;
+
+        fn get_follower_stats(account: AccountId) -> FollowerStatsData;
+
+        fn get_follow_edge(follower: AccountId, followee: AccountId) -> Option<FollowEdgeData<BlockNumber>>;
+
+        /// Accounts followed by both `a` and `b`, bounded by `MAX_MUTUAL_FOLLOWS_SCAN`.
+        fn get_mutual_follows(a: AccountId, b: AccountId) -> Vec<AccountId>;
+
+        /// Accounts following both `a` and `b`, bounded by `MAX_COMMON_FOLLOWERS_SCAN`.
+        fn get_common_followers(a: AccountId, b: AccountId) -> Vec<AccountId>;
+
+        /// Friends-of-friends not yet followed by `account`, ranked by reputation.
+        ///
+        /// Work is bounded by `MAX_SUGGESTION_FOLLOWEES`, `MAX_SUGGESTION_CANDIDATES_PER_FOLLOWEE`
+        /// and `MAX_SUGGESTIONS`, regardless of the requested `limit`.
+        fn get_follow_suggestions(account: AccountId, limit: u32) -> Vec<FollowSuggestionData<AccountId>>;
+
+        /// Latest intents and journey steps of the accounts followed by `account`,
+        /// recorded at or after `since_block`, newest first.
+        ///
+        /// Work is bounded by `MAX_FEED_FOLLOWEES`, `MAX_FEED_INTENTS_PER_ACCOUNT` and
+        /// `MAX_FEED_ITEMS`, regardless of the requested `limit`.
+        fn get_feed(account: AccountId, since_block: BlockNumber, limit: u32) -> Vec<FeedItemData<AccountId, BlockNumber>>;
+
+        /// Follower, following and reputation counters of each of the first
+        /// `MAX_BATCH_ACCOUNTS` accounts, in order
+        fn get_stats_batch(accounts: Vec<AccountId>) -> Vec<AccountStatsData>;
     }
 }
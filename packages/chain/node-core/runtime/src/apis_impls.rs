@@ -42,15 +42,122 @@ use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-    AccountId, Aura, Balance, Block, BlockNumber, Executive, Grandpa,
+    AccountId, Aura, Balance, Block, BlockNumber, ChainGhost, Executive, Ghonity, Grandpa,
     Nonce, Runtime, RuntimeCall, RuntimeGenesisConfig, SessionKeys, System,
     TransactionPayment, UncheckedExtrinsic, VERSION,
 };
 
-use crate::apis::ghost_protocol::{IntentData, JourneyStepData, MessagePointerData};
-use pallet_chainghost::{IntentById, IntentsByAccount, JourneyByIntent};
+use crate::apis::ghost_protocol::{
+    AccountStatsData, ChainRefData, FeedItemData, FeedItemKind, FollowEdgeData, FollowSuggestionData, FollowerStatsData, IntentData,
+    IntentTreeData, JourneyHeadData, JourneyStepData, MessagePointerData, PersonaData, SubIntentData, MAX_COMMON_FOLLOWERS_SCAN, MAX_FEED_FOLLOWEES,
+    MAX_FEED_INTENTS_PER_ACCOUNT, MAX_FEED_ITEMS, MAX_INTENTS_PAGE, MAX_MUTUAL_FOLLOWS_SCAN,
+    MAX_SUGGESTIONS, MAX_SUGGESTION_CANDIDATES_PER_FOLLOWEE, MAX_SUGGESTION_FOLLOWEES, MAX_BATCH_ACCOUNTS,
+    MAX_BATCH_INTENTS,
+};
+use pallet_chainghost::{
+    CancelReasons, ChainRefs, IntentById, IntentErrors, JourneyHeads, JourneySteps,
+    UnlockedAchievements,
+};
 use pallet_g3mail::{InboxCount, MessagesByRecipient, PublicKeys};
-use pallet_ghonity::{FollowerCount, FollowingCount, Follows, ReputationScores};
+use pallet_ghonity::{
+    FollowerCount, FollowingCount, Follows, ReputationScores, WeightedFollowerScore,
+};
+
+/// Intent `intent_id` with its error, external transactions and cancellation reason
+fn intent_data(intent_id: pallet_chainghost::IntentId) -> Option<IntentData<AccountId, BlockNumber>> {
+    IntentById::<Runtime>::get(intent_id).map(|intent| IntentData {
+        intent_id: intent.intent_id,
+        account: intent.account,
+        status: intent.status,
+        timestamp: intent.timestamp,
+        metadata: intent.metadata.into_inner(),
+        kind: intent.kind,
+        error: IntentErrors::<Runtime>::get(intent_id),
+        chain_refs: ChainRefs::<Runtime>::get(intent_id)
+            .into_iter()
+            .map(|chain_ref| ChainRefData {
+                chain_id: chain_ref.chain_id,
+                tx_hash: chain_ref.tx_hash.into_inner(),
+                block_number: chain_ref.block_number,
+                attested: chain_ref.attested,
+            })
+            .collect(),
+        cancel_reason: CancelReasons::<Runtime>::get(intent_id),
+    })
+}
+
+/// Latest intents and journey steps of the accounts followed by `account`, recorded at or after
+/// `since_block`, newest first
+///
+/// Only the newest `limit` items are kept while collecting, and the journey of an intent is read
+/// back from its latest step until a step could no longer be kept.
+fn feed(account: &AccountId, since_block: BlockNumber, limit: u32) -> Vec<FeedItemData<AccountId, BlockNumber>> {
+    let limit = limit.min(MAX_FEED_ITEMS) as usize;
+    let mut items = Vec::with_capacity(limit);
+
+    if limit == 0 {
+        return items;
+    }
+
+    for followee in Follows::<Runtime>::iter_key_prefix(account).take(MAX_FEED_FOLLOWEES as usize) {
+        let intent_ids = ChainGhost::latest_intents_of(&followee, MAX_FEED_INTENTS_PER_ACCOUNT);
+        for intent_id in intent_ids {
+            let Some(intent) = IntentById::<Runtime>::get(intent_id) else {
+                continue;
+            };
+
+            // Older intents may still carry recent journey steps. Steps are recorded in block
+            // order, so none before a step that cannot be kept can be either.
+            let step_count = JourneyHeads::<Runtime>::get(intent_id).map_or(0, |head| head.step_count);
+            for step_id in (0..step_count).rev() {
+                let Some(step) = JourneySteps::<Runtime>::get(intent_id, step_id) else {
+                    continue;
+                };
+                if step.timestamp < since_block || !enters_feed(&items, limit, step.timestamp) {
+                    break;
+                }
+                insert_feed_item(&mut items, limit, FeedItemData {
+                    account: followee.clone(),
+                    intent_id,
+                    kind: FeedItemKind::JourneyStep { step_id },
+                    status: intent.status.clone(),
+                    block_number: step.timestamp,
+                    content: step.description.into_inner(),
+                });
+            }
+
+            if intent.timestamp >= since_block && enters_feed(&items, limit, intent.timestamp) {
+                insert_feed_item(&mut items, limit, FeedItemData {
+                    account: followee.clone(),
+                    intent_id,
+                    kind: FeedItemKind::Intent,
+                    status: intent.status,
+                    block_number: intent.timestamp,
+                    content: intent.metadata.into_inner(),
+                });
+            }
+        }
+    }
+
+    items
+}
+
+/// Whether an item recorded at `block_number` is kept among `items`, the newest `limit` feed
+/// items collected so far. On a tie, the item collected first is kept.
+fn enters_feed(items: &[FeedItemData<AccountId, BlockNumber>], limit: usize, block_number: BlockNumber) -> bool {
+    items.len() < limit || items.last().is_some_and(|oldest| block_number > oldest.block_number)
+}
+
+/// Insert `item` into `items`, newest first, dropping the oldest item beyond `limit`
+fn insert_feed_item(
+    items: &mut Vec<FeedItemData<AccountId, BlockNumber>>,
+    limit: usize,
+    item: FeedItemData<AccountId, BlockNumber>,
+) {
+    let position = items.partition_point(|kept| kept.block_number >= item.block_number);
+    items.insert(position, item);
+    items.truncate(limit);
+}
 
 impl_runtime_apis! {
     impl sp_api::Core<Block> for Runtime {
@@ -304,33 +411,92 @@ impl_runtime_apis! {
 
     impl crate::apis::ghost_protocol::ChainGhostRuntimeApi<Block, AccountId, BlockNumber> for Runtime {
         fn get_intent(intent_id: pallet_chainghost::IntentId) -> Option<IntentData<AccountId, BlockNumber>> {
-            IntentById::<Runtime>::get(intent_id).map(|intent| IntentData {
-                intent_id: intent.intent_id,
-                account: intent.account,
-                status: intent.status,
-                timestamp: intent.timestamp,
-                metadata: intent.metadata.into_inner(),
-            })
+            intent_data(intent_id)
         }
 
-        fn get_intents_by_account(account: AccountId) -> Vec<pallet_chainghost::IntentId> {
-            IntentsByAccount::<Runtime>::get(account).into_inner()
+        fn get_intents_by_account(
+            account: AccountId,
+            cursor: Option<pallet_chainghost::IntentId>,
+            limit: u32,
+        ) -> Vec<pallet_chainghost::IntentId> {
+            ChainGhost::intents_of(&account, cursor, limit.min(MAX_INTENTS_PAGE))
         }
 
-        fn get_journey_steps(intent_id: pallet_chainghost::IntentId) -> Vec<JourneyStepData<BlockNumber>> {
-            JourneyByIntent::<Runtime>::get(intent_id)
-                .iter()
+        fn get_journey_steps(intent_id: pallet_chainghost::IntentId) -> Vec<JourneyStepData<AccountId, BlockNumber>> {
+            ChainGhost::journey_by_intent(intent_id)
+                .into_iter()
                 .map(|step| JourneyStepData {
                     step_id: step.step_id,
-                    description: step.description.clone().into_inner(),
+                    step_kind: step.step_kind,
+                    description: step.description.into_inner(),
                     timestamp: step.timestamp,
+                    author: step.author,
+                    linked_tx: step.linked_tx,
+                    attachment: step.attachment.map(|cid| cid.into_inner()),
                 })
                 .collect()
         }
 
+        fn get_journey_head(intent_id: pallet_chainghost::IntentId) -> Option<JourneyHeadData> {
+            JourneyHeads::<Runtime>::get(intent_id).map(|head| JourneyHeadData {
+                step_count: head.step_count,
+                hash: head.hash,
+            })
+        }
+
         fn get_intent_status(intent_id: pallet_chainghost::IntentId) -> Option<pallet_chainghost::IntentStatus> {
             IntentById::<Runtime>::get(intent_id).map(|intent| intent.status)
         }
+
+        fn get_intent_tree(intent_id: pallet_chainghost::IntentId) -> Option<IntentTreeData<AccountId>> {
+            let intent = IntentById::<Runtime>::get(intent_id)?;
+
+            let sub_intents = ChainGhost::sub_intent_tree(intent_id)
+                .into_iter()
+                .enumerate()
+                .map(|(index, (action, sub_intent))| SubIntentData {
+                    index: index as u32,
+                    action,
+                    depends_on: sub_intent.depends_on.into_inner(),
+                    status: sub_intent.status,
+                })
+                .collect();
+
+            Some(IntentTreeData {
+                intent_id,
+                status: intent.status,
+                sub_intents,
+                ready: ChainGhost::ready_sub_intents(intent_id),
+            })
+        }
+
+        fn get_ready_sub_intents(intent_id: pallet_chainghost::IntentId) -> Vec<u32> {
+            ChainGhost::ready_sub_intents(intent_id)
+        }
+
+        fn get_persona(account: AccountId) -> PersonaData<BlockNumber> {
+            let persona = ChainGhost::persona(&account);
+
+            PersonaData {
+                intents_created: persona.intents_created,
+                intents_executed: persona.intents_executed,
+                intents_failed: persona.intents_failed,
+                success_ratio: persona.success_ratio(),
+                distinct_kinds: persona.distinct_kinds(),
+                journey_steps: persona.journey_steps,
+                achievements: UnlockedAchievements::<Runtime>::iter_prefix(&account).collect(),
+            }
+        }
+
+        fn get_intents_batch(
+            intent_ids: Vec<pallet_chainghost::IntentId>,
+        ) -> Vec<Option<IntentData<AccountId, BlockNumber>>> {
+            intent_ids
+                .into_iter()
+                .take(MAX_BATCH_INTENTS as usize)
+                .map(intent_data)
+                .collect()
+        }
     }
 
     impl crate::apis::ghost_protocol::G3MailRuntimeApi<Block, AccountId, BlockNumber> for Runtime {
@@ -356,9 +522,17 @@ impl_runtime_apis! {
         fn get_inbox_count(account: AccountId) -> u32 {
             InboxCount::<Runtime>::get(account)
         }
+
+        fn get_public_keys_batch(accounts: Vec<AccountId>) -> Vec<Option<Vec<u8>>> {
+            accounts
+                .into_iter()
+                .take(MAX_BATCH_ACCOUNTS as usize)
+                .map(|account| PublicKeys::<Runtime>::get(account).map(|key| key.into_inner()))
+                .collect()
+        }
     }
 
-    impl crate::apis::ghost_protocol::GhonityRuntimeApi<Block, AccountId> for Runtime {
+    impl crate::apis::ghost_protocol::GhonityRuntimeApi<Block, AccountId, BlockNumber> for Runtime {
         fn is_following(follower: AccountId, followee: AccountId) -> bool {
             Follows::<Runtime>::contains_key(&follower, &followee)
         }
@@ -374,6 +548,62 @@ impl_runtime_apis! {
         fn get_reputation_score(account: AccountId) -> u32 {
             ReputationScores::<Runtime>::get(account)
         }
+
+        fn get_follower_stats(account: AccountId) -> FollowerStatsData {
+            FollowerStatsData {
+                follower_count: FollowerCount::<Runtime>::get(&account),
+                weighted_score: WeightedFollowerScore::<Runtime>::get(&account),
+            }
+        }
+
+        fn get_follow_edge(follower: AccountId, followee: AccountId) -> Option<FollowEdgeData<BlockNumber>> {
+            Follows::<Runtime>::get(&follower, &followee).map(|edge| FollowEdgeData {
+                since: edge.since,
+                age: System::block_number().saturating_sub(edge.since),
+                tags: edge.tags,
+            })
+        }
+
+        fn get_mutual_follows(a: AccountId, b: AccountId) -> Vec<AccountId> {
+            Ghonity::mutual_follows(&a, &b, MAX_MUTUAL_FOLLOWS_SCAN)
+        }
+
+        fn get_common_followers(a: AccountId, b: AccountId) -> Vec<AccountId> {
+            Ghonity::common_followers(&a, &b, MAX_COMMON_FOLLOWERS_SCAN)
+        }
+
+        fn get_follow_suggestions(account: AccountId, limit: u32) -> Vec<FollowSuggestionData<AccountId>> {
+            Ghonity::follow_suggestions(
+                &account,
+                MAX_SUGGESTION_FOLLOWEES,
+                MAX_SUGGESTION_CANDIDATES_PER_FOLLOWEE,
+                limit.min(MAX_SUGGESTIONS),
+            )
+            .into_iter()
+            .map(|(candidate, mutual_count)| FollowSuggestionData {
+                reputation: ReputationScores::<Runtime>::get(&candidate),
+                account: candidate,
+                mutual_count,
+            })
+            .collect()
+        }
+
+        fn get_feed(account: AccountId, since_block: BlockNumber, limit: u32) -> Vec<FeedItemData<AccountId, BlockNumber>> {
+            feed(&account, since_block, limit)
+        }
+
+        fn get_stats_batch(accounts: Vec<AccountId>) -> Vec<AccountStatsData> {
+            accounts
+                .into_iter()
+                .take(MAX_BATCH_ACCOUNTS as usize)
+                .map(|account| AccountStatsData {
+                    follower_count: FollowerCount::<Runtime>::get(&account),
+                    following_count: FollowingCount::<Runtime>::get(&account),
+                    reputation_score: ReputationScores::<Runtime>::get(&account),
+                    weighted_score: WeightedFollowerScore::<Runtime>::get(&account),
+                })
+                .collect()
+        }
     }
 
     impl fp_rpc::EthereumRuntimeRPCApi<Block> for Runtime {
@@ -621,3 +851,132 @@ impl_runtime_apis! {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{RuntimeOrigin, UNIT};
+    use pallet_chainghost::{IntentKind, JourneyStepKind, TransferIntent};
+    use sp_runtime::BuildStorage;
+
+    const VIEWER: AccountId = AccountId::new([1; 32]);
+    const ALICE: AccountId = AccountId::new([2; 32]);
+    const BOB: AccountId = AccountId::new([3; 32]);
+    /// Not followed by `VIEWER`
+    const CAROL: AccountId = AccountId::new([4; 32]);
+    /// Private, with a pending follow request of `VIEWER`
+    const DAVE: AccountId = AccountId::new([5; 32]);
+
+    fn new_test_ext() -> sp_io::TestExternalities {
+        let mut storage = RuntimeGenesisConfig::default().build_storage().unwrap();
+        pallet_balances::GenesisConfig::<Runtime> {
+            balances: [VIEWER, ALICE, BOB, CAROL, DAVE]
+                .into_iter()
+                .map(|account| (account, 10 * UNIT))
+                .collect(),
+            ..Default::default()
+        }
+        .assimilate_storage(&mut storage)
+        .unwrap();
+
+        let mut ext = sp_io::TestExternalities::new(storage);
+        ext.execute_with(|| {
+            System::set_block_number(1);
+            for followee in [ALICE, BOB] {
+                Ghonity::follow(RuntimeOrigin::signed(VIEWER), followee).unwrap();
+            }
+            Ghonity::set_privacy(RuntimeOrigin::signed(DAVE), true).unwrap();
+            Ghonity::request_follow(RuntimeOrigin::signed(VIEWER), DAVE).unwrap();
+        });
+        ext
+    }
+
+    fn create_intent(owner: AccountId) -> pallet_chainghost::IntentId {
+        let intent_id = ChainGhost::next_intent_id();
+        let kind = IntentKind::Transfer(TransferIntent {
+            dest: VIEWER,
+            asset: 0,
+            amount: 100,
+        });
+        ChainGhost::execute_intent(RuntimeOrigin::signed(owner), kind, b"intent".to_vec(), Vec::new())
+            .unwrap();
+        intent_id
+    }
+
+    fn record_step(owner: AccountId, intent_id: pallet_chainghost::IntentId) {
+        ChainGhost::record_journey(
+            RuntimeOrigin::signed(owner),
+            intent_id,
+            JourneyStepKind::Note,
+            b"step".to_vec(),
+            None,
+            None,
+        )
+        .unwrap();
+    }
+
+    /// Followee, intent, kind and block of each feed item
+    fn summary(
+        items: Vec<FeedItemData<AccountId, BlockNumber>>,
+    ) -> Vec<(AccountId, pallet_chainghost::IntentId, FeedItemKind, BlockNumber)> {
+        items
+            .into_iter()
+            .map(|item| (item.account, item.intent_id, item.kind, item.block_number))
+            .collect()
+    }
+
+    #[test]
+    fn feed_lists_followed_accounts_newest_first() {
+        new_test_ext().execute_with(|| {
+            let alice_intent = create_intent(ALICE);
+            System::set_block_number(2);
+            let bob_intent = create_intent(BOB);
+            System::set_block_number(3);
+            record_step(ALICE, alice_intent);
+            System::set_block_number(4);
+            create_intent(CAROL);
+            create_intent(DAVE);
+            System::set_block_number(5);
+            record_step(BOB, bob_intent);
+
+            let step = FeedItemKind::JourneyStep { step_id: 0 };
+            let all = vec![
+                (BOB, bob_intent, step.clone(), 5),
+                (ALICE, alice_intent, step, 3),
+                (BOB, bob_intent, FeedItemKind::Intent, 2),
+                (ALICE, alice_intent, FeedItemKind::Intent, 1),
+            ];
+            assert_eq!(summary(feed(&VIEWER, 0, 100)), all);
+
+            // Items recorded before `since_block` are left out
+            assert_eq!(summary(feed(&VIEWER, 3, 100)), all[..2].to_vec());
+
+            // Only the newest `limit` items are returned
+            assert_eq!(summary(feed(&VIEWER, 0, 3)), all[..3].to_vec());
+            assert!(feed(&VIEWER, 0, 0).is_empty());
+
+            // Accounts not followed yet have no feed items
+            assert!(feed(&CAROL, 0, 100).is_empty());
+        });
+    }
+
+    #[test]
+    fn feed_is_capped_at_max_feed_items() {
+        new_test_ext().execute_with(|| {
+            let intents = [create_intent(ALICE), create_intent(ALICE)];
+            for block in 2..=51 {
+                System::set_block_number(block);
+                for intent_id in intents {
+                    record_step(ALICE, intent_id);
+                }
+            }
+
+            // 100 journey steps and 2 intents
+            let items = feed(&VIEWER, 0, u32::MAX);
+            assert_eq!(items.len(), MAX_FEED_ITEMS as usize);
+            assert!(items.windows(2).all(|pair| pair[0].block_number >= pair[1].block_number));
+            assert!(items.iter().all(|item| item.kind != FeedItemKind::Intent));
+            assert_eq!(items.last().map(|item| item.block_number), Some(2));
+        });
+    }
+}
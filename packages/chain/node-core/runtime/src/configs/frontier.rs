@@ -6,6 +6,7 @@
 // - pallet_base_fee: EIP-1559 base fee mechanism
 
 use super::{AccountId, Balances, Runtime, RuntimeEvent};
+use crate::precompiles::{ChainGhostPrecompile, CHAINGHOST_PRECOMPILE_ADDRESS};
 use frame_support::{
     parameter_types,
     traits::FindAuthor,
@@ -13,7 +14,7 @@ use frame_support::{
     ConsensusEngineId,
 };
 use pallet_evm::{
-    EnsureAddressNever, FeeCalculator, IdentityAddressMapping,
+    AddressMapping, EnsureAddressNever, FeeCalculator,
 };
 use sp_core::{H160, U256};
 use sp_runtime::{
@@ -51,6 +52,34 @@ parameter_types! {
     pub Elasticity: Permill = Permill::from_parts(125_000); // 12.5%
 }
 
+/// Prefix of the accounts that Ethereum addresses map to
+const ETH_ACCOUNT_PREFIX: &[u8; 4] = b"eth:";
+
+/// Account of an Ethereum address: `eth:`, the 20 address bytes, then 8 zero bytes
+pub fn h160_to_account_id(address: &H160) -> AccountId {
+    let mut account = [0u8; 32];
+    account[0..4].copy_from_slice(ETH_ACCOUNT_PREFIX);
+    account[4..24].copy_from_slice(address.as_bytes());
+    AccountId::from(account)
+}
+
+/// Ethereum address of an account, the inverse of `h160_to_account_id`
+///
+/// `None` for accounts no Ethereum address maps to, such as sr25519 accounts.
+pub fn account_id_to_h160(account: &AccountId) -> Option<H160> {
+    let bytes: &[u8; 32] = account.as_ref();
+    let is_mapped = bytes[0..4] == *ETH_ACCOUNT_PREFIX && bytes[24..32].iter().all(|b| *b == 0);
+    is_mapped.then(|| H160::from_slice(&bytes[4..24]))
+}
+
+/// Address mapping of the EVM, through `h160_to_account_id`
+pub struct GhostAddressMapping;
+impl AddressMapping<AccountId> for GhostAddressMapping {
+    fn into_account_id(address: H160) -> AccountId {
+        h160_to_account_id(&address)
+    }
+}
+
 /// Finds the author of a block (validator/authority)
 pub struct FindAuthorTruncated<F>(sp_std::marker::PhantomData<F>);
 impl<F: FindAuthor<u32>> FindAuthor<H160> for FindAuthorTruncated<F> {
@@ -81,27 +110,28 @@ impl FeeCalculator for FixedGasPrice {
 }
 
 /// EVM precompiles configuration
-/// Using empty precompile set for initial integration
+/// Routes the Ghost Protocol precompiles by address
 /// TODO: Add standard Ethereum precompiles (ecrecover, sha256, ripemd160, etc.) 
 /// when precompile crates are added to dependencies
 pub struct GhostPrecompiles<R>(sp_std::marker::PhantomData<R>);
 
 impl<R> pallet_evm::PrecompileSet for GhostPrecompiles<R>
 where
-    R: pallet_evm::Config,
+    R: pallet_evm::Config + pallet_chainghost::Config,
 {
     fn execute(
         &self,
         handle: &mut impl pallet_evm::PrecompileHandle,
     ) -> Option<pallet_evm::PrecompileResult> {
-        // Empty precompile set for now
-        // Precompiles will be added in a future update
+        if handle.code_address() == CHAINGHOST_PRECOMPILE_ADDRESS {
+            return Some(ChainGhostPrecompile::<R>::execute(handle));
+        }
         None
     }
     
-    fn is_precompile(&self, _address: H160, _remaining_gas: u64) -> pallet_evm::IsPrecompileResult {
+    fn is_precompile(&self, address: H160, _remaining_gas: u64) -> pallet_evm::IsPrecompileResult {
         pallet_evm::IsPrecompileResult::Answer {
-            is_precompile: false,
+            is_precompile: address == CHAINGHOST_PRECOMPILE_ADDRESS,
             extra_cost: 0,
         }
     }
@@ -127,9 +157,9 @@ impl pallet_evm::Config for Runtime {
     /// Withdraw origin (only root can withdraw)
     type WithdrawOrigin = EnsureAddressNever<AccountId>;
     
-    /// Address mapping (converts between Substrate AccountId and Ethereum H160)
-    /// For now using identity mapping, can be customized for SS58 ↔ H160 conversion
-    type AddressMapping = IdentityAddressMapping;
+    /// Address mapping (converts Ethereum H160 to Substrate AccountId)
+    /// Invertible: `account_id_to_h160` gives back the address of a mapped account
+    type AddressMapping = GhostAddressMapping;
     
     /// Currency type for EVM balance operations
     type Currency = Balances;
@@ -137,7 +167,7 @@ impl pallet_evm::Config for Runtime {
     /// Runtime event type
     type RuntimeEvent = RuntimeEvent;
     
-    /// EVM precompiles (standard Ethereum precompiles)
+    /// EVM precompiles (Ghost Protocol pallets)
     type PrecompilesType = GhostPrecompiles<Runtime>;
     type PrecompilesValue = ();
     
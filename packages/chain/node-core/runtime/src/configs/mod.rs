@@ -27,6 +27,7 @@
 pub mod frontier;
 
 // Substrate and Polkadot dependencies
+use alloc::{vec, vec::Vec};
 use frame_support::{
     derive_impl, parameter_types,
     traits::{ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, VariantCountOf},
@@ -35,7 +36,11 @@ use frame_support::{
         IdentityFee, Weight,
     },
 };
-use frame_system::limits::{BlockLength, BlockWeights};
+use frame_system::{
+    limits::{BlockLength, BlockWeights},
+    EnsureRoot,
+};
+use pallet_chainghost::{Achievement, AchievementKind};
 use pallet_transaction_payment::{ConstFeeMultiplier, FungibleAdapter, Multiplier};
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_runtime::{traits::One, Perbill};
@@ -43,9 +48,9 @@ use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-    AccountId, Aura, Balance, Balances, Block, BlockNumber, Hash, Nonce, PalletInfo, Runtime,
-    RuntimeCall, RuntimeEvent, RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin, RuntimeTask,
-    System, EXISTENTIAL_DEPOSIT, SLOT_DURATION, VERSION,
+    AccountId, Aura, Balance, Balances, Block, BlockNumber, Ghonity, Hash, Nonce, PalletInfo,
+    Runtime, RuntimeCall, RuntimeEvent, RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin,
+    RuntimeTask, System, DAYS, EXISTENTIAL_DEPOSIT, HOURS, SLOT_DURATION, UNIT, VERSION,
 };
 
 const NORMAL_DISPATCH_RATIO: Perbill = Perbill::from_percent(75);
@@ -91,6 +96,9 @@ impl frame_system::Config for Runtime {
     /// This is used as an identifier of the chain. 42 is the generic substrate prefix.
     type SS58Prefix = SS58Prefix;
     type MaxConsumers = frame_support::traits::ConstU32<16>;
+    /// Record account creation blocks for Ghonity's follower age check.
+    type OnNewAccount = Ghonity;
+    type OnKilledAccount = Ghonity;
 }
 
 impl pallet_aura::Config for Runtime {
@@ -162,16 +170,92 @@ impl pallet_sudo::Config for Runtime {
 
 // ===== Ghost Protocol Custom Pallets Configuration =====
 
+parameter_types! {
+    pub const ClaimSlash: Perbill = Perbill::from_percent(10);
+    pub PersonaAchievements: Vec<Achievement> = vec![
+        // First intent, regular and power user
+        Achievement { kind: AchievementKind::IntentsExecuted, threshold: 1 },
+        Achievement { kind: AchievementKind::IntentsExecuted, threshold: 10 },
+        Achievement { kind: AchievementKind::IntentsExecuted, threshold: 100 },
+        // Reliable executor
+        Achievement { kind: AchievementKind::SuccessRatio, threshold: 90 },
+        // Explorer and all-rounder
+        Achievement { kind: AchievementKind::DistinctKinds, threshold: 3 },
+        Achievement { kind: AchievementKind::DistinctKinds, threshold: 5 },
+        // Storyteller
+        Achievement { kind: AchievementKind::JourneySteps, threshold: 100 },
+    ];
+}
+
 /// Configure ChainGhost pallet for intent-based execution and journey visualization
 impl pallet_chainghost::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type Currency = Balances;
     type WeightInfo = pallet_chainghost::weights::SubstrateWeight<Runtime>;
 
-    /// Maximum number of intents per account (prevent spam)
-    type MaxIntentsPerAccount = ConstU32<100>;
+    /// Deposit held per stored intent (prevent spam), released when the intent is removed
+    type IntentDeposit = ConstU128<{ UNIT / 10 }>;
+
+    /// Deposit held per stored template, released when the template is removed
+    type TemplateDeposit = ConstU128<{ UNIT / 10 }>;
+
+    /// Deposit held per active recurring schedule, released when the schedule ends
+    type RecurringDeposit = ConstU128<{ UNIT / 10 }>;
 
     /// Maximum journey steps per intent (narrative limit)
     type MaxJourneyStepsPerIntent = ConstU32<50>;
+
+    /// Maximum intent metadata length in bytes
+    type MaxMetadataLen = ConstU32<256>;
+
+    /// Maximum journey step description length in bytes
+    type MaxDescriptionLen = ConstU32<512>;
+
+    type RuntimeCall = RuntimeCall;
+
+    /// Maximum calls dispatched by a single intent (one journey step each)
+    type MaxCallsPerIntent = ConstU32<10>;
+
+    /// Bond held from solvers while registered
+    type SolverBond = ConstU128<{ 100 * UNIT }>;
+
+    /// Blocks a solver has to fulfil a claimed intent
+    type ClaimTimeout = ConstU32<{ 2 * HOURS }>;
+
+    /// Share of the solver bond paid to the intent creator when a claim times out or a
+    /// fulfilment is disputed
+    type ClaimSlash = ClaimSlash;
+
+    /// Claims per solver, so that every open claim is backed by a full 10% slash
+    type MaxActiveClaims = ConstU32<10>;
+
+    /// Blocks an intent owner has to dispute a fulfilment before its reward is paid
+    type DisputePeriod = ConstU32<{ DAYS }>;
+
+    /// Maximum delegates (e.g. backends recording journeys) per account
+    type MaxDelegates = ConstU32<16>;
+
+    /// Maximum external chain transactions attached to an intent
+    type MaxChainRefsPerIntent = ConstU32<16>;
+
+    /// Relayers attesting external execution are managed by root
+    type RelayerAdminOrigin = EnsureRoot<AccountId>;
+
+    /// Soulbound badges awarded on persona counters
+    type AchievementThresholds = PersonaAchievements;
+
+    /// The success ratio badge needs at least 10 resolved intents
+    type MinResolvedForSuccessRatio = ConstU32<10>;
+
+    /// Intent templates stored per account
+    type MaxTemplatesPerAccount = ConstU32<32>;
+
+    /// Active recurring schedules per account
+    type MaxRecurringPerAccount = ConstU32<16>;
+
+    /// Recurring intents created per block, the excess waits for the next blocks
+    type MaxInstantiationsPerBlock = ConstU32<20>;
 }
 
 /// Configure G3Mail pallet for decentralized messaging
@@ -192,8 +276,27 @@ impl pallet_g3mail::Config for Runtime {
 /// Configure Ghonity pallet for social graph and reputation
 impl pallet_ghonity::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
+    type RuntimeHoldReason = RuntimeHoldReason;
     type WeightInfo = pallet_ghonity::weights::SubstrateWeight<Runtime>;
 
     /// Maximum accounts a user can follow (prevent graph spam)
     type MaxFollowing = ConstU32<1000>;
+
+    /// Currency checked against the minimum follower balance and holding request deposits
+    type Currency = Balances;
+
+    /// Maximum reputation a single follower contributes to a weighted follower score
+    type MaxReputationPerFollower = ConstU32<1000>;
+
+    /// Followers younger than a week carry no weight (sybil resistance)
+    type MinFollowerAge = ConstU32<{ 7 * DAYS }>;
+
+    /// Followers holding less than 1 UNIT carry no weight (sybil resistance)
+    type MinFollowerBalance = ConstU128<UNIT>;
+
+    /// Maximum pending follow requests per private account
+    type MaxPendingRequests = ConstU32<100>;
+
+    /// Deposit held per pending follow request (prevent queue spam), released when resolved
+    type FollowRequestDeposit = ConstU128<{ UNIT / 100 }>;
 }
@@ -8,6 +8,7 @@ pub mod apis_impls;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarks;
 pub mod configs;
+pub mod precompiles;
 
 extern crate alloc;
 use alloc::vec::Vec;
@@ -26,10 +27,22 @@ pub use pallet_timestamp::Call as TimestampCall;
 #[cfg(any(feature = "std", test))]
 pub use sp_runtime::BuildStorage;
 
-pub use pallet_chainghost::{Intent, IntentId, IntentStatus, JourneyStep};
+pub use pallet_chainghost::{
+    AchievementKind, CancelReason, Intent, IntentAction, IntentId, IntentKind, IntentStatus, JourneyStep, JourneyStepKind, LinkedTx,
+};
 pub use pallet_g3mail::{MessageId, MessagePointer};
-pub use pallet_ghonity::{FollowerCount, FollowingCount, ReputationScores};
+pub use pallet_ghonity::{FollowerCount, FollowingCount, ReputationScores, WeightedFollowerScore};
 pub use apis_impls::RuntimeApi;
+pub use configs::frontier::{account_id_to_h160, h160_to_account_id};
+
+/// ChainGhost pallet events, decoded by the node RPC subscriptions
+pub type ChainGhostEvent = pallet_chainghost::Event<Runtime>;
+
+/// G3Mail pallet events, decoded by the node RPC subscriptions
+pub type G3MailEvent = pallet_g3mail::Event<Runtime>;
+
+/// Ghonity pallet events, decoded by the node RPC subscriptions
+pub type GhonityEvent = pallet_ghonity::Event<Runtime>;
 
 pub mod genesis_config_presets;
 
@@ -70,10 +83,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     //   `spec_version`, and `authoring_version` are the same between Wasm and native.
     // This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
     //   the compatible custom types.
-    spec_version: 100,
+    spec_version: 106,
     impl_version: 1,
     apis: apis_impls::RUNTIME_API_VERSIONS,
-    transaction_version: 1,
+    transaction_version: 2,
     system_version: 1,
 };
 
@@ -244,7 +257,16 @@ pub type SignedPayload = generic::SignedPayload<RuntimeCall, TxExtension>;
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+    pallet_ghonity::migrations::v1::MigrateV0ToV1<Runtime>,
+    pallet_ghonity::migrations::v2::MigrateV1ToV2<Runtime>,
+    pallet_chainghost::migrations::v1::MigrateV0ToV1<Runtime>,
+    pallet_chainghost::migrations::v2::MigrateV1ToV2<Runtime>,
+    pallet_chainghost::migrations::v3::MigrateV2ToV3<Runtime>,
+    pallet_chainghost::migrations::v4::MigrateV3ToV4<Runtime>,
+    pallet_chainghost::migrations::v5::MigrateV4ToV5<Runtime>,
+    pallet_chainghost::migrations::v6::MigrateV5ToV6<Runtime>,
+);
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: Unlicense
+pragma solidity >=0.8.3;
+
+/// @dev The ChainGhost precompile address
+address constant CHAINGHOST_ADDRESS = 0x0000000000000000000000000000000000000800;
+
+/// @dev The ChainGhost precompile instance
+ChainGhost constant CHAINGHOST_CONTRACT = ChainGhost(CHAINGHOST_ADDRESS);
+
+/// @title ChainGhost intents from the EVM
+/// @notice Calls dispatch into pallet-chainghost as the caller's mapped Substrate account.
+interface ChainGhost {
+    /// @notice Create an intent owned by the caller
+    /// @param intent SCALE encoding of `(IntentKind, Vec<u8>)`: the typed payload and its metadata
+    /// @return intentId The ID of the new intent
+    /// @custom:selector d29b3488
+    function executeIntent(bytes calldata intent) external returns (uint64 intentId);
+
+    /// @notice Record a note on the journey of an intent owned or claimed by the caller
+    /// @param intentId The intent to record the step on
+    /// @param description Description of the step
+    /// @custom:selector 2829e51c
+    function recordJourney(uint64 intentId, bytes calldata description) external;
+
+    /// @notice Read the status of an intent; reverts if the intent does not exist
+    /// @param intentId The intent to look up
+    /// @return status 0 = Pending, 1 = Executed, 2 = Failed, 3 = Cancelled
+    /// @custom:selector b2a00440
+    function intentStatus(uint64 intentId) external view returns (uint8 status);
+}
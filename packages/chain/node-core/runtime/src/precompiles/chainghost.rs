@@ -0,0 +1,589 @@
+// ChainGhost precompile
+//
+// Solidity interface: `ChainGhost.sol` in this directory.
+//
+// - `executeIntent(bytes)`: create an intent; `bytes` is the SCALE encoding of
+//   `(IntentKind<AccountId>, Vec<u8>)`, i.e. the typed payload followed by the metadata.
+//   Returns the new intent ID.
+// - `recordJourney(uint64,bytes)`: record a `Note` journey step on an intent owned or claimed
+//   by the caller
+// - `intentStatus(uint64)`: returns 0 (Pending), 1 (Executed), 2 (Failed) or 3 (Cancelled)
+//
+// Gas is charged from the pallet weights through the runtime's `GasWeightMapping`.
+
+use alloc::vec::Vec;
+use codec::DecodeAll;
+use fp_evm::{
+    ExitError, ExitRevert, ExitSucceed, PrecompileFailure, PrecompileHandle, PrecompileOutput,
+    PrecompileResult,
+};
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+use pallet_chainghost::{IntentId, IntentKind, JourneyStepKind, WeightInfo};
+use pallet_evm::{AddressMapping, GasWeightMapping};
+use sp_core::H160;
+
+/// Address of the ChainGhost precompile (0x0000…0800)
+pub const CHAINGHOST_PRECOMPILE_ADDRESS: H160 = H160([
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x08, 0x00,
+]);
+
+/// `executeIntent(bytes)`
+pub const SELECTOR_EXECUTE_INTENT: [u8; 4] = [0xd2, 0x9b, 0x34, 0x88];
+/// `recordJourney(uint64,bytes)`
+pub const SELECTOR_RECORD_JOURNEY: [u8; 4] = [0x28, 0x29, 0xe5, 0x1c];
+/// `intentStatus(uint64)`
+pub const SELECTOR_INTENT_STATUS: [u8; 4] = [0xb2, 0xa0, 0x04, 0x40];
+
+/// Largest `bytes` argument accepted, well above any valid intent or journey step
+const MAX_BYTES_ARGUMENT: usize = 8 * 1024;
+
+/// ABI word size
+const WORD: usize = 32;
+
+pub struct ChainGhostPrecompile<R>(core::marker::PhantomData<R>);
+
+impl<R> ChainGhostPrecompile<R>
+where
+    R: pallet_evm::Config + pallet_chainghost::Config,
+{
+    pub fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        // Dispatching as the caller is only sound when the precompile is called directly
+        if handle.code_address() != handle.context().address {
+            return Err(revert("ChainGhost: cannot be called with DELEGATECALL or CALLCODE"));
+        }
+
+        let input = handle.input();
+        if input.len() < 4 {
+            return Err(revert("ChainGhost: missing function selector"));
+        }
+        let selector = [input[0], input[1], input[2], input[3]];
+        let args = input[4..].to_vec();
+
+        match selector {
+            SELECTOR_EXECUTE_INTENT => Self::execute_intent(handle, &args),
+            SELECTOR_RECORD_JOURNEY => Self::record_journey(handle, &args),
+            SELECTOR_INTENT_STATUS => Self::intent_status(handle, &args),
+            _ => Err(revert("ChainGhost: unknown function selector")),
+        }
+    }
+
+    fn execute_intent(handle: &mut impl PrecompileHandle, args: &[u8]) -> PrecompileResult {
+        ensure_mutable(handle)?;
+        record_weight::<R>(handle, <R as pallet_chainghost::Config>::WeightInfo::execute_intent())?;
+
+        let encoded = read_bytes(args, 0)?;
+        let (kind, metadata) =
+            <(IntentKind<R::AccountId>, Vec<u8>)>::decode_all(&mut &encoded[..])
+                .map_err(|_| revert("ChainGhost: invalid intent encoding"))?;
+
+        let who = R::AddressMapping::into_account_id(handle.context().caller);
+        let intent_id = pallet_chainghost::Pallet::<R>::next_intent_id();
+
+        pallet_chainghost::Pallet::<R>::execute_intent(
+            RawOrigin::Signed(who).into(),
+            kind,
+            metadata,
+            Vec::new(),
+        )
+        .map_err(|err| revert(err.error.into()))?;
+
+        Ok(succeed(encode_u64(intent_id)))
+    }
+
+    fn record_journey(handle: &mut impl PrecompileHandle, args: &[u8]) -> PrecompileResult {
+        ensure_mutable(handle)?;
+        record_weight::<R>(handle, <R as pallet_chainghost::Config>::WeightInfo::record_journey())?;
+
+        let intent_id: IntentId = read_u64(args, 0)?;
+        let description = read_bytes(args, 1)?;
+
+        let who = R::AddressMapping::into_account_id(handle.context().caller);
+
+        pallet_chainghost::Pallet::<R>::record_journey(
+            RawOrigin::Signed(who).into(),
+            intent_id,
+            JourneyStepKind::Note,
+            description,
+            None,
+            None,
+        )
+        .map_err(|err| revert(err.into()))?;
+
+        Ok(succeed(Vec::new()))
+    }
+
+    fn intent_status(handle: &mut impl PrecompileHandle, args: &[u8]) -> PrecompileResult {
+        record_weight::<R>(handle, <R as frame_system::Config>::DbWeight::get().reads(1))?;
+
+        let intent_id: IntentId = read_u64(args, 0)?;
+        let intent = pallet_chainghost::Pallet::<R>::intent_by_id(intent_id)
+            .ok_or_else(|| revert("ChainGhost: intent not found"))?;
+
+        Ok(succeed(encode_u64(intent.status.as_u8() as u64)))
+    }
+}
+
+/// Charge the gas equivalent of `weight`
+fn record_weight<R: pallet_evm::Config>(
+    handle: &mut impl PrecompileHandle,
+    weight: frame_support::weights::Weight,
+) -> Result<(), PrecompileFailure> {
+    handle.record_cost(R::GasWeightMapping::weight_to_gas(weight))?;
+    Ok(())
+}
+
+fn ensure_mutable(handle: &impl PrecompileHandle) -> Result<(), PrecompileFailure> {
+    if handle.is_static() {
+        return Err(PrecompileFailure::Error {
+            exit_status: ExitError::Other("ChainGhost: cannot modify state in static call".into()),
+        });
+    }
+    Ok(())
+}
+
+fn succeed(output: Vec<u8>) -> PrecompileOutput {
+    PrecompileOutput {
+        exit_status: ExitSucceed::Returned,
+        output,
+    }
+}
+
+/// Revert with a Solidity `Error(string)` payload
+fn revert(message: &str) -> PrecompileFailure {
+    PrecompileFailure::Revert {
+        exit_status: ExitRevert::Reverted,
+        output: encode_revert_message(message),
+    }
+}
+
+fn encode_revert_message(message: &str) -> Vec<u8> {
+    // Error(string)
+    let mut output = Vec::from([0x08, 0xc3, 0x79, 0xa0]);
+    output.extend_from_slice(&encode_u64(WORD as u64));
+    output.extend_from_slice(&encode_u64(message.len() as u64));
+    output.extend_from_slice(message.as_bytes());
+    output.resize(4 + 2 * WORD + message.len().div_ceil(WORD) * WORD, 0);
+    output
+}
+
+/// Encode a `uint` ABI word
+fn encode_u64(value: u64) -> Vec<u8> {
+    let mut word = [0u8; WORD];
+    word[WORD - 8..].copy_from_slice(&value.to_be_bytes());
+    word.to_vec()
+}
+
+/// Read the ABI word at `index`
+fn read_word(args: &[u8], index: usize) -> Result<&[u8], PrecompileFailure> {
+    let start = index.checked_mul(WORD).ok_or_else(|| revert("ChainGhost: invalid input"))?;
+    args.get(start..start.saturating_add(WORD))
+        .ok_or_else(|| revert("ChainGhost: input too short"))
+}
+
+/// Read a `uint64` argument from the head word at `index`
+fn read_u64(args: &[u8], index: usize) -> Result<u64, PrecompileFailure> {
+    let word = read_word(args, index)?;
+    if word[..WORD - 8].iter().any(|byte| *byte != 0) {
+        return Err(revert("ChainGhost: uint64 out of range"));
+    }
+    let mut value = [0u8; 8];
+    value.copy_from_slice(&word[WORD - 8..]);
+    Ok(u64::from_be_bytes(value))
+}
+
+/// Read a `bytes` argument whose offset is stored in the head word at `index`
+fn read_bytes(args: &[u8], index: usize) -> Result<Vec<u8>, PrecompileFailure> {
+    let offset = read_u64(args, index)? as usize;
+    if offset % WORD != 0 {
+        return Err(revert("ChainGhost: invalid bytes offset"));
+    }
+
+    let length = read_u64(args, offset / WORD)? as usize;
+    if length > MAX_BYTES_ARGUMENT {
+        return Err(revert("ChainGhost: bytes argument too long"));
+    }
+
+    let start = offset + WORD;
+    args.get(start..start + length)
+        .map(|data| data.to_vec())
+        .ok_or_else(|| revert("ChainGhost: input too short"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AccountId, Balance, Runtime, RuntimeGenesisConfig, UNIT};
+    use codec::Encode;
+    use fp_evm::{Context, ExitReason, Transfer};
+    use pallet_chainghost::{IntentStatus, TransferIntent};
+    use sp_core::{H256, U256};
+    use sp_runtime::BuildStorage;
+
+    /// Caller funded at genesis
+    const CALLER: H160 = H160([0x11; 20]);
+    /// Caller without funds
+    const PAUPER: H160 = H160([0x22; 20]);
+
+    /// Precompile handle calling the precompile directly from `caller`
+    struct MockHandle {
+        input: Vec<u8>,
+        context: Context,
+        code_address: H160,
+        is_static: bool,
+        gas_limit: u64,
+        gas_used: u64,
+    }
+
+    impl MockHandle {
+        fn new(caller: H160, input: Vec<u8>) -> Self {
+            Self {
+                input,
+                context: Context {
+                    address: CHAINGHOST_PRECOMPILE_ADDRESS,
+                    caller,
+                    apparent_value: U256::zero(),
+                },
+                code_address: CHAINGHOST_PRECOMPILE_ADDRESS,
+                is_static: false,
+                gas_limit: u64::MAX,
+                gas_used: 0,
+            }
+        }
+    }
+
+    impl PrecompileHandle for MockHandle {
+        fn call(
+            &mut self,
+            _to: H160,
+            _transfer: Option<Transfer>,
+            _input: Vec<u8>,
+            _gas_limit: Option<u64>,
+            _is_static: bool,
+            _context: &Context,
+        ) -> (ExitReason, Vec<u8>) {
+            // The ChainGhost precompile makes no subcalls
+            (ExitReason::Error(ExitError::Other("unexpected subcall".into())), Vec::new())
+        }
+
+        fn record_cost(&mut self, cost: u64) -> Result<(), ExitError> {
+            if self.gas_used.saturating_add(cost) > self.gas_limit {
+                return Err(ExitError::OutOfGas);
+            }
+            self.gas_used += cost;
+            Ok(())
+        }
+
+        fn record_external_cost(
+            &mut self,
+            _ref_time: Option<u64>,
+            _proof_size: Option<u64>,
+            _storage_growth: Option<u64>,
+        ) -> Result<(), ExitError> {
+            Ok(())
+        }
+
+        fn refund_external_cost(&mut self, _ref_time: Option<u64>, _proof_size: Option<u64>) {}
+
+        fn remaining_gas(&self) -> u64 {
+            self.gas_limit - self.gas_used
+        }
+
+        fn log(
+            &mut self,
+            _address: H160,
+            _topics: Vec<H256>,
+            _data: Vec<u8>,
+        ) -> Result<(), ExitError> {
+            Ok(())
+        }
+
+        fn code_address(&self) -> H160 {
+            self.code_address
+        }
+
+        fn input(&self) -> &[u8] {
+            &self.input
+        }
+
+        fn context(&self) -> &Context {
+            &self.context
+        }
+
+        fn origin(&self) -> H160 {
+            self.context.caller
+        }
+
+        fn is_static(&self) -> bool {
+            self.is_static
+        }
+
+        fn gas_limit(&self) -> Option<u64> {
+            Some(self.gas_limit)
+        }
+
+        fn is_contract_being_constructed(&self, _address: H160) -> bool {
+            false
+        }
+    }
+
+    fn new_test_ext() -> sp_io::TestExternalities {
+        let mut storage = RuntimeGenesisConfig::default().build_storage().unwrap();
+        pallet_balances::GenesisConfig::<Runtime> {
+            balances: vec![(account_of(CALLER), 10 * UNIT)],
+            ..Default::default()
+        }
+        .assimilate_storage(&mut storage)
+        .unwrap();
+
+        let mut ext = sp_io::TestExternalities::new(storage);
+        ext.execute_with(|| frame_system::Pallet::<Runtime>::set_block_number(1));
+        ext
+    }
+
+    /// Substrate account the runtime dispatches as for `caller`
+    fn account_of(caller: H160) -> AccountId {
+        <Runtime as pallet_evm::Config>::AddressMapping::into_account_id(caller)
+    }
+
+    fn gas_of(weight: frame_support::weights::Weight) -> u64 {
+        <Runtime as pallet_evm::Config>::GasWeightMapping::weight_to_gas(weight)
+    }
+
+    fn call(selector: [u8; 4], args: Vec<u8>) -> Vec<u8> {
+        let mut input = selector.to_vec();
+        input.extend(args);
+        input
+    }
+
+    fn execute_intent_call(amount: Balance) -> Vec<u8> {
+        let kind = IntentKind::<AccountId>::Transfer(TransferIntent {
+            dest: account_of(PAUPER),
+            asset: 0,
+            amount,
+        });
+        let encoded = (kind, b"metadata".to_vec()).encode();
+        call(SELECTOR_EXECUTE_INTENT, encode_bytes_call(&[encode_u64(32)], &encoded))
+    }
+
+    fn record_journey_call(intent_id: IntentId, description: &[u8]) -> Vec<u8> {
+        let args = encode_bytes_call(&[encode_u64(intent_id), encode_u64(64)], description);
+        call(SELECTOR_RECORD_JOURNEY, args)
+    }
+
+    fn intent_status_call(intent_id: IntentId) -> Vec<u8> {
+        call(SELECTOR_INTENT_STATUS, encode_u64(intent_id))
+    }
+
+    /// Reason of a revert, decoded from its `Error(string)` payload
+    fn revert_reason(result: PrecompileResult) -> Vec<u8> {
+        match result {
+            Err(PrecompileFailure::Revert { output, .. }) => read_bytes(&output[4..], 0).unwrap(),
+            other => panic!("expected a revert, got {:?}", other),
+        }
+    }
+
+    fn encode_bytes_call(head: &[Vec<u8>], data: &[u8]) -> Vec<u8> {
+        let mut args: Vec<u8> = head.concat();
+        args.extend_from_slice(&encode_u64(data.len() as u64));
+        args.extend_from_slice(data);
+        args.resize(args.len() + (WORD - data.len() % WORD) % WORD, 0);
+        args
+    }
+
+    #[test]
+    fn selectors_match_solidity_signatures() {
+        let selector = |signature: &str| {
+            let hash = sp_io::hashing::keccak_256(signature.as_bytes());
+            [hash[0], hash[1], hash[2], hash[3]]
+        };
+
+        assert_eq!(selector("executeIntent(bytes)"), SELECTOR_EXECUTE_INTENT);
+        assert_eq!(selector("recordJourney(uint64,bytes)"), SELECTOR_RECORD_JOURNEY);
+        assert_eq!(selector("intentStatus(uint64)"), SELECTOR_INTENT_STATUS);
+    }
+
+    #[test]
+    fn caller_account_maps_back_to_its_address() {
+        assert_eq!(crate::account_id_to_h160(&account_of(CALLER)), Some(CALLER));
+        assert_eq!(crate::h160_to_account_id(&PAUPER), account_of(PAUPER));
+    }
+
+    #[test]
+    fn precompile_address_is_0x800() {
+        assert_eq!(CHAINGHOST_PRECOMPILE_ADDRESS, H160::from_low_u64_be(0x800));
+    }
+
+    #[test]
+    fn reads_abi_encoded_arguments() {
+        // recordJourney(7, "step one")
+        let args = encode_bytes_call(&[encode_u64(7), encode_u64(64)], b"step one");
+
+        assert_eq!(read_u64(&args, 0).unwrap(), 7);
+        assert_eq!(read_bytes(&args, 1).unwrap(), b"step one".to_vec());
+    }
+
+    #[test]
+    fn rejects_malformed_arguments() {
+        // Truncated input
+        assert!(read_u64(&[0u8; 16], 0).is_err());
+
+        // uint64 with high bits set
+        let mut word = encode_u64(1);
+        word[0] = 1;
+        assert!(read_u64(&word, 0).is_err());
+
+        // Length pointing past the end of the input
+        let mut args = encode_u64(32);
+        args.extend_from_slice(&encode_u64(100));
+        args.extend_from_slice(&[0u8; 32]);
+        assert!(read_bytes(&args, 0).is_err());
+
+        // Oversized bytes argument
+        let mut args = encode_u64(32);
+        args.extend_from_slice(&encode_u64(MAX_BYTES_ARGUMENT as u64 + 1));
+        assert!(read_bytes(&args, 0).is_err());
+    }
+
+    #[test]
+    fn revert_message_is_abi_encoded() {
+        let output = encode_revert_message("ChainGhost: intent not found");
+
+        assert_eq!(&output[..4], &[0x08, 0xc3, 0x79, 0xa0]);
+        assert_eq!(read_u64(&output[4..], 0).unwrap(), 32);
+        assert_eq!(read_bytes(&output[4..], 0).unwrap(), b"ChainGhost: intent not found".to_vec());
+        assert_eq!((output.len() - 4) % WORD, 0);
+    }
+
+    #[test]
+    fn execute_intent_dispatches_as_the_mapped_caller() {
+        new_test_ext().execute_with(|| {
+            let mut handle = MockHandle::new(CALLER, execute_intent_call(5));
+            let output = ChainGhostPrecompile::<Runtime>::execute(&mut handle).unwrap();
+
+            let intent_id = read_u64(&output.output, 0).unwrap();
+            let intent = pallet_chainghost::Pallet::<Runtime>::intent_by_id(intent_id).unwrap();
+            assert_eq!(intent.account, account_of(CALLER));
+            assert_eq!(intent.status, IntentStatus::Pending);
+            assert_eq!(intent.metadata.to_vec(), b"metadata".to_vec());
+            assert_eq!(
+                handle.gas_used,
+                gas_of(<Runtime as pallet_chainghost::Config>::WeightInfo::execute_intent())
+            );
+        });
+    }
+
+    #[test]
+    fn record_journey_and_intent_status_dispatch_to_the_pallet() {
+        new_test_ext().execute_with(|| {
+            let mut handle = MockHandle::new(CALLER, execute_intent_call(5));
+            let output = ChainGhostPrecompile::<Runtime>::execute(&mut handle).unwrap();
+            let intent_id = read_u64(&output.output, 0).unwrap();
+
+            let mut handle = MockHandle::new(CALLER, record_journey_call(intent_id, b"Swapped"));
+            ChainGhostPrecompile::<Runtime>::execute(&mut handle).unwrap();
+            assert_eq!(
+                handle.gas_used,
+                gas_of(<Runtime as pallet_chainghost::Config>::WeightInfo::record_journey())
+            );
+
+            let journey = pallet_chainghost::Pallet::<Runtime>::journey_by_intent(intent_id);
+            assert_eq!(journey.len(), 1);
+            assert_eq!(journey[0].author, account_of(CALLER));
+            assert_eq!(journey[0].step_kind, JourneyStepKind::Note);
+            assert_eq!(journey[0].description.to_vec(), b"Swapped".to_vec());
+
+            // Reading the status is allowed in a static call
+            let mut handle = MockHandle::new(PAUPER, intent_status_call(intent_id));
+            handle.is_static = true;
+            let output = ChainGhostPrecompile::<Runtime>::execute(&mut handle).unwrap();
+            assert_eq!(read_u64(&output.output, 0).unwrap(), IntentStatus::Pending.as_u8() as u64);
+            assert_eq!(
+                handle.gas_used,
+                gas_of(<Runtime as frame_system::Config>::DbWeight::get().reads(1))
+            );
+        });
+    }
+
+    #[test]
+    fn pallet_errors_revert_with_their_name() {
+        new_test_ext().execute_with(|| {
+            // The unfunded caller cannot cover the intent deposit
+            let mut handle = MockHandle::new(PAUPER, execute_intent_call(5));
+            assert!(ChainGhostPrecompile::<Runtime>::execute(&mut handle).is_err());
+            assert_eq!(pallet_chainghost::Pallet::<Runtime>::next_intent_id(), 0);
+
+            let mut handle = MockHandle::new(CALLER, execute_intent_call(0));
+            assert_eq!(
+                revert_reason(ChainGhostPrecompile::<Runtime>::execute(&mut handle)),
+                b"ZeroAmount".to_vec()
+            );
+
+            let mut handle = MockHandle::new(CALLER, execute_intent_call(5));
+            let output = ChainGhostPrecompile::<Runtime>::execute(&mut handle).unwrap();
+            let intent_id = read_u64(&output.output, 0).unwrap();
+
+            // Only the owner's mapped account may record on its intent
+            let mut handle = MockHandle::new(PAUPER, record_journey_call(intent_id, b"Forged"));
+            assert_eq!(
+                revert_reason(ChainGhostPrecompile::<Runtime>::execute(&mut handle)),
+                b"NotIntentOwner".to_vec()
+            );
+            assert!(pallet_chainghost::Pallet::<Runtime>::journey_by_intent(intent_id).is_empty());
+
+            let mut handle = MockHandle::new(CALLER, intent_status_call(intent_id + 1));
+            assert_eq!(
+                revert_reason(ChainGhostPrecompile::<Runtime>::execute(&mut handle)),
+                b"ChainGhost: intent not found".to_vec()
+            );
+        });
+    }
+
+    #[test]
+    fn rejects_invalid_calls_before_dispatching() {
+        new_test_ext().execute_with(|| {
+            let mut handle = MockHandle::new(CALLER, vec![0xde, 0xad, 0xbe, 0xef]);
+            assert_eq!(
+                revert_reason(ChainGhostPrecompile::<Runtime>::execute(&mut handle)),
+                b"ChainGhost: unknown function selector".to_vec()
+            );
+
+            let mut handle = MockHandle::new(CALLER, vec![0xd2, 0x9b]);
+            assert_eq!(
+                revert_reason(ChainGhostPrecompile::<Runtime>::execute(&mut handle)),
+                b"ChainGhost: missing function selector".to_vec()
+            );
+
+            // A contract delegating to the precompile would dispatch as its own caller
+            let mut handle = MockHandle::new(CALLER, execute_intent_call(5));
+            handle.context.address = H160::repeat_byte(0x33);
+            assert_eq!(
+                revert_reason(ChainGhostPrecompile::<Runtime>::execute(&mut handle)),
+                b"ChainGhost: cannot be called with DELEGATECALL or CALLCODE".to_vec()
+            );
+
+            let mut handle = MockHandle::new(CALLER, execute_intent_call(5));
+            handle.is_static = true;
+            assert!(matches!(
+                ChainGhostPrecompile::<Runtime>::execute(&mut handle),
+                Err(PrecompileFailure::Error { .. })
+            ));
+
+            let mut handle = MockHandle::new(CALLER, call(SELECTOR_EXECUTE_INTENT, encode_u64(32)));
+            assert_eq!(
+                revert_reason(ChainGhostPrecompile::<Runtime>::execute(&mut handle)),
+                b"ChainGhost: input too short".to_vec()
+            );
+
+            // Gas is charged before dispatching, so an underfunded call changes nothing
+            let mut handle = MockHandle::new(CALLER, execute_intent_call(5));
+            handle.gas_limit = 1;
+            assert!(matches!(
+                ChainGhostPrecompile::<Runtime>::execute(&mut handle),
+                Err(PrecompileFailure::Error { exit_status: ExitError::OutOfGas })
+            ));
+
+            assert_eq!(pallet_chainghost::Pallet::<Runtime>::next_intent_id(), 0);
+        });
+    }
+}
@@ -0,0 +1,9 @@
+// Ghost Protocol EVM precompiles
+//
+// Precompiles expose the custom pallets to Solidity contracts and EVM accounts.
+// Each precompile lives at a fixed address and dispatches into its pallet using
+// the caller's mapped Substrate account.
+
+mod chainghost;
+
+pub use chainghost::{ChainGhostPrecompile, CHAINGHOST_PRECOMPILE_ADDRESS};